@@ -17,6 +17,12 @@ pub struct EventEngine {
     pub event_history: Vec<ProcessedEvent>,
 }
 
+impl Default for EventEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl EventEngine {
     /// Creates a new EventEngine instance
     pub fn new() -> Self {
@@ -176,6 +182,8 @@ pub enum DecisionType {
     MediaInterview,
     /// Personal life choice
     PersonalLifeChoice,
+    /// Career opportunity at the club or with the national team
+    CareerOpportunity,
 }
 
 /// An option in a user decision
@@ -282,11 +290,13 @@ impl std::fmt::Display for crate::core::time_engine::ScheduledEventType {
             crate::core::time_engine::ScheduledEventType::TrainingSession => write!(f, "TrainingSession"),
             crate::core::time_engine::ScheduledEventType::TransferWindowOpen => write!(f, "TransferWindowOpen"),
             crate::core::time_engine::ScheduledEventType::TransferWindowClose => write!(f, "TransferWindowClose"),
+            crate::core::time_engine::ScheduledEventType::DeadlineDayBid(_) => write!(f, "DeadlineDayBid"),
             crate::core::time_engine::ScheduledEventType::ContractExpires(_) => write!(f, "ContractExpires"),
             crate::core::time_engine::ScheduledEventType::ContractNegotiation(_) => write!(f, "ContractNegotiation"),
             crate::core::time_engine::ScheduledEventType::ManagerEvaluation => write!(f, "ManagerEvaluation"),
             crate::core::time_engine::ScheduledEventType::SeasonEnd => write!(f, "SeasonEnd"),
             crate::core::time_engine::ScheduledEventType::InternationalBreak => write!(f, "InternationalBreak"),
+            crate::core::time_engine::ScheduledEventType::InternationalTournament => write!(f, "InternationalTournament"),
             crate::core::time_engine::ScheduledEventType::MediaEvent => write!(f, "MediaEvent"),
             crate::core::time_engine::ScheduledEventType::PersonalEvent(_) => write!(f, "PersonalEvent"),
             crate::core::time_engine::ScheduledEventType::RandomEvent => write!(f, "RandomEvent"),
@@ -1,8 +1,12 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::entities::{Player, Team, Competition};
+use crate::core::event_engine::UserDecisionRequest;
+use crate::entities::{
+    Player, Team, Competition, Standing, PlayerCompetitionStats, NationalTeam, InternationalFixture,
+    InternationalTournament, ContractTerms, Agent, Position, SquadRole
+};
 
 /// The main game state that holds all the data for a running game
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -13,6 +17,9 @@ pub struct GameState {
     pub current_club_id: Uuid,
     /// All clubs in the game world
     pub clubs: Vec<Team>,
+    /// All other players in the game world
+    #[serde(default)]
+    pub players: Vec<Player>,
     /// Current season information
     pub season: SeasonInfo,
     /// All leagues in the game
@@ -31,6 +38,39 @@ pub struct GameState {
     pub save_version: String,
     /// Current game date
     pub current_date: DateTime<Utc>,
+    /// Final tables and winners of completed seasons
+    #[serde(default)]
+    pub season_history: Vec<SeasonArchive>,
+    /// Senior national teams, one per nationality in the game world
+    #[serde(default)]
+    pub national_teams: Vec<NationalTeam>,
+    /// International matches played so far
+    #[serde(default)]
+    pub international_fixtures: Vec<InternationalFixture>,
+    /// World Cups and continental championships, from qualifying to the finals
+    #[serde(default)]
+    pub international_tournaments: Vec<InternationalTournament>,
+    /// Agents working in the game world
+    #[serde(default)]
+    pub agents: Vec<Agent>,
+    /// The agent currently representing the player character
+    #[serde(default)]
+    pub player_agent: Option<Uuid>,
+    /// The player character's money: balance and every payment in or out
+    #[serde(default)]
+    pub player_finances: PlayerFinances,
+    /// Weekly market values of every player in the world, oldest first
+    #[serde(default)]
+    pub market_values: std::collections::HashMap<Uuid, Vec<MarketValuePoint>>,
+    /// Career opportunities offered to the player and whether they took them
+    #[serde(default)]
+    pub career_opportunities: Vec<OpportunityRecord>,
+    /// What the player wants from their career, used to judge every offer
+    #[serde(default)]
+    pub career_priorities: CareerPriorities,
+    /// Decisions waiting for the player, oldest first
+    #[serde(default)]
+    pub pending_decisions: Vec<UserDecisionRequest>,
 }
 
 impl GameState {
@@ -39,6 +79,7 @@ impl GameState {
             player,
             current_club_id,
             clubs: Vec::new(),
+            players: Vec::new(),
             season: SeasonInfo::new(),
             leagues: Vec::new(),
             competitions: Vec::new(),
@@ -48,8 +89,33 @@ impl GameState {
             tutorial_state: std::collections::HashMap::new(),
             save_version: "1.0".to_string(),
             current_date: Utc::now(),
+            season_history: Vec::new(),
+            national_teams: Vec::new(),
+            international_fixtures: Vec::new(),
+            international_tournaments: Vec::new(),
+            agents: Vec::new(),
+            player_agent: None,
+            player_finances: PlayerFinances::new(),
+            market_values: std::collections::HashMap::new(),
+            career_opportunities: Vec::new(),
+            career_priorities: CareerPriorities::new(),
+            pending_decisions: Vec::new(),
         }
     }
+
+    /// Looks up any player in the world, including the player character
+    pub fn find_player(&self, player_id: Uuid) -> Option<&Player> {
+        std::iter::once(&self.player)
+            .chain(self.players.iter())
+            .find(|player| player.id == player_id)
+    }
+
+    /// Mutable lookup of any player in the world, including the player character
+    pub fn find_player_mut(&mut self, player_id: Uuid) -> Option<&mut Player> {
+        std::iter::once(&mut self.player)
+            .chain(self.players.iter_mut())
+            .find(|player| player.id == player_id)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -60,19 +126,87 @@ pub struct SeasonInfo {
     pub current_matchday: u8,
     pub is_active: bool,
     pub transfer_windows: TransferWindows,
+    /// Window dates for countries whose calendar differs from `transfer_windows`
+    #[serde(default)]
+    pub country_windows: std::collections::HashMap<String, TransferWindows>,
+}
+
+impl Default for SeasonInfo {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl SeasonInfo {
     pub fn new() -> Self {
+        let start_date = Utc::now();
         SeasonInfo {
-            year: "".to_string(),
-            start_date: Utc::now(),
-            end_date: Utc::now() + chrono::Duration::days(365),
+            year: crate::utils::helpers::season_label(season_start_year(start_date)),
+            start_date,
+            end_date: start_date + chrono::Duration::days(365),
             current_matchday: 1,
             is_active: true,
             transfer_windows: TransferWindows::new(),
+            country_windows: std::collections::HashMap::new(),
         }
     }
+
+    /// The transfer windows that apply to clubs in a country
+    pub fn windows_for(&self, country: &str) -> &TransferWindows {
+        self.country_windows.get(country).unwrap_or(&self.transfer_windows)
+    }
+}
+
+/// Seasons run from summer to summer, so dates before July belong to the season
+/// that started the previous year
+pub fn season_start_year(date: DateTime<Utc>) -> i32 {
+    use chrono::Datelike;
+
+    if date.month() >= 7 {
+        date.year()
+    } else {
+        date.year() - 1
+    }
+}
+
+/// A completed season kept for the history books
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeasonArchive {
+    pub season: String, // e.g., "2026-27"
+    pub competitions: Vec<CompetitionRecord>,
+    #[serde(default)]
+    pub awards: Vec<AwardResult>,
+}
+
+/// The published outcome of an end-of-season award vote
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AwardResult {
+    pub name: String,        // e.g., "Top Scorer"
+    pub competition: String, // League name, or "World" for global awards
+    pub winners: Vec<Uuid>,  // One player, or a full XI for team of the season
+    pub voting: Vec<NomineeVotes>,
+}
+
+/// A nominee's standing in an award vote
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NomineeVotes {
+    pub player_id: Uuid,
+    pub team_id: Uuid,
+    pub score: f32,      // Contender score, or goals for the top scorer award
+    pub vote_share: f32, // Percentage of the jury vote
+}
+
+/// Final outcome of a single competition in an archived season
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompetitionRecord {
+    pub competition_id: Uuid,
+    pub name: String,
+    pub winner: Option<Uuid>,
+    pub final_table: Vec<Standing>,
+    #[serde(default)]
+    pub player_stats: Vec<PlayerCompetitionStats>,
+    #[serde(default)]
+    pub youth: bool, // Youth or reserve competition
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -83,6 +217,12 @@ pub struct TransferWindows {
     pub winter_end: Option<DateTime<Utc>>,
 }
 
+impl Default for TransferWindows {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl TransferWindows {
     pub fn new() -> Self {
         TransferWindows {
@@ -92,6 +232,63 @@ impl TransferWindows {
             winter_end: None,
         }
     }
+
+    /// The windows a country's clubs trade in during the season starting in `season_start_year`
+    pub fn for_country(country: &str, season_start_year: i32) -> Self {
+        let ((summer_open, summer_close), (winter_open, winter_close)) = match country {
+            "England" | "Scotland" | "Wales" => (((6, 14), (9, 1)), ((1, 1), (2, 2))),
+            "Spain" | "Italy" | "Germany" | "France" | "Portugal" | "Netherlands" => (((7, 1), (9, 1)), ((1, 2), (2, 1))),
+            _ => (((7, 1), (8, 31)), ((1, 1), (1, 31))),
+        };
+        let at = |year: i32, (month, day): (u32, u32)| {
+            NaiveDate::from_ymd_opt(year, month, day).unwrap().and_hms_opt(0, 0, 0).unwrap().and_utc()
+        };
+
+        TransferWindows {
+            summer_start: at(season_start_year, summer_open),
+            summer_end: at(season_start_year, summer_close),
+            winter_start: Some(at(season_start_year + 1, winter_open)),
+            winter_end: Some(at(season_start_year + 1, winter_close)),
+        }
+    }
+
+    /// Opening and closing days of each window, in calendar order
+    pub fn periods(&self) -> Vec<(NaiveDate, NaiveDate)> {
+        let mut periods = vec![(self.summer_start.date_naive(), self.summer_end.date_naive())];
+        if let (Some(start), Some(end)) = (self.winter_start, self.winter_end) {
+            periods.push((start.date_naive(), end.date_naive()));
+        }
+        periods
+    }
+
+    /// Whether a window is open on the date; both the opening and closing days count
+    pub fn is_open(&self, date: NaiveDate) -> bool {
+        self.periods().iter().any(|(start, end)| date >= *start && date <= *end)
+    }
+
+    /// The opening day of the window open on the date
+    pub fn opening_date(&self, date: NaiveDate) -> Option<NaiveDate> {
+        self.periods().into_iter().find(|(start, end)| date >= *start && date <= *end).map(|(start, _)| start)
+    }
+
+    /// The closing day of the window open on the date
+    pub fn closing_date(&self, date: NaiveDate) -> Option<NaiveDate> {
+        self.periods().into_iter().find(|(start, end)| date >= *start && date <= *end).map(|(_, end)| end)
+    }
+
+    /// The opening day of the next window after the date; windows repeat each year
+    pub fn next_opening(&self, date: NaiveDate) -> NaiveDate {
+        use chrono::Datelike;
+
+        self.periods()
+            .into_iter()
+            .map(|(start, _)| start)
+            .find(|start| *start > date)
+            .unwrap_or_else(|| {
+                let summer = self.summer_start.date_naive();
+                summer.with_year(summer.year() + 1).unwrap_or(summer)
+            })
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -99,6 +296,42 @@ pub struct TransferSystemState {
     pub interested_clubs: std::collections::HashMap<Uuid, TransferInterest>,
     pub active_offers: Vec<TransferOffer>,
     pub negotiation_history: Vec<TransferNegotiation>,
+    /// Hidden scouting awareness each club has of the player (0-100)
+    #[serde(default)]
+    pub scouting_awareness: std::collections::HashMap<Uuid, f32>,
+    /// Transfer gossip the player has heard, oldest first
+    #[serde(default)]
+    pub rumours: Vec<TransferRumour>,
+    /// When the player asked their club to let them leave, if they have
+    #[serde(default)]
+    pub transfer_request: Option<NaiveDate>,
+    /// When the player's last contract ran out, while they are without a club
+    #[serde(default)]
+    pub free_agent_since: Option<NaiveDate>,
+    /// A move agreed with a foreign club that starts when the current contract expires
+    #[serde(default)]
+    pub pre_contract: Option<PreContract>,
+    /// A trial the free agent is currently on
+    #[serde(default)]
+    pub trial: Option<TrialPeriod>,
+    /// Offers agreed while the window was shut, waiting for it to open
+    #[serde(default)]
+    pub pre_agreements: Vec<PreAgreement>,
+    /// Every fee-paying move in the world, with the clauses still owed on it
+    #[serde(default)]
+    pub transfer_records: Vec<TransferRecord>,
+    /// Renewal talks the player's club has opened on their current contract
+    #[serde(default)]
+    pub renewal_talks: Option<RenewalTalks>,
+    /// The last day each AI club did business in the transfer market
+    #[serde(default)]
+    pub market_days: std::collections::HashMap<Uuid, NaiveDate>,
+}
+
+impl Default for TransferSystemState {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl TransferSystemState {
@@ -107,6 +340,16 @@ impl TransferSystemState {
             interested_clubs: std::collections::HashMap::new(),
             active_offers: Vec::new(),
             negotiation_history: Vec::new(),
+            scouting_awareness: std::collections::HashMap::new(),
+            rumours: Vec::new(),
+            transfer_request: None,
+            free_agent_since: None,
+            pre_contract: None,
+            trial: None,
+            pre_agreements: Vec::new(),
+            transfer_records: Vec::new(),
+            renewal_talks: None,
+            market_days: std::collections::HashMap::new(),
         }
     }
 }
@@ -117,9 +360,69 @@ pub struct TransferInterest {
     pub interest_level: InterestLevel,
     pub last_evaluation_date: DateTime<Utc>,
     pub evaluation_score: f32,
+    /// Matches the club's scouts have attended
+    #[serde(default)]
+    pub scout_visits: u32,
+}
+
+/// A transfer rumour in the player's news feed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransferRumour {
+    pub id: Uuid,
+    pub date: NaiveDate,
+    pub club_id: Option<Uuid>,
+    pub headline: String,
+    pub reliability: RumourReliability,
+    /// Whether the story reflects real interest; hidden from the player
+    pub truthful: bool,
 }
 
+/// A pre-contract agreement with a club abroad, signed in the last six months of a contract
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreContract {
+    pub club_id: Uuid,
+    pub signed_on: NaiveDate,
+    pub terms: ContractTerms,
+}
+
+/// Contract renewal talks opened by the player's club
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RenewalTalks {
+    pub club_id: Uuid,
+    pub contract_end_date: NaiveDate, // End of the contract being renewed
+    pub opened_on: NaiveDate,
+    pub last_approach: NaiveDate,
+    pub approaches: u32,
+    pub stalls: u32, // Times the player has put the club off
+    pub broken_off: bool,
+}
+
+/// An offer made outside a transfer window, held until the window opens
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreAgreement {
+    pub offer: TransferOffer,
+    pub agreed_on: NaiveDate,
+    pub completes_on: NaiveDate, // The day the next window opens
+}
+
+/// A free agent's trial at a club, with the terms on offer if they impress
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrialPeriod {
+    pub club_id: Uuid,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub terms: ContractTerms,
+}
+
+/// How much trust a rumour deserves, from idle gossip to inside information
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum RumourReliability {
+    Tabloid,
+    Journalist,
+    Insider,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum InterestLevel {
     Monitoring,
     Scouting,
@@ -138,6 +441,81 @@ pub struct TransferOffer {
     pub transfer_fee: Option<f32>,
     pub offer_date: DateTime<Utc>,
     pub expiry_date: DateTime<Utc>,
+    /// How the fee is paid and what the selling club keeps a claim to
+    #[serde(default)]
+    pub fee_structure: Option<FeeStructure>,
+}
+
+/// How a transfer fee is paid, and what the selling club keeps a claim to afterwards
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FeeStructure {
+    pub upfront: f32,
+    pub instalments: Vec<FeeInstalment>,
+    pub add_ons: Vec<FeeAddOn>,
+    pub sell_on_percentage: f32, // Share of the next fee owed to the selling club
+    pub buy_back: Option<BuyBackClause>,
+}
+
+impl FeeStructure {
+    /// A fee paid in full on signing, with no strings attached
+    pub fn upfront(fee: f32) -> Self {
+        FeeStructure {
+            upfront: fee,
+            ..FeeStructure::default()
+        }
+    }
+
+    /// The money the buying club is committed to, upfront and instalments
+    pub fn guaranteed(&self) -> f32 {
+        self.upfront + self.instalments.iter().map(|instalment| instalment.amount).sum::<f32>()
+    }
+
+    /// The most the deal could cost if every add-on is met
+    pub fn maximum(&self) -> f32 {
+        self.guaranteed() + self.add_ons.iter().map(|add_on| add_on.amount).sum::<f32>()
+    }
+}
+
+/// Part of a fee paid on a set date after the move
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeeInstalment {
+    pub due: NaiveDate,
+    pub amount: f32,
+    pub paid: bool,
+}
+
+/// An extra payment owed once the player achieves something at the buying club
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeeAddOn {
+    pub condition: AddOnCondition,
+    pub amount: f32,
+    pub paid: bool,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum AddOnCondition {
+    Appearances(u32),
+    Trophies(u32),
+}
+
+/// The selling club's right to buy the player back for a set fee
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuyBackClause {
+    pub fee: f32,
+    pub expires_on: NaiveDate,
+}
+
+/// A completed transfer and the clauses that follow the player afterwards
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransferRecord {
+    pub player_id: Uuid,
+    pub from_club_id: Uuid,
+    pub to_club_id: Uuid,
+    pub date: NaiveDate,
+    pub fee: FeeStructure,
+    /// Senior appearances and trophies at the buying club, counted towards the add-ons
+    pub appearances: u32,
+    pub trophies: u32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -146,6 +524,27 @@ pub struct TransferNegotiation {
     pub player_response: Option<PlayerResponse>,
     pub agent_response: Option<AgentResponse>,
     pub final_result: Option<NegotiationResult>,
+    /// Every offer and counter-offer, in order
+    #[serde(default)]
+    pub rounds: Vec<NegotiationRound>,
+    /// Terms both sides signed up to, if the talks succeeded
+    #[serde(default)]
+    pub agreed_terms: Option<ContractTerms>,
+}
+
+/// One offer or counter-offer in a contract negotiation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NegotiationRound {
+    pub round: u8,
+    pub party: NegotiatingParty,
+    pub terms: ContractTerms,
+    pub note: String,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum NegotiatingParty {
+    Club,
+    Player,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -167,4 +566,212 @@ pub enum NegotiationResult {
     Accepted,
     Rejected,
     Withdrawn,
-}
\ No newline at end of file
+}
+
+/// The player's personal money, kept as a ledger of every payment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerFinances {
+    pub balance: f32,
+    pub ledger: Vec<LedgerEntry>, // Oldest first
+    /// Things the player has bought and keeps paying for
+    #[serde(default)]
+    pub lifestyle: Vec<LifestylePurchase>,
+    /// Net worth at the end of each season of the career
+    #[serde(default)]
+    pub net_worth_history: Vec<NetWorthRecord>,
+}
+
+impl Default for PlayerFinances {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PlayerFinances {
+    pub fn new() -> Self {
+        PlayerFinances {
+            balance: 0.0,
+            ledger: Vec::new(),
+            lifestyle: Vec::new(),
+            net_worth_history: Vec::new(),
+        }
+    }
+
+    /// Records a payment, positive for money in and negative for money out
+    pub fn record(
+        &mut self,
+        date: NaiveDate,
+        kind: LedgerEntryKind,
+        description: String,
+        amount: f32,
+    ) {
+        self.balance += amount;
+        self.ledger.push(LedgerEntry {
+            date,
+            kind,
+            description,
+            amount,
+        });
+    }
+}
+
+/// A single payment in the player's ledger
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LedgerEntry {
+    pub date: NaiveDate,
+    pub kind: LedgerEntryKind,
+    pub description: String,
+    pub amount: f32,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum LedgerEntryKind {
+    Wage,
+    Bonus,
+    Tax,
+    AgentFee,
+    Lifestyle,
+}
+
+/// A lifestyle item the player owns or pays for
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LifestylePurchase {
+    pub item: LifestyleItem,
+    pub bought_on: NaiveDate,
+    pub price: f32, // Up-front cost, zero for services
+}
+
+/// Something the player can spend their money on
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum LifestyleItem {
+    House,           // Lifts morale every week
+    Car,             // A morale and local profile boost when bought
+    PersonalTrainer, // Extra fitness and recovery every week
+}
+
+impl LifestyleItem {
+    pub fn name(&self) -> &'static str {
+        match self {
+            LifestyleItem::House => "house",
+            LifestyleItem::Car => "car",
+            LifestyleItem::PersonalTrainer => "personal trainer",
+        }
+    }
+}
+
+/// The player's net worth when a season ended
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetWorthRecord {
+    pub season: String, // e.g., "2026-27"
+    pub net_worth: f32,
+}
+
+/// A player's market value on one date
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarketValuePoint {
+    pub date: NaiveDate,
+    pub value: f32,
+}
+
+/// A chance to change the shape of the player's career without moving club
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CareerOpportunity {
+    pub id: Uuid,
+    pub club_id: Uuid,
+    pub offered_on: NaiveDate,
+    pub kind: OpportunityKind,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum OpportunityKind {
+    Captaincy,
+    RolePromotion { role: SquadRole, displaced: Option<Uuid> }, // The teammate who loses out
+    PositionChange { position: Position, role: SquadRole },
+    NationalTeam { national_team_id: Uuid },
+    Mentorship { mentee_id: Uuid },
+}
+
+/// An opportunity the player has answered
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpportunityRecord {
+    pub opportunity: CareerOpportunity,
+    pub accepted: bool,
+}
+
+/// Something a player can want from their career
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum CareerPriority {
+    Money,
+    PlayingTime,
+    Trophies,
+    LeaguePrestige,
+    ProximityToHome,
+    Loyalty,
+}
+
+impl CareerPriority {
+    pub const ALL: [CareerPriority; 6] = [
+        CareerPriority::Money,
+        CareerPriority::PlayingTime,
+        CareerPriority::Trophies,
+        CareerPriority::LeaguePrestige,
+        CareerPriority::ProximityToHome,
+        CareerPriority::Loyalty,
+    ];
+}
+
+/// How much the player cares about each career priority, each 0-100
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CareerPriorities {
+    pub money: f32,
+    pub playing_time: f32,
+    pub trophies: f32,
+    pub league_prestige: f32,
+    pub proximity_to_home: f32,
+    pub loyalty: f32,
+    pub home_country: String, // Country the player grew up playing in
+}
+
+impl Default for CareerPriorities {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CareerPriorities {
+    /// A profile that weighs everything evenly
+    pub fn new() -> Self {
+        CareerPriorities {
+            money: 50.0,
+            playing_time: 50.0,
+            trophies: 50.0,
+            league_prestige: 50.0,
+            proximity_to_home: 50.0,
+            loyalty: 50.0,
+            home_country: String::new(),
+        }
+    }
+
+    pub fn weight(&self, priority: CareerPriority) -> f32 {
+        match priority {
+            CareerPriority::Money => self.money,
+            CareerPriority::PlayingTime => self.playing_time,
+            CareerPriority::Trophies => self.trophies,
+            CareerPriority::LeaguePrestige => self.league_prestige,
+            CareerPriority::ProximityToHome => self.proximity_to_home,
+            CareerPriority::Loyalty => self.loyalty,
+        }
+    }
+
+    pub fn set_weight(&mut self, priority: CareerPriority, weight: f32) {
+        let weight = weight.clamp(0.0, 100.0);
+        match priority {
+            CareerPriority::Money => self.money = weight,
+            CareerPriority::PlayingTime => self.playing_time = weight,
+            CareerPriority::Trophies => self.trophies = weight,
+            CareerPriority::LeaguePrestige => self.league_prestige = weight,
+            CareerPriority::ProximityToHome => self.proximity_to_home = weight,
+            CareerPriority::Loyalty => self.loyalty = weight,
+        }
+    }
+}
@@ -18,6 +18,9 @@ pub struct TimeEngine {
     pub is_paused: bool,
     /// Reason for the pause
     pub pause_reason: Option<PauseReason>,
+    /// Events that have fired and are waiting for the game systems to act on them
+    #[serde(default)]
+    pub triggered_events: Vec<ScheduledEvent>,
 }
 
 impl TimeEngine {
@@ -29,6 +32,7 @@ impl TimeEngine {
             event_queue: BinaryHeap::new(),
             is_paused: false,
             pause_reason: None,
+            triggered_events: Vec::new(),
         }
     }
 
@@ -39,7 +43,7 @@ impl TimeEngine {
         }
 
         // Advance the current date
-        self.current_date = self.current_date + self.tick_duration;
+        self.current_date += self.tick_duration;
 
         // Check for any events that should now be triggered
         self.process_scheduled_events();
@@ -52,6 +56,17 @@ impl TimeEngine {
         self.event_queue.push(event);
     }
 
+    /// Removes every queued event matching the predicate, e.g. a postponed match
+    /// Returns the number of events removed
+    pub fn remove_events<F>(&mut self, predicate: F) -> usize
+    where
+        F: Fn(&ScheduledEvent) -> bool,
+    {
+        let queued = self.event_queue.len();
+        self.event_queue.retain(|event| !predicate(event));
+        queued - self.event_queue.len()
+    }
+
     /// Processes all events that are scheduled for the current time
     fn process_scheduled_events(&mut self) {
         let mut events_to_process = Vec::new();
@@ -71,9 +86,15 @@ impl TimeEngine {
         // Process the collected events
         for event in events_to_process {
             self.handle_event_priority(&event);
+            self.triggered_events.push(event);
         }
     }
 
+    /// Hands over the events that have fired since the last call, oldest first
+    pub fn take_triggered_events(&mut self) -> Vec<ScheduledEvent> {
+        std::mem::take(&mut self.triggered_events)
+    }
+
     /// Checks if there are any high-priority events requiring user input
     fn handle_event_priority(&mut self, event: &ScheduledEvent) {
         if event.requires_user_input {
@@ -204,6 +225,8 @@ pub enum ScheduledEventType {
     TransferWindowOpen,
     /// Transfer window closes
     TransferWindowClose,
+    /// Last-minute bid in the final days of a window
+    DeadlineDayBid(Uuid), // Club ID
     /// Contract expires
     ContractExpires(Uuid), // Player ID
     /// Contract negotiation
@@ -214,6 +237,8 @@ pub enum ScheduledEventType {
     SeasonEnd,
     /// International break
     InternationalBreak,
+    /// Matchday at the finals of an international tournament
+    InternationalTournament,
     /// Media event
     MediaEvent,
     /// Personal event (injury, family, etc.)
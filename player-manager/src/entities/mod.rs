@@ -54,6 +54,13 @@ pub struct Player {
     pub tutorial_state: std::collections::HashMap<String, bool>,
 }
 
+impl Player {
+    /// Overall ability: a weighted average of technical (35%), physical (25%) and mental (40%) attributes
+    pub fn ability_score(&self) -> f32 {
+        self.technical.average() * 0.35 + self.physical.average() * 0.25 + self.mental.average() * 0.40
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TechnicalAttributes {
     pub dribbling: u8,
@@ -133,7 +140,7 @@ pub struct HiddenAttributes {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Contract {
-    pub club_id: Uuid,
+    pub club_id: Uuid, // Nil while the player is a free agent
     pub wage: f32,
     pub length_years: u8,
     pub squad_role: SquadRole,
@@ -141,6 +148,27 @@ pub struct Contract {
     pub performance_bonuses: Vec<Bonus>,
     pub contract_end_date: NaiveDate,
     pub league_strength: f32, // 0-100, affects reputation conversion
+    /// Set while the player is out on loan; `club_id` is then the loan club
+    #[serde(default)]
+    pub loan: Option<Loan>,
+}
+
+/// A loan spell away from the parent club
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Loan {
+    pub parent_club_id: Uuid,
+    pub loan_club_id: Uuid,
+    #[serde(default)]
+    pub loan_manager_id: Uuid, // Manager who brought the player in at the loan club
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub promised_role: SquadRole,
+    pub promised_minutes_share: f32, // 0-1, share of the loan club's matches the player was promised
+    pub wage_split: f32,             // 0-1, share of the wage paid by the loan club
+    pub purchase_option: Option<f32>, // Fee at which the loan club can make the move permanent
+    pub recall_allowed: bool,
+    #[serde(default)]
+    pub appearances: u32, // Senior matches played for the loan club since the loan began
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -150,7 +178,7 @@ pub struct Bonus {
     pub achieved: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum BonusCondition {
     Goals(u32),
     Appearances(u32),
@@ -160,6 +188,17 @@ pub enum BonusCondition {
     IndividualAward(String),
 }
 
+/// Terms put on the table during contract talks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContractTerms {
+    pub wage: f32,
+    pub length_years: u8,
+    pub squad_role: SquadRole,
+    pub release_clause: Option<f32>,
+    pub signing_on_fee: f32,
+    pub performance_bonuses: Vec<Bonus>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CareerStats {
     pub seasons_played: u8,
@@ -173,9 +212,52 @@ pub struct CareerStats {
     pub season_stats: Vec<SeasonStats>,
     pub awards: Vec<Award>,
     pub trophies: Vec<Trophy>,
+    /// Stats for the season in progress, archived into `season_stats` at rollover
+    #[serde(default)]
+    pub current_season: SeasonStats,
+    #[serde(default)]
+    pub international_caps: u32,
+    #[serde(default)]
+    pub international_goals: u32,
+    #[serde(default)]
+    pub first_call_up: Option<NaiveDate>, // Date of the first senior national team call-up
+    /// Youth and reserve league stats, kept apart from senior football
+    #[serde(default)]
+    pub youth_season: SeasonStats,
+    #[serde(default)]
+    pub youth_history: Vec<SeasonStats>,
+    #[serde(default)]
+    pub youth_form: Vec<f32>, // Recent youth match ratings, watched by the manager
+    /// Earlier spells at other clubs during the season in progress, e.g. before a loan
+    #[serde(default)]
+    pub club_spells: Vec<SeasonStats>,
+}
+
+impl CareerStats {
+    /// Records a match in both the current season and the career totals
+    pub fn record_match(&mut self, stats: &PlayerMatchStats, rating: f32, goals_conceded: u32) {
+        let season = &mut self.current_season;
+        season.average_rating = (season.average_rating * season.appearances as f32 + rating)
+            / (season.appearances + 1) as f32;
+        season.appearances += 1;
+        season.goals += stats.goals as u32;
+        season.assists += stats.assists as u32;
+        season.yellow_cards += stats.yellow_cards as u32;
+        season.red_cards += stats.red_cards as u32;
+        season.goals_conceded += goals_conceded;
+
+        self.average_rating = (self.average_rating * self.total_appearances as f32 + rating)
+            / (self.total_appearances + 1) as f32;
+        self.total_appearances += 1;
+        self.total_goals += stats.goals as u32;
+        self.total_assists += stats.assists as u32;
+        self.total_yellow_cards += stats.yellow_cards as u32;
+        self.total_red_cards += stats.red_cards as u32;
+        self.highest_rating = self.highest_rating.max(rating);
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SeasonStats {
     pub season_year: String, // e.g., "2023-24"
     pub appearances: u32,
@@ -186,6 +268,8 @@ pub struct SeasonStats {
     pub average_rating: f32,
     pub team_finish_position: Option<u8>,
     pub goals_conceded: u32, // For goalkeepers
+    #[serde(default)]
+    pub club_id: Option<Uuid>, // Club the stats were recorded for
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -336,6 +420,85 @@ pub struct Team {
     pub tactical_identity: String, // Added tactical identity field
 }
 
+impl Team {
+    /// The club's manager, who heads the staff list
+    ///
+    /// Clubs with no staff on record are dealt with through the club itself.
+    pub fn manager_id(&self) -> Uuid {
+        self.staff.first().copied().unwrap_or(self.id)
+    }
+}
+
+/// A football agent who represents players in transfers and contract talks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Agent {
+    pub id: Uuid,
+    pub name: String,
+    pub negotiation_skill: u8, // 1-100, how much extra they squeeze out of clubs
+    pub greed: u8,             // 1-100, how much they chase their own cut
+    pub network_reach: u8,     // 1-100, how many clubs they have contacts at
+    pub loyalty: u8,           // 1-100, how closely they follow the client's wishes
+    pub fee_percentage: f32,   // Share of the first year's earnings taken as a fee
+    pub network: Vec<Uuid>,    // Club IDs the agent has contacts at
+}
+
+/// A country's senior national team, drawn from players of that nationality
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NationalTeam {
+    pub id: Uuid,
+    pub nationality: String, // Matches `Player::nationality`
+    pub name: String,
+    pub manager: NationalTeamManager,
+    pub squad: Vec<Uuid>, // Players in the most recent call-up
+}
+
+/// How a national team manager picks a squad
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NationalTeamManager {
+    pub form_weight: f32, // 0-1, how much current form counts against reputation
+    pub squad_size: usize,
+}
+
+/// A match between two national teams
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InternationalFixture {
+    pub id: Uuid,
+    pub home_team: Uuid, // National team IDs
+    pub away_team: Uuid,
+    pub date: NaiveDate,
+    pub match_type: InternationalMatchType,
+    pub result: Option<MatchResult>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum InternationalMatchType {
+    Friendly,
+    Qualifier,
+    Tournament, // Finals of a World Cup or continental championship
+}
+
+/// A World Cup or continental championship, from the qualifying draw to the final
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InternationalTournament {
+    pub id: Uuid,
+    pub kind: TournamentKind,
+    pub name: String, // e.g., "World Cup 2030"
+    pub year: i32, // Year of the summer finals
+    pub qualifying_groups: Vec<Competition>, // Played during the international breaks before the finals
+    pub qualified: Vec<Uuid>,
+    pub finals_groups: Vec<Competition>,
+    pub knockout: Vec<InternationalFixture>,
+    pub remaining: Vec<Uuid>, // Teams still alive in the knockout rounds
+    pub player_stats: Vec<PlayerCompetitionStats>, // Finals only
+    pub winner: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum TournamentKind {
+    WorldCup,
+    ContinentalChampionship,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Finances {
     pub balance: f32,
@@ -365,6 +528,12 @@ pub struct Competition {
     pub season_start: NaiveDate,
     pub season_end: NaiveDate,
     pub current_season: CurrentSeason, // Added current season field
+    /// Criteria used to separate teams level on points, applied in order
+    #[serde(default = "TiebreakRule::goal_difference_first")]
+    pub tiebreak_rules: Vec<TiebreakRule>,
+    /// Per-player totals for this season, used for the leaderboards
+    #[serde(default)]
+    pub player_stats: Vec<PlayerCompetitionStats>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -372,10 +541,30 @@ pub enum CompetitionType {
     League,
     Knockout,
     GroupAndKnockout,
+    YouthLeague,   // Under-18s
+    ReserveLeague, // Under-21s
+}
+
+impl CompetitionType {
+    /// Oldest age allowed at the start of the season, for age-limited competitions
+    pub fn age_limit(&self) -> Option<u8> {
+        match self {
+            CompetitionType::YouthLeague => Some(17),
+            CompetitionType::ReserveLeague => Some(20),
+            _ => None,
+        }
+    }
+
+    /// Whether this is a youth or reserve competition rather than senior football
+    pub fn is_youth(&self) -> bool {
+        self.age_limit().is_some()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CurrentSeason {
+    #[serde(default)]
+    pub year: String, // e.g., "2026-27"
     pub is_active: bool,
     pub current_matchday: u32,
     pub start_date: NaiveDate,
@@ -393,6 +582,8 @@ pub struct Fixture {
     pub status: MatchStatus,
     pub result: Option<MatchResult>,
     pub matchday: u32, // Added matchday field
+    #[serde(default)]
+    pub postponed_from: Option<NaiveDate>, // Original date if the fixture was moved
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -408,6 +599,109 @@ pub struct Standing {
     pub points: u8,
     pub form: Vec<FormResult>, // Last 5 results
     pub goal_difference: i32, // Added goal difference field
+    #[serde(default)]
+    pub away_goals_for: u32,
+    #[serde(default)]
+    pub fair_play_points: i32, // Deductions for cards, 0 is a clean record
+    #[serde(default)]
+    pub lot: Option<u32>, // Drawn the first time lots are needed, higher ranks first
+}
+
+/// A player's running totals in one competition season
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PlayerCompetitionStats {
+    pub player_id: Uuid,
+    pub team_id: Uuid,
+    pub appearances: u32,
+    pub goals: u32,
+    pub assists: u32,
+    pub yellow_cards: u32,
+    pub red_cards: u32,
+    pub clean_sheets: u32, // Goalkeepers only
+    pub rating_total: f32, // Sum of match ratings, see `average_rating`
+    pub monthly: Vec<MonthlyStats>,
+}
+
+impl PlayerCompetitionStats {
+    pub fn average_rating(&self) -> f32 {
+        if self.appearances == 0 {
+            0.0
+        } else {
+            self.rating_total / self.appearances as f32
+        }
+    }
+}
+
+/// A player's totals for one calendar month, used for player of the month
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MonthlyStats {
+    pub year: i32,
+    pub month: u32,
+    pub appearances: u32,
+    pub goals: u32,
+    pub assists: u32,
+    pub rating_total: f32,
+}
+
+impl MonthlyStats {
+    pub fn average_rating(&self) -> f32 {
+        if self.appearances == 0 {
+            0.0
+        } else {
+            self.rating_total / self.appearances as f32
+        }
+    }
+}
+
+/// A criterion for ordering teams that finish level on points
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum TiebreakRule {
+    GoalDifference,
+    GoalsFor,
+    HeadToHeadPoints,         // Points in matches between the tied teams only
+    HeadToHeadGoalDifference, // Goal difference in matches between the tied teams only
+    HeadToHeadGoalsFor,       // Goals scored in matches between the tied teams only
+    AwayGoals,
+    Wins,
+    FairPlay,
+    DrawingOfLots,
+}
+
+impl TiebreakRule {
+    /// Overall record first (Premier League style)
+    pub fn goal_difference_first() -> Vec<TiebreakRule> {
+        vec![
+            TiebreakRule::GoalDifference,
+            TiebreakRule::GoalsFor,
+        ]
+    }
+
+    /// Head-to-head record before the overall record (La Liga / Serie A style)
+    pub fn head_to_head_first() -> Vec<TiebreakRule> {
+        vec![
+            TiebreakRule::HeadToHeadPoints,
+            TiebreakRule::HeadToHeadGoalDifference,
+            TiebreakRule::GoalDifference,
+            TiebreakRule::GoalsFor,
+            TiebreakRule::FairPlay,
+            TiebreakRule::DrawingOfLots,
+        ]
+    }
+
+    /// Continental group stage criteria
+    pub fn group_stage() -> Vec<TiebreakRule> {
+        vec![
+            TiebreakRule::HeadToHeadPoints,
+            TiebreakRule::HeadToHeadGoalDifference,
+            TiebreakRule::HeadToHeadGoalsFor,
+            TiebreakRule::GoalDifference,
+            TiebreakRule::GoalsFor,
+            TiebreakRule::AwayGoals,
+            TiebreakRule::Wins,
+            TiebreakRule::FairPlay,
+            TiebreakRule::DrawingOfLots,
+        ]
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -5,6 +5,9 @@ pub mod ui;
 pub mod save;
 pub mod utils;
 
+#[cfg(test)]
+mod test_support;
+
 pub use entities::*;
 pub use core::*;
 pub use systems::*;
@@ -3,13 +3,26 @@ use player_manager::entities::{
     SquadRole, HiddenAttributes, Finances, Facilities, 
     CurrentSeason
 };
-use player_manager::core::{TimeEngine, EventEngine, game_state::GameState};
+use player_manager::core::{TimeEngine, EventEngine, game_state::{season_start_year, CareerPriority, GameState, TransferWindows}};
 use player_manager::ui::ConsoleUI;
+use player_manager::systems::{AgentEngine, CompetitionEngine, PriorityEngine, SquadEngine, YouthEngine};
 use chrono::{NaiveDate, Datelike};
 use std::collections::HashMap;
 use std::io::{self, Write};
 use uuid::Uuid;
 
+/// Agents looking for clients when a career starts
+const STARTING_AGENTS: usize = 12;
+/// The clubs in the world when a career starts: name, city and reputation; the player starts at the first
+const STARTING_CLUBS: [(&str, &str, f32); 6] = [
+    ("Manchester United", "Manchester", 90.0),
+    ("Aston Villa", "Birmingham", 78.0),
+    ("Everton", "Liverpool", 72.0),
+    ("Brentford", "London", 66.0),
+    ("Fulham", "London", 64.0),
+    ("Burnley", "Burnley", 55.0),
+];
+
 fn main() {
     println!("⚽ Starting From Boots to Ballon d'Or - Football Career Simulator!");
     println!("--------------------------------------------------");
@@ -83,27 +96,57 @@ fn main() {
         }
     };
 
+    // Get career priorities
+    println!("What matters most to you in your career?");
+    for (i, priority) in CareerPriority::ALL.iter().enumerate() {
+        println!("{}. {:?}", i + 1, priority);
+    }
+    print!("Rank up to three, most important first (e.g., 2 3 1): ");
+    io::stdout().flush().unwrap();
+    let mut ranking_str = String::new();
+    io::stdin().read_line(&mut ranking_str).expect("Failed to read line");
+    let ranking: Vec<CareerPriority> = ranking_str
+        .split_whitespace()
+        .filter_map(|choice| choice.parse::<usize>().ok())
+        .filter_map(|choice| CareerPriority::ALL.get(choice.wrapping_sub(1)).copied())
+        .collect();
+
     // Initialize all core systems
     let time_engine = TimeEngine::new(chrono::Utc::now());
     let event_engine = EventEngine::new();
     
     // Initialize teams and competitions first to get IDs
-    let team = create_sample_team();
-    let team_id = team.id;
-    let teams = vec![team];
-    let competitions = vec![create_sample_competition(team_id)];
+    let teams: Vec<Team> = STARTING_CLUBS
+        .iter()
+        .map(|(club_name, city, reputation)| create_sample_team(club_name, city, *reputation))
+        .collect();
+    let team_id = teams[0].id;
+    let mut league = create_sample_competition(teams.iter().map(|team| team.id).collect());
+    CompetitionEngine::new().initialize_season(&mut league);
+    let mut competitions = YouthEngine::new().create_youth_leagues(&league);
+    competitions.insert(0, league);
     
     // Create a starting player with the correct club ID
     let player = create_starting_player(name.to_string(), nationality.to_string(), age, position, team_id);
     
     // Create initial game state
-    let game_state = GameState::new(player, team_id);
+    let mut game_state = GameState::new(player, team_id);
+    game_state.career_priorities = PriorityEngine::new().starting_priorities(&ranking, &teams[0].country);
+    game_state.current_date = time_engine.current_date;
+    for country in teams.iter().map(|team| team.country.clone()) {
+        let windows = TransferWindows::for_country(&country, season_start_year(time_engine.current_date));
+        game_state.season.country_windows.insert(country, windows);
+    }
+    game_state.clubs = teams;
+    SquadEngine::new().populate_world(&mut game_state, time_engine.current_date.date_naive());
+    game_state.agents = AgentEngine::new().generate_agents(STARTING_AGENTS, &game_state.clubs);
+    game_state.competitions = competitions;
     
     // Initialize UI
     let mut ui = ConsoleUI::new(time_engine, event_engine);
     
     // Run the main game loop
-    ui.run_main_loop(game_state.player, teams, competitions);
+    ui.run_main_loop(&mut game_state);
     
     println!("Thanks for playing!");
 }
@@ -171,6 +214,7 @@ fn create_starting_player(name: String, nationality: String, age: u8, position:
             performance_bonuses: vec![],
             contract_end_date: NaiveDate::from_ymd_opt(2026, 6, 15).unwrap(),
             league_strength: 50.0,
+            loan: None,
         },
         career_stats: CareerStats {
             seasons_played: 0,
@@ -184,6 +228,14 @@ fn create_starting_player(name: String, nationality: String, age: u8, position:
             season_stats: vec![],
             awards: vec![],
             trophies: vec![],
+            current_season: player_manager::entities::SeasonStats::default(),
+            international_caps: 0,
+            international_goals: 0,
+            first_call_up: None,
+            youth_season: player_manager::entities::SeasonStats::default(),
+            youth_history: vec![],
+            youth_form: vec![],
+            club_spells: vec![],
         },
         relationships: HashMap::new(),
         injury_status: None,
@@ -192,45 +244,51 @@ fn create_starting_player(name: String, nationality: String, age: u8, position:
     }
 }
 
-fn create_sample_team() -> Team {
+fn create_sample_team(name: &str, city: &str, reputation: f32) -> Team {
+    // Money, facilities and crowds scale with the club's standing
+    let scale = reputation / 90.0;
+    let level = (reputation / 10.0).round() as u8;
     Team {
         id: Uuid::new_v4(),
-        name: "Manchester United".to_string(),
+        name: name.to_string(),
         country: "England".to_string(),
-        city: "Manchester".to_string(),
-        reputation: 90.0,
+        city: city.to_string(),
+        reputation,
         finances: Finances {
-            balance: 100_000_000.0,
-            weekly_wage_bill: 4_000_000.0,
-            revenue_per_week: 5_000_000.0,
-            debt: 500_000_000.0,
+            balance: 100_000_000.0 * scale * scale,
+            weekly_wage_bill: 4_000_000.0 * scale * scale,
+            revenue_per_week: 5_000_000.0 * scale * scale,
+            debt: 0.0,
         },
-        financial_power: 95.0,
+        financial_power: reputation + 5.0,
         youth_focus: 70.0,
         tactical_identity: "Attacking".to_string(),
-        facilities_quality: 90.0,
-        medical_quality: 85.0,
-        squad: vec![Uuid::new_v4()], // Just a dummy ID for now
-        staff: vec![],
-        youth_academy_level: 9,
+        facilities_quality: reputation,
+        medical_quality: reputation - 5.0,
+        squad: vec![], // Filled when the world is populated
+        staff: vec![Uuid::new_v4()], // The manager
+        youth_academy_level: level,
         facilities: Facilities {
-            training_ground_quality: 9,
-            stadium_capacity: 74000,
-            stadium_quality: 9,
-            youth_facilities: 9,
+            training_ground_quality: level,
+            stadium_capacity: (74000.0 * scale * scale) as u32,
+            stadium_quality: level,
+            youth_facilities: level,
         },
     }
 }
 
-fn create_sample_competition(team_id: Uuid) -> Competition {
+fn create_sample_competition(teams: Vec<Uuid>) -> Competition {
     Competition {
         id: Uuid::new_v4(),
         name: "Premier League".to_string(),
         country: "England".to_string(),
         competition_type: player_manager::entities::CompetitionType::League,
         level: 1,
-        teams: vec![team_id],
+        teams,
         current_season: CurrentSeason {
+            year: player_manager::utils::helpers::season_label(
+                player_manager::core::game_state::season_start_year(chrono::Utc::now()),
+            ),
             start_date: chrono::Utc::now().date_naive(),
             end_date: (chrono::Utc::now() + chrono::Duration::days(365)).date_naive(),
             current_matchday: 1,
@@ -240,5 +298,7 @@ fn create_sample_competition(team_id: Uuid) -> Competition {
         standings: vec![],
         season_start: chrono::Utc::now().date_naive(),
         season_end: (chrono::Utc::now() + chrono::Duration::days(365)).date_naive(),
+        tiebreak_rules: player_manager::entities::TiebreakRule::goal_difference_first(),
+        player_stats: vec![],
     }
 }
\ No newline at end of file
@@ -11,6 +11,12 @@ use crate::core::game_state::GameState;
 /// It supports multiple save slots and version migration
 pub struct SaveManager;
 
+impl Default for SaveManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl SaveManager {
     /// Creates a new SaveManager instance
     pub fn new() -> Self {
@@ -248,9 +254,10 @@ impl SaveManager {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::entities::{Position, Foot, CareerStats, SquadRole, Contract, HiddenAttributes};
+    use crate::entities::{Player, Position, Foot, CareerStats, SquadRole, Contract, HiddenAttributes};
     use chrono::NaiveDate;
     use std::collections::HashMap;
+    use uuid::Uuid;
 
     #[test]
     fn test_save_load_cycle() {
@@ -391,6 +398,7 @@ mod tests {
                 performance_bonuses: vec![],
                 contract_end_date: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
                 league_strength: 75.0,
+                loan: None,
             },
             career_stats: CareerStats {
                 seasons_played: 3,
@@ -404,6 +412,14 @@ mod tests {
                 season_stats: vec![],
                 awards: vec![],
                 trophies: vec![],
+                current_season: crate::entities::SeasonStats::default(),
+                international_caps: 0,
+                international_goals: 0,
+                first_call_up: None,
+                youth_season: crate::entities::SeasonStats::default(),
+                youth_history: vec![],
+                youth_form: vec![],
+                club_spells: vec![],
             },
             relationships: HashMap::new(),
             injury_status: None,
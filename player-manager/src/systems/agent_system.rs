@@ -0,0 +1,379 @@
+// src/systems/agent_system.rs
+use chrono::NaiveDate;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::core::game_state::{AgentResponse, CareerPriorities, GameState, NegotiationResult, PlayerResponse, TransferNegotiation};
+use crate::entities::{Agent, ContractTerms, Player, Team};
+use crate::systems::transfer_system::{NegotiationPreferences, TransferInterest, TransferOffer};
+use crate::systems::{PriorityEngine, TransferEngine};
+
+/// First names and surnames combined into generated agents' names
+const AGENT_FIRST_NAMES: [&str; 8] = ["Marco", "Sofia", "Jonas", "Amara", "Luis", "Hannah", "Viktor", "Yusuf"];
+const AGENT_SURNAMES: [&str; 8] = ["Bellini", "Mendes", "Keller", "Okafor", "Ferreira", "Doyle", "Novak", "Demir"];
+/// Relationship damage done by firing an agent
+const FIRING_RELATIONSHIP_PENALTY: f32 = 30.0;
+/// Weeks of wages counted as a year's earnings when working out fees
+const WEEKS_PER_YEAR: f32 = 52.0;
+
+/// The AgentEngine manages the agents who represent players
+/// Agents take a fee, open doors at clubs in their network and negotiate on the player's behalf
+pub struct AgentEngine;
+
+impl Default for AgentEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AgentEngine {
+    /// Creates a new AgentEngine instance
+    pub fn new() -> Self {
+        AgentEngine
+    }
+
+    /// Generates agents with random personalities and contacts among the given clubs
+    ///
+    /// Every agent gets a different name; once the name combinations run out they are numbered.
+    pub fn generate_agents(&self, count: usize, clubs: &[Team]) -> Vec<Agent> {
+        let mut rng = rand::thread_rng();
+        let mut names: Vec<String> = AGENT_FIRST_NAMES
+            .iter()
+            .flat_map(|first| AGENT_SURNAMES.iter().map(move |surname| format!("{} {}", first, surname)))
+            .collect();
+        names.shuffle(&mut rng);
+
+        (0..count)
+            .map(|i| {
+                let greed = rng.gen_range(10..=95);
+                let network_reach: u8 = rng.gen_range(10..=95);
+                let contacts = ((clubs.len() as f32) * network_reach as f32 / 100.0).round() as usize;
+                let network = clubs
+                    .choose_multiple(&mut rng, contacts.max(1).min(clubs.len()))
+                    .map(|club| club.id)
+                    .collect();
+
+                Agent {
+                    id: Uuid::new_v4(),
+                    name: match i / names.len() {
+                        0 => names[i].clone(),
+                        round => format!("{} {}", names[i % names.len()], round + 1),
+                    },
+                    negotiation_skill: rng.gen_range(10..=95),
+                    greed,
+                    network_reach,
+                    loyalty: rng.gen_range(10..=95),
+                    fee_percentage: self.fee_percentage_for(greed),
+                    network,
+                }
+            })
+            .collect()
+    }
+
+    /// Fee an agent charges, from 5% for the modest up to 15% for the greediest
+    pub fn fee_percentage_for(&self, greed: u8) -> f32 {
+        5.0 + greed.min(100) as f32 / 10.0
+    }
+
+    /// Whether an agent is willing to take a player on as a client
+    ///
+    /// Well-connected agents only represent players with the reputation to match.
+    pub fn will_represent(&self, agent: &Agent, player: &Player) -> bool {
+        let reputation = player.local_reputation.max(player.international_reputation);
+        reputation + 40.0 >= agent.network_reach as f32
+    }
+
+    /// The agent currently representing the player character
+    pub fn current_agent<'a>(&self, game_state: &'a GameState) -> Option<&'a Agent> {
+        let agent_id = game_state.player_agent?;
+        game_state.agents.iter().find(|agent| agent.id == agent_id)
+    }
+
+    /// Hires an agent to represent the player character
+    pub fn hire_agent(&self, game_state: &mut GameState, agent_id: Uuid) -> AgentHireOutcome {
+        if game_state.player_agent.is_some() {
+            return AgentHireOutcome::AlreadyRepresented;
+        }
+
+        let Some(agent) = game_state.agents.iter().find(|agent| agent.id == agent_id) else {
+            return AgentHireOutcome::NotFound;
+        };
+
+        if !self.will_represent(agent, &game_state.player) {
+            return AgentHireOutcome::Declined(format!(
+                "{} only represents players with a bigger reputation",
+                agent.name
+            ));
+        }
+
+        game_state.player_agent = Some(agent_id);
+        AgentHireOutcome::Hired
+    }
+
+    /// Fires the player character's agent, souring the relationship with them
+    ///
+    /// Returns the ID of the agent who was let go.
+    pub fn fire_agent(&self, game_state: &mut GameState) -> Option<Uuid> {
+        let agent_id = game_state.player_agent.take()?;
+
+        let relationship = game_state.player.relationships.entry(agent_id).or_insert(50.0);
+        *relationship = (*relationship - FIRING_RELATIONSHIP_PENALTY).max(0.0);
+
+        Some(agent_id)
+    }
+
+    /// The agent's cut of a deal: a share of the first year's wages plus the signing-on fee
+    pub fn agent_fee(&self, agent: &Agent, terms: &ContractTerms) -> f32 {
+        (terms.wage * WEEKS_PER_YEAR + terms.signing_on_fee) * agent.fee_percentage / 100.0
+    }
+
+    /// Talks the player up to clubs in the agent's network
+    ///
+    /// Network clubs still need a real positional gap, but a well-connected agent
+    /// lifts their interest.
+    pub fn generate_interest(
+        &self,
+        agent: &Agent,
+        player: &Player,
+        all_teams: &[Team],
+        all_players: &[Player],
+        current_club_id: Uuid,
+        today: NaiveDate,
+    ) -> Vec<TransferInterest> {
+        let transfer_engine = TransferEngine::new();
+        let boost = 1.0 + agent.network_reach as f32 / 200.0;
+
+        all_teams
+            .iter()
+            .filter(|team| team.id != current_club_id && agent.network.contains(&team.id))
+            .filter_map(|team| transfer_engine.evaluate_club_interest(player, team, all_players, boost, today))
+            .map(|mut interest| {
+                interest.reasons.push(format!("Brought to the table by {}", agent.name));
+                interest
+            })
+            .collect()
+    }
+
+    /// The priorities an agent actually negotiates with
+    ///
+    /// Loyal agents follow the client's wishes. Agents whose greed outweighs their
+    /// loyalty chase a bigger deal, and with it a bigger fee, even when that goes
+    /// against what the player asked for.
+    pub fn agent_priorities(&self, agent: &Agent, stated: &NegotiationPreferences) -> (NegotiationPreferences, Vec<String>) {
+        let mut priorities = stated.clone();
+        let mut overridden = Vec::new();
+
+        if agent.greed <= agent.loyalty {
+            return (priorities, overridden);
+        }
+
+        if !priorities.prefer_higher_wage {
+            priorities.prefer_higher_wage = true;
+            overridden.push(format!("{} pushed for a higher wage", agent.name));
+        }
+        if !priorities.prefer_longer_contract {
+            priorities.prefer_longer_contract = true;
+            overridden.push(format!("{} pushed for a longer contract", agent.name));
+        }
+        if priorities.prefer_playing_time && agent.greed - agent.loyalty >= 30 {
+            priorities.prefer_playing_time = false;
+            overridden.push(format!("{} dropped the playing-time demand to close the deal", agent.name));
+        }
+
+        (priorities, overridden)
+    }
+
+    /// Negotiates a contract on the player's behalf
+    ///
+    /// A skilled agent convinces the club it needs the player more than it thinks,
+    /// which stretches the club's limits.
+    pub fn negotiate_on_behalf(
+        &self,
+        agent: &Agent,
+        player: &Player,
+        club: &Team,
+        offer: &TransferOffer,
+        stated_preferences: &NegotiationPreferences,
+        positional_need: f32,
+    ) -> AgentNegotiation {
+        let (priorities, overridden_priorities) = self.agent_priorities(agent, stated_preferences);
+        let pitched_need = (positional_need + (agent.negotiation_skill as f32 - 50.0) * 0.4).clamp(0.0, 100.0);
+
+        let mut negotiation = TransferEngine::new().negotiate_contract(player, club, offer, &priorities, pitched_need);
+        negotiation.player_response = Some(PlayerResponse::LetAgentHandle);
+        negotiation.agent_response = Some(match negotiation.final_result {
+            Some(NegotiationResult::Accepted) => AgentResponse::Accepted,
+            Some(NegotiationResult::Rejected) | Some(NegotiationResult::Withdrawn) => AgentResponse::Rejected,
+            None => AgentResponse::Negotiating,
+        });
+
+        let agent_fee = negotiation
+            .agreed_terms
+            .as_ref()
+            .map(|terms| self.agent_fee(agent, terms))
+            .unwrap_or(0.0);
+
+        AgentNegotiation {
+            negotiation,
+            agent_fee,
+            overridden_priorities,
+        }
+    }
+
+    /// Negotiates a contract on the player's behalf, going in with the demands their career priorities make
+    pub fn negotiate_for_priorities(
+        &self,
+        agent: &Agent,
+        player: &Player,
+        club: &Team,
+        offer: &TransferOffer,
+        priorities: &CareerPriorities,
+        positional_need: f32,
+    ) -> AgentNegotiation {
+        let stated = PriorityEngine::new().negotiation_preferences(priorities);
+        self.negotiate_on_behalf(agent, player, club, offer, &stated, positional_need)
+    }
+}
+
+/// Outcome of trying to hire an agent
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum AgentHireOutcome {
+    Hired,
+    AlreadyRepresented, // Fire the current agent first
+    Declined(String),
+    NotFound,
+}
+
+/// Contract talks an agent ran on the player's behalf
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentNegotiation {
+    pub negotiation: TransferNegotiation,
+    pub agent_fee: f32,                     // Zero if no deal was agreed
+    pub overridden_priorities: Vec<String>, // Where the agent went against the player's wishes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{create_test_player, create_test_team};
+    
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_hire_and_fire_agent() {
+        let engine = AgentEngine::new();
+        let mut player = create_test_player();
+        player.local_reputation = 20.0;
+        player.international_reputation = 10.0;
+        let mut game_state = GameState::new(player, Uuid::new_v4());
+        let superagent = create_test_agent(50, 50, 95, 50, vec![]);
+        let modest = create_test_agent(50, 50, 30, 50, vec![]);
+        game_state.agents = vec![superagent.clone(), modest.clone()];
+
+        assert!(matches!(engine.hire_agent(&mut game_state, superagent.id), AgentHireOutcome::Declined(_)));
+        assert_eq!(engine.hire_agent(&mut game_state, Uuid::new_v4()), AgentHireOutcome::NotFound);
+        assert_eq!(engine.hire_agent(&mut game_state, modest.id), AgentHireOutcome::Hired);
+        assert_eq!(engine.current_agent(&game_state).unwrap().id, modest.id);
+        assert_eq!(engine.hire_agent(&mut game_state, modest.id), AgentHireOutcome::AlreadyRepresented);
+
+        assert_eq!(engine.fire_agent(&mut game_state), Some(modest.id));
+        assert!(game_state.player_agent.is_none());
+        assert_eq!(game_state.player.relationships[&modest.id], 20.0);
+        assert_eq!(engine.fire_agent(&mut game_state), None);
+    }
+
+    #[test]
+    fn test_generated_agents_have_unique_names() {
+        let agents = AgentEngine::new().generate_agents(100, &[]);
+        let names: std::collections::HashSet<&str> = agents.iter().map(|agent| agent.name.as_str()).collect();
+        assert_eq!(names.len(), 100);
+    }
+
+    #[test]
+    fn test_greedy_agent_overrides_priorities() {
+        let engine = AgentEngine::new();
+        let stated = NegotiationPreferences {
+            prefer_longer_contract: false,
+            prefer_higher_wage: false,
+            prefer_prestige_club: false,
+            prefer_playing_time: true,
+        };
+
+        let loyal = create_test_agent(50, 20, 50, 90, vec![]);
+        let (priorities, overridden) = engine.agent_priorities(&loyal, &stated);
+        assert!(overridden.is_empty());
+        assert!(!priorities.prefer_higher_wage && priorities.prefer_playing_time);
+
+        let greedy = create_test_agent(50, 90, 50, 20, vec![]);
+        let (priorities, overridden) = engine.agent_priorities(&greedy, &stated);
+        assert_eq!(overridden.len(), 3);
+        assert!(priorities.prefer_higher_wage && priorities.prefer_longer_contract);
+        assert!(!priorities.prefer_playing_time);
+
+        // Greedier agents also charge more
+        assert_eq!(engine.fee_percentage_for(90), 14.0);
+        assert!(engine.fee_percentage_for(greedy.greed) > engine.fee_percentage_for(loyal.greed));
+    }
+
+    #[test]
+    fn test_agent_generates_interest_from_network() {
+        let engine = AgentEngine::new();
+        let player = create_test_player();
+        let current = create_test_team();
+        let contact = create_test_team();
+        let outsider = create_test_team();
+        let agent = create_test_agent(50, 50, 80, 50, vec![current.id, contact.id]);
+        let teams = vec![current.clone(), contact.clone(), outsider];
+        let today = NaiveDate::from_ymd_opt(2025, 8, 1).unwrap();
+
+        let interests = engine.generate_interest(&agent, &player, &teams, &[], current.id, today);
+
+        assert_eq!(interests.len(), 1);
+        assert_eq!(interests[0].club_id, contact.id);
+        assert!(interests[0].reasons.iter().any(|r| r.contains(&agent.name)));
+    }
+
+    #[test]
+    fn test_negotiation_skill_stretches_club() {
+        let engine = AgentEngine::new();
+        let player = create_test_player();  // Ambitious enough to demand a key role
+        let club = create_test_team();
+        let offer = TransferEngine::new().generate_transfer_offer(&player, &club, &player.contract, NaiveDate::from_ymd_opt(2025, 8, 1).unwrap());
+        let preferences = NegotiationPreferences {
+            prefer_longer_contract: false,
+            prefer_higher_wage: false,
+            prefer_prestige_club: false,
+            prefer_playing_time: false,
+        };
+
+        let skilled = create_test_agent(95, 30, 50, 80, vec![]);
+        let talks = engine.negotiate_on_behalf(&skilled, &player, &club, &offer, &preferences, 50.0);
+        assert!(matches!(talks.negotiation.final_result, Some(NegotiationResult::Accepted)));
+        assert!(matches!(talks.negotiation.player_response, Some(PlayerResponse::LetAgentHandle)));
+        assert!(matches!(talks.negotiation.agent_response, Some(AgentResponse::Accepted)));
+        let terms = talks.negotiation.agreed_terms.as_ref().unwrap();
+        assert_eq!(talks.agent_fee, engine.agent_fee(&skilled, terms));
+        assert!(talks.agent_fee > 0.0);
+
+        let clumsy = create_test_agent(10, 30, 50, 80, vec![]);
+        let talks = engine.negotiate_on_behalf(&clumsy, &player, &club, &offer, &preferences, 50.0);
+        assert!(matches!(talks.negotiation.agent_response, Some(AgentResponse::Rejected)));
+        assert_eq!(talks.agent_fee, 0.0);
+    }
+
+    // Helper functions for tests
+    fn create_test_agent(skill: u8, greed: u8, reach: u8, loyalty: u8, network: Vec<Uuid>) -> Agent {
+        Agent {
+            id: Uuid::new_v4(),
+            name: "Test Agent".to_string(),
+            negotiation_skill: skill,
+            greed,
+            network_reach: reach,
+            loyalty,
+            fee_percentage: AgentEngine::new().fee_percentage_for(greed),
+            network,
+        }
+    }
+}
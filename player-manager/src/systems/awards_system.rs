@@ -0,0 +1,387 @@
+// src/systems/awards_system.rs
+use uuid::Uuid;
+
+use crate::core::game_state::{AwardResult, GameState, NomineeVotes};
+use crate::entities::{Award, Competition, CompetitionType, Position};
+use crate::systems::reputation_system::TeamSuccess;
+use crate::systems::{CompetitionEngine, LeaderboardEngine, ReputationEngine};
+
+/// Appearances needed in a league to be considered for its awards
+const MIN_NOMINEE_APPEARANCES: u32 = 10;
+/// Oldest age that still counts for the young player award
+const YOUNG_PLAYER_MAX_AGE: u8 = 21;
+/// Number of nominees on each published shortlist
+const SHORTLIST_SIZE: usize = 5;
+
+/// The AwardsEngine runs the end-of-season awards ceremony
+/// It scores nominees across every league, publishes the voting and hands out awards
+pub struct AwardsEngine;
+
+impl Default for AwardsEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AwardsEngine {
+    /// Creates a new AwardsEngine instance
+    pub fn new() -> Self {
+        AwardsEngine
+    }
+
+    /// Votes on every award for the season, then gives the winners their awards and reputation
+    pub fn run_ceremony(&self, game_state: &mut GameState, season: &str) -> Vec<AwardResult> {
+        let mut results = Vec::new();
+        let mut world_nominees: Vec<Nominee> = Vec::new();
+        let mut seen_leagues: Vec<Uuid> = Vec::new();
+
+        let leagues: Vec<&Competition> = game_state.leagues.iter()
+            .chain(game_state.competitions.iter())
+            .filter(|competition| matches!(competition.competition_type, CompetitionType::League))
+            .collect();
+
+        for league in leagues {
+            if seen_leagues.contains(&league.id) {
+                continue;
+            }
+            seen_leagues.push(league.id);
+
+            let nominees = self.score_nominees(game_state, league);
+            results.extend(self.vote_league_awards(league, &nominees));
+
+            // A player's best league campaign counts toward the world award
+            for nominee in nominees {
+                match world_nominees.iter_mut().find(|n| n.player_id == nominee.player_id) {
+                    Some(existing) if existing.score < nominee.score => *existing = nominee,
+                    Some(_) => {}
+                    None => world_nominees.push(nominee),
+                }
+            }
+        }
+
+        if let Some(result) = self.vote("World Player of the Year", "World", world_nominees) {
+            results.push(result);
+        }
+
+        self.present_awards(game_state, &results, season);
+        results
+    }
+
+    /// Scores every eligible player in a league with the award contender formula
+    pub fn score_nominees(&self, game_state: &GameState, league: &Competition) -> Vec<Nominee> {
+        let leaderboards = LeaderboardEngine::new();
+        let reputation = ReputationEngine::new();
+
+        league.player_stats.iter()
+            .filter(|stats| stats.appearances >= MIN_NOMINEE_APPEARANCES)
+            .filter_map(|stats| {
+                let player = game_state.find_player(stats.player_id)?;
+                let performance = leaderboards.season_performance(league, stats.player_id)?;
+                let team_success = self.team_success(game_state, league, stats.team_id);
+
+                Some(Nominee {
+                    player_id: player.id,
+                    team_id: stats.team_id,
+                    position: player.primary_position,
+                    age: player.age,
+                    goals: stats.goals,
+                    score: reputation.calculate_award_contender_score(player, performance, team_success),
+                })
+            })
+            .collect()
+    }
+
+    /// Runs the player of the season, young player, top scorer and team of the season votes
+    fn vote_league_awards(&self, league: &Competition, nominees: &[Nominee]) -> Vec<AwardResult> {
+        let mut results = Vec::new();
+
+        if let Some(result) = self.vote("League Best Player", &league.name, nominees.to_vec()) {
+            results.push(result);
+        }
+
+        let young: Vec<Nominee> = nominees.iter()
+            .filter(|nominee| nominee.age <= YOUNG_PLAYER_MAX_AGE)
+            .cloned()
+            .collect();
+        if let Some(result) = self.vote("Best Young Player", &league.name, young) {
+            results.push(result);
+        }
+
+        // The golden boot goes on goals alone, with the leaderboard's tiebreak
+        let scorers = LeaderboardEngine::new().top_scorers(league, SHORTLIST_SIZE);
+        if let Some(leader) = scorers.first() {
+            let total_goals: f32 = scorers.iter().map(|entry| entry.value).sum();
+            results.push(AwardResult {
+                name: "Top Scorer".to_string(),
+                competition: league.name.clone(),
+                winners: vec![leader.player_id],
+                voting: scorers.iter()
+                    .map(|entry| NomineeVotes {
+                        player_id: entry.player_id,
+                        team_id: entry.team_id,
+                        score: entry.value,
+                        vote_share: entry.value / total_goals * 100.0,
+                    })
+                    .collect(),
+            });
+        }
+
+        if let Some(result) = self.team_of_the_season(league, nominees) {
+            results.push(result);
+        }
+
+        results
+    }
+
+    /// Picks the best 4-3-3 from the nominees, leaving a line short if there aren't enough players
+    fn team_of_the_season(&self, league: &Competition, nominees: &[Nominee]) -> Option<AwardResult> {
+        let mut ranked = nominees.to_vec();
+        ranked.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+        let mut eleven: Vec<Nominee> = Vec::new();
+        for (line, places) in [(Line::Goalkeeper, 1), (Line::Defence, 4), (Line::Midfield, 3), (Line::Attack, 3)] {
+            eleven.extend(ranked.iter().filter(|nominee| line_for(nominee.position) == line).take(places).cloned());
+        }
+        if eleven.is_empty() {
+            return None;
+        }
+
+        let voting = self.publish_votes(&eleven);
+        Some(AwardResult {
+            name: "Team of the Season".to_string(),
+            competition: league.name.clone(),
+            winners: eleven.iter().map(|nominee| nominee.player_id).collect(),
+            voting,
+        })
+    }
+
+    /// Shortlists the top nominees and awards the prize to the highest score
+    fn vote(&self, name: &str, competition: &str, mut nominees: Vec<Nominee>) -> Option<AwardResult> {
+        nominees.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| b.goals.cmp(&a.goals)));
+        nominees.truncate(SHORTLIST_SIZE);
+        let winner = nominees.first()?.player_id;
+
+        Some(AwardResult {
+            name: name.to_string(),
+            competition: competition.to_string(),
+            winners: vec![winner],
+            voting: self.publish_votes(&nominees),
+        })
+    }
+
+    /// Splits the jury vote between nominees in proportion to their scores
+    fn publish_votes(&self, nominees: &[Nominee]) -> Vec<NomineeVotes> {
+        let total: f32 = nominees.iter().map(|nominee| nominee.score.max(0.0)).sum();
+
+        nominees.iter()
+            .map(|nominee| NomineeVotes {
+                player_id: nominee.player_id,
+                team_id: nominee.team_id,
+                score: nominee.score,
+                vote_share: if total > 0.0 { nominee.score.max(0.0) / total * 100.0 } else { 0.0 },
+            })
+            .collect()
+    }
+
+    /// Gives each winner an `Award` entry and the matching reputation boost
+    fn present_awards(&self, game_state: &mut GameState, results: &[AwardResult], season: &str) {
+        let reputation = ReputationEngine::new();
+
+        for result in results {
+            for winner in &result.winners {
+                if let Some(player) = game_state.find_player_mut(*winner) {
+                    player.career_stats.awards.push(Award {
+                        name: result.name.clone(),
+                        season: season.to_string(),
+                        competition: result.competition.clone(),
+                    });
+                    reputation.apply_award(player, &result.name);
+                }
+            }
+        }
+    }
+
+    /// How a club's season went, for the contender score
+    fn team_success(&self, game_state: &GameState, league: &Competition, team_id: Uuid) -> TeamSuccess {
+        let competition_engine = CompetitionEngine::new();
+        let position = league.standings.iter()
+            .find(|standing| standing.team_id == team_id)
+            .map(|standing| standing.position as usize)
+            .unwrap_or(0);
+        let won_cup = game_state.competitions.iter()
+            .filter(|competition| !matches!(competition.competition_type, CompetitionType::League))
+            .any(|competition| competition_engine.get_competition_winner(competition) == Some(team_id));
+        let relegation_line = league.standings.len().saturating_sub(3);
+
+        match position {
+            1 => TeamSuccess::LeagueWinner,
+            _ if won_cup => TeamSuccess::CupWinner,
+            2..=4 => TeamSuccess::Top4,
+            p if p > relegation_line && relegation_line > 0 => TeamSuccess::Relegation,
+            _ => TeamSuccess::MidTable,
+        }
+    }
+}
+
+/// A player in the running for the season's awards
+#[derive(Debug, Clone)]
+pub struct Nominee {
+    pub player_id: Uuid,
+    pub team_id: Uuid,
+    pub position: Position,
+    pub age: u8,
+    pub goals: u32,
+    pub score: f32,
+}
+
+/// Lines of the team of the season
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Line {
+    Goalkeeper,
+    Defence,
+    Midfield,
+    Attack,
+}
+
+fn line_for(position: Position) -> Line {
+    match position {
+        Position::GK => Line::Goalkeeper,
+        Position::RB | Position::CB | Position::LB | Position::FB => Line::Defence,
+        Position::DM | Position::RM | Position::CM | Position::LM | Position::AM => Line::Midfield,
+        Position::RW | Position::LW | Position::CF | Position::SS => Line::Attack,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::{Player, PlayerCompetitionStats};
+    use crate::test_support::{create_league, create_test_player};
+
+    #[test]
+    fn test_ceremony_hands_out_awards() {
+        let engine = AwardsEngine::new();
+        let (champions, strugglers) = (Uuid::new_v4(), Uuid::new_v4());
+
+        let star = create_player(Position::CF, 26, 40.0);
+        let youngster = create_player(Position::RW, 19, 20.0);
+        let keeper = create_player(Position::GK, 30, 30.0);
+        let fringe = create_player(Position::CM, 24, 30.0);
+
+        let league = create_league(
+            vec![(champions, 1), (strugglers, 2)],
+            vec![
+                create_stats(star.id, champions, 34, 28, 7, 7.9),
+                create_stats(youngster.id, strugglers, 30, 9, 6, 7.3),
+                create_stats(keeper.id, champions, 34, 0, 0, 7.0),
+                // Not enough appearances to be nominated
+                create_stats(fringe.id, strugglers, 4, 3, 0, 8.5),
+            ],
+        );
+
+        let mut game_state = GameState::new(create_player(Position::CM, 17, 5.0), strugglers);
+        game_state.players = vec![star.clone(), youngster.clone(), keeper.clone(), fringe.clone()];
+        game_state.leagues.push(league.clone());
+        game_state.competitions.push(league);
+
+        let results = engine.run_ceremony(&mut game_state, "2026-27");
+        let winner_of = |name: &str| {
+            results.iter().find(|result| result.name == name).map(|result| result.winners.clone()).unwrap()
+        };
+
+        assert_eq!(winner_of("League Best Player"), vec![star.id]);
+        assert_eq!(winner_of("Best Young Player"), vec![youngster.id]);
+        assert_eq!(winner_of("Top Scorer"), vec![star.id]);
+        assert_eq!(winner_of("World Player of the Year"), vec![star.id]);
+
+        // Too few players for a full XI, so the lines are left short
+        let team_of_the_season = winner_of("Team of the Season");
+        assert_eq!(team_of_the_season.len(), 3);
+        assert!(!team_of_the_season.contains(&fringe.id));
+
+        // Each league is counted once even though it is listed twice
+        assert_eq!(results.iter().filter(|result| result.name == "League Best Player").count(), 1);
+
+        // Published voting shares add up to the whole jury
+        for result in &results {
+            let total: f32 = result.voting.iter().map(|votes| votes.vote_share).sum();
+            assert!((total - 100.0).abs() < 0.1, "{} shares sum to {}", result.name, total);
+        }
+
+        let star_after = game_state.find_player(star.id).unwrap();
+        let award_names: Vec<&str> = star_after.career_stats.awards.iter().map(|award| award.name.as_str()).collect();
+        assert_eq!(award_names.len(), 4);
+        assert!(award_names.contains(&"World Player of the Year"));
+        assert!(star_after.career_stats.awards.iter().all(|award| award.season == "2026-27"));
+        // 40 + league best player 15 + top scorer 10 + team of the season 5 + world player 25
+        assert_eq!(star_after.international_reputation, 95.0);
+
+        let fringe_after = game_state.find_player(fringe.id).unwrap();
+        assert!(fringe_after.career_stats.awards.is_empty());
+        assert_eq!(fringe_after.international_reputation, 30.0);
+    }
+
+    #[test]
+    fn test_player_character_can_win_awards() {
+        let engine = AwardsEngine::new();
+        let club = Uuid::new_v4();
+        let player = create_player(Position::AM, 18, 10.0);
+        let league = create_league(vec![(club, 1)], vec![create_stats(player.id, club, 20, 12, 10, 7.8)]);
+
+        let mut game_state = GameState::new(player, club);
+        game_state.leagues.push(league);
+
+        engine.run_ceremony(&mut game_state, "2026-27");
+
+        let awards: Vec<&str> = game_state.player.career_stats.awards.iter().map(|award| award.name.as_str()).collect();
+        assert!(awards.contains(&"Best Young Player"));
+        assert!(awards.contains(&"League Best Player"));
+        assert!(game_state.player.international_reputation > 10.0);
+    }
+
+    #[test]
+    fn test_team_success_from_final_position() {
+        let engine = AwardsEngine::new();
+        let teams: Vec<Uuid> = (0..8).map(|_| Uuid::new_v4()).collect();
+        let league = create_league(
+            teams.iter().enumerate().map(|(i, team)| (*team, i as u8 + 1)).collect(),
+            vec![],
+        );
+        let game_state = GameState::new(create_player(Position::CM, 17, 5.0), teams[0]);
+
+        assert!(matches!(engine.team_success(&game_state, &league, teams[0]), TeamSuccess::LeagueWinner));
+        assert!(matches!(engine.team_success(&game_state, &league, teams[3]), TeamSuccess::Top4));
+        assert!(matches!(engine.team_success(&game_state, &league, teams[4]), TeamSuccess::MidTable));
+        assert!(matches!(engine.team_success(&game_state, &league, teams[5]), TeamSuccess::Relegation));
+    }
+
+    fn create_player(position: Position, age: u8, international_reputation: f32) -> Player {
+        let mut player = create_test_player();
+        player.id = Uuid::new_v4();
+        player.primary_position = position;
+        player.age = age;
+        player.international_reputation = international_reputation;
+        player.career_stats.awards = vec![];
+        player
+    }
+
+    fn create_stats(
+        player_id: Uuid,
+        team_id: Uuid,
+        appearances: u32,
+        goals: u32,
+        assists: u32,
+        average_rating: f32,
+    ) -> PlayerCompetitionStats {
+        PlayerCompetitionStats {
+            player_id,
+            team_id,
+            appearances,
+            goals,
+            assists,
+            rating_total: average_rating * appearances as f32,
+            ..PlayerCompetitionStats::default()
+        }
+    }
+
+}
@@ -0,0 +1,307 @@
+// src/systems/bonus_system.rs
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::core::game_state::{GameState, LedgerEntryKind, PlayerFinances};
+use crate::entities::{
+    Bonus, BonusCondition, Competition, CompetitionType, Player, PlayerCompetitionStats,
+};
+use crate::systems::CompetitionEngine;
+
+/// The BonusEngine pays out the performance bonuses written into the player's contract
+/// It checks each bonus against the season's stats and results, and resets them when a new season starts
+pub struct BonusEngine;
+
+impl Default for BonusEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BonusEngine {
+    /// Creates a new BonusEngine instance
+    pub fn new() -> Self {
+        BonusEngine
+    }
+
+    /// Marks every bonus whose condition has been met as achieved and pays it into the player's finances
+    ///
+    /// Each bonus is paid once a season. Counting bonuses pay as soon as the target is reached,
+    /// league position and cup bonuses once the competition is over.
+    pub fn evaluate_bonuses(
+        &self,
+        player: &mut Player,
+        competitions: &[Competition],
+        finances: &mut PlayerFinances,
+        today: NaiveDate,
+    ) -> Vec<BonusPayout> {
+        let mut payouts = Vec::new();
+
+        for index in 0..player.contract.performance_bonuses.len() {
+            let bonus = &player.contract.performance_bonuses[index];
+            if bonus.achieved || !self.bonus_progress(player, bonus, competitions).met {
+                continue;
+            }
+
+            let payout = BonusPayout {
+                condition: bonus.condition.clone(),
+                amount: bonus.amount,
+            };
+            finances.record(
+                today,
+                LedgerEntryKind::Bonus,
+                self.describe_condition(&payout.condition),
+                payout.amount,
+            );
+            player.contract.performance_bonuses[index].achieved = true;
+            payouts.push(payout);
+        }
+        payouts
+    }
+
+    /// Settles the finished season's bonuses and clears them for the new season
+    pub fn settle_season(&self, game_state: &mut GameState, today: NaiveDate) -> Vec<BonusPayout> {
+        let payouts = self.evaluate_bonuses(
+            &mut game_state.player,
+            &game_state.competitions,
+            &mut game_state.player_finances,
+            today,
+        );
+        for bonus in game_state.player.contract.performance_bonuses.iter_mut() {
+            bonus.achieved = false;
+        }
+        payouts
+    }
+
+    /// How far the player is towards a bonus this season
+    pub fn bonus_progress(
+        &self,
+        player: &Player,
+        bonus: &Bonus,
+        competitions: &[Competition],
+    ) -> BonusProgress {
+        let club_id = player.contract.club_id;
+        let senior = || {
+            competitions
+                .iter()
+                .filter(|competition| !competition.competition_type.is_youth())
+        };
+        let player_total = |count: fn(&PlayerCompetitionStats) -> u32| -> u32 {
+            senior()
+                .flat_map(|competition| competition.player_stats.iter())
+                .filter(|stats| stats.player_id == player.id)
+                .map(count)
+                .sum()
+        };
+        let counting = |current: u32, target: u32| BonusProgress {
+            current,
+            target,
+            met: current >= target,
+        };
+
+        match &bonus.condition {
+            BonusCondition::Goals(target) => counting(player_total(|stats| stats.goals), *target),
+            BonusCondition::Appearances(target) => {
+                counting(player_total(|stats| stats.appearances), *target)
+            }
+            BonusCondition::CleanSheets(target) => {
+                counting(player_total(|stats| stats.clean_sheets), *target)
+            }
+            BonusCondition::LeaguePosition(target) => {
+                let league = self.current_league(player, competitions);
+                let position = league
+                    .and_then(|league| {
+                        league
+                            .standings
+                            .iter()
+                            .find(|standing| standing.team_id == club_id)
+                    })
+                    .map_or(0, |standing| standing.position);
+                let finished = league
+                    .is_some_and(|league| CompetitionEngine::new().is_competition_finished(league));
+                BonusProgress {
+                    current: position as u32,
+                    target: *target as u32,
+                    met: finished && position > 0 && position <= *target,
+                }
+            }
+            BonusCondition::CupWin => {
+                let competition_engine = CompetitionEngine::new();
+                let won = senior()
+                    .filter(|competition| {
+                        !matches!(competition.competition_type, CompetitionType::League)
+                    })
+                    .any(|competition| {
+                        competition_engine.get_competition_winner(competition) == Some(club_id)
+                    });
+                counting(won as u32, 1)
+            }
+            BonusCondition::IndividualAward(name) => {
+                let season = self
+                    .current_league(player, competitions)
+                    .map(|league| league.current_season.year.clone())
+                    .unwrap_or_else(|| player.career_stats.current_season.season_year.clone());
+                let won = player
+                    .career_stats
+                    .awards
+                    .iter()
+                    .any(|award| award.name == *name && award.season == season);
+                counting(won as u32, 1)
+            }
+        }
+    }
+
+    /// A short description of a bonus condition, for the contract screen and the ledger
+    pub fn describe_condition(&self, condition: &BonusCondition) -> String {
+        match condition {
+            BonusCondition::Goals(target) => format!("{} goals", target),
+            BonusCondition::Appearances(target) => format!("{} appearances", target),
+            BonusCondition::CleanSheets(target) => format!("{} clean sheets", target),
+            BonusCondition::LeaguePosition(target) => format!("Finish in the top {}", target),
+            BonusCondition::CupWin => "Win a cup".to_string(),
+            BonusCondition::IndividualAward(name) => format!("Win {}", name),
+        }
+    }
+
+    /// The senior league the player's club plays in
+    fn current_league<'a>(
+        &self,
+        player: &Player,
+        competitions: &'a [Competition],
+    ) -> Option<&'a Competition> {
+        competitions.iter().find(|competition| {
+            matches!(competition.competition_type, CompetitionType::League)
+                && competition.teams.contains(&player.contract.club_id)
+        })
+    }
+}
+
+/// Where the player stands against one bonus
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BonusProgress {
+    pub current: u32, // League position for position bonuses, 1 once a cup or award is won
+    pub target: u32,
+    pub met: bool,
+}
+
+/// A bonus paid out to the player
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BonusPayout {
+    pub condition: BonusCondition,
+    pub amount: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::{Award, MatchStatus};
+    use crate::test_support::{create_fixture, create_league, create_test_player, date};
+    use uuid::Uuid;
+
+    #[test]
+    fn test_counting_bonuses_pay_once_when_reached() {
+        let engine = BonusEngine::new();
+        let mut player = create_test_player();
+        player.contract.performance_bonuses = vec![
+            create_bonus(BonusCondition::Goals(10), 5000.0),
+            create_bonus(BonusCondition::Appearances(20), 2000.0),
+        ];
+        let stats = PlayerCompetitionStats {
+            player_id: player.id,
+            team_id: player.contract.club_id,
+            appearances: 15,
+            goals: 10,
+            ..PlayerCompetitionStats::default()
+        };
+        let competitions = vec![create_league(
+            vec![(player.contract.club_id, 5)],
+            vec![stats],
+        )];
+        let mut finances = PlayerFinances::new();
+
+        let payouts =
+            engine.evaluate_bonuses(&mut player, &competitions, &mut finances, date(2027, 2, 1));
+        assert_eq!(payouts.len(), 1);
+        assert_eq!(payouts[0].condition, BonusCondition::Goals(10));
+        assert_eq!(finances.balance, 5000.0);
+        assert_eq!(finances.ledger[0].kind, LedgerEntryKind::Bonus);
+        assert!(player.contract.performance_bonuses[0].achieved);
+
+        let progress = engine.bonus_progress(
+            &player,
+            &player.contract.performance_bonuses[1],
+            &competitions,
+        );
+        assert_eq!(
+            progress,
+            BonusProgress {
+                current: 15,
+                target: 20,
+                met: false
+            }
+        );
+
+        // An achieved bonus is not paid twice
+        assert!(engine
+            .evaluate_bonuses(&mut player, &competitions, &mut finances, date(2027, 2, 8))
+            .is_empty());
+        assert_eq!(finances.balance, 5000.0);
+    }
+
+    #[test]
+    fn test_season_bonuses_pay_at_the_end_and_reset() {
+        let engine = BonusEngine::new();
+        let mut player = create_test_player();
+        let club_id = player.contract.club_id;
+        player.contract.performance_bonuses = vec![
+            create_bonus(BonusCondition::LeaguePosition(4), 10000.0),
+            create_bonus(
+                BonusCondition::IndividualAward("Top Scorer".to_string()),
+                8000.0,
+            ),
+        ];
+        player.career_stats.awards.push(Award {
+            name: "Top Scorer".to_string(),
+            season: "2026-27".to_string(),
+            competition: "Test League".to_string(),
+        });
+        let mut league = create_league(vec![(club_id, 3), (Uuid::new_v4(), 1)], vec![]);
+        league
+            .fixtures
+            .push(create_fixture(club_id, Uuid::new_v4(), date(2027, 5, 20)));
+        let mut game_state = GameState::new(player, club_id);
+        game_state.competitions = vec![league];
+
+        // Third place with a game to go isn't enough yet
+        let payouts = engine.evaluate_bonuses(
+            &mut game_state.player,
+            &game_state.competitions,
+            &mut game_state.player_finances,
+            date(2027, 5, 1),
+        );
+        assert_eq!(payouts.len(), 1);
+        assert_eq!(game_state.player_finances.balance, 8000.0);
+
+        game_state.competitions[0].fixtures[0].status = MatchStatus::Finished;
+        let payouts = engine.settle_season(&mut game_state, date(2027, 6, 1));
+        assert_eq!(payouts.len(), 1);
+        assert_eq!(payouts[0].condition, BonusCondition::LeaguePosition(4));
+        assert_eq!(game_state.player_finances.balance, 18000.0);
+        assert!(game_state
+            .player
+            .contract
+            .performance_bonuses
+            .iter()
+            .all(|bonus| !bonus.achieved));
+    }
+
+    // Helper functions for tests
+    fn create_bonus(condition: BonusCondition, amount: f32) -> Bonus {
+        Bonus {
+            condition,
+            amount,
+            achieved: false,
+        }
+    }
+
+}
@@ -0,0 +1,425 @@
+// src/systems/calendar_system.rs
+use chrono::{Datelike, NaiveDate, Weekday};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::core::time_engine::{EventPriority, ScheduledEvent, ScheduledEventType, TimeEngine};
+use crate::entities::{Competition, CompetitionType, MatchStatus, Player};
+use crate::utils::constants::MIN_REST_DAYS;
+use crate::utils::helpers::kick_off;
+
+/// The CalendarEngine keeps the fixture list playable across all competitions
+/// It postpones clashing or weather-hit fixtures, finds new midweek dates and tracks congestion
+pub struct CalendarEngine;
+
+impl Default for CalendarEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CalendarEngine {
+    /// Creates a new CalendarEngine instance
+    pub fn new() -> Self {
+        CalendarEngine
+    }
+
+    /// Postpones league fixtures that clash with a cup tie for either club
+    pub fn resolve_cup_clashes(&self, competitions: &mut [Competition]) -> Vec<Postponement> {
+        let mut postponements = Vec::new();
+
+        // Each postponement frees a date, so keep going until the calendar is clean
+        while let Some(fixture_id) = self.find_clash(competitions) {
+            match self.postpone_fixture(competitions, fixture_id, PostponementReason::CupTie) {
+                Some(postponement) => postponements.push(postponement),
+                None => break,
+            }
+        }
+
+        postponements
+    }
+
+    /// Rolls for bad weather on every fixture scheduled for the given day
+    pub fn check_weather(&self, competitions: &mut [Competition], date: NaiveDate) -> Vec<Postponement> {
+        let mut rng = rand::thread_rng();
+        let chance = self.weather_postponement_chance(date);
+
+        let fixture_ids: Vec<Uuid> = competitions.iter()
+            .flat_map(|competition| competition.fixtures.iter())
+            .filter(|fixture| fixture.scheduled_date == date && fixture.status == MatchStatus::Scheduled)
+            .map(|fixture| fixture.id)
+            .collect();
+
+        fixture_ids.into_iter()
+            .filter(|_| rng.gen::<f32>() < chance)
+            .filter_map(|fixture_id| self.postpone_fixture(competitions, fixture_id, PostponementReason::Weather))
+            .collect()
+    }
+
+    /// Postpones a fixture and moves it to the next free midweek slot, cancelling it if none is left
+    pub fn postpone_fixture(
+        &self,
+        competitions: &mut [Competition],
+        fixture_id: Uuid,
+        reason: PostponementReason,
+    ) -> Option<Postponement> {
+        let (competition_index, fixture_index) = competitions.iter().enumerate()
+            .find_map(|(c, competition)| {
+                competition.fixtures.iter()
+                    .position(|fixture| fixture.id == fixture_id)
+                    .map(|f| (c, f))
+            })?;
+
+        // Take the fixture out of the calendar before looking for a new date
+        let fixture = &mut competitions[competition_index].fixtures[fixture_index];
+        if fixture.status != MatchStatus::Scheduled {
+            return None;
+        }
+        fixture.status = MatchStatus::Postponed;
+        let original_date = fixture.scheduled_date;
+        let teams = [fixture.home_team, fixture.away_team];
+        let latest = competitions[competition_index].current_season.end_date;
+
+        let new_date = self.next_free_midweek_slot(competitions, teams, original_date, latest);
+
+        let fixture = &mut competitions[competition_index].fixtures[fixture_index];
+        fixture.postponed_from.get_or_insert(original_date);
+        match new_date {
+            Some(date) => {
+                fixture.scheduled_date = date;
+                fixture.status = MatchStatus::Scheduled;
+            }
+            None => fixture.status = MatchStatus::Cancelled,
+        }
+
+        Some(Postponement {
+            fixture_id,
+            competition_id: competitions[competition_index].id,
+            reason,
+            original_date,
+            new_date,
+        })
+    }
+
+    /// Finds the first Tuesday or Wednesday after a date when both teams are rested and free
+    pub fn next_free_midweek_slot(
+        &self,
+        competitions: &[Competition],
+        teams: [Uuid; 2],
+        after: NaiveDate,
+        latest: NaiveDate,
+    ) -> Option<NaiveDate> {
+        after.succ_opt()?
+            .iter_days()
+            .take_while(|date| *date <= latest)
+            .filter(|date| matches!(date.weekday(), Weekday::Tue | Weekday::Wed))
+            .find(|date| teams.iter().all(|team_id| self.is_team_available(competitions, *team_id, *date)))
+    }
+
+    /// Checks that a team has no senior match on the date or within its rest days either side
+    pub fn is_team_available(&self, competitions: &[Competition], team_id: Uuid, date: NaiveDate) -> bool {
+        !competitions.iter()
+            .filter(|competition| !competition.competition_type.is_youth())
+            .flat_map(|competition| competition.fixtures.iter())
+            .filter(|fixture| self.occupies_calendar(&fixture.status))
+            .filter(|fixture| fixture.home_team == team_id || fixture.away_team == team_id)
+            .any(|fixture| (fixture.scheduled_date - date).num_days().abs() <= MIN_REST_DAYS)
+    }
+
+    /// Counts a team's senior matches in the seven days from the given date
+    pub fn matches_in_week(&self, competitions: &[Competition], team_id: Uuid, week_start: NaiveDate) -> usize {
+        competitions.iter()
+            .filter(|competition| !competition.competition_type.is_youth())
+            .flat_map(|competition| competition.fixtures.iter())
+            .filter(|fixture| self.occupies_calendar(&fixture.status))
+            .filter(|fixture| fixture.home_team == team_id || fixture.away_team == team_id)
+            .filter(|fixture| {
+                let offset = (fixture.scheduled_date - week_start).num_days();
+                (0..7).contains(&offset)
+            })
+            .count()
+    }
+
+    /// How stretched a team is by the number of matches in a week
+    pub fn congestion_level(&self, matches_in_week: usize) -> CongestionLevel {
+        match matches_in_week {
+            0..=1 => CongestionLevel::Normal,
+            2 => CongestionLevel::Busy,
+            _ => CongestionLevel::Congested,
+        }
+    }
+
+    /// Adds the extra fatigue of a crowded week, returning the amount added
+    pub fn apply_congestion_fatigue(&self, player: &mut Player, matches_in_week: usize) -> f32 {
+        let extra_matches = matches_in_week.saturating_sub(1) as f32;
+        // Players with high stamina cope better with two games a week
+        let stamina_factor = 1.0 - (player.physical.stamina as f32 / 200.0);
+        let added = extra_matches * 12.0 * stamina_factor;
+
+        let before = player.fatigue;
+        player.fatigue = (player.fatigue + added).clamp(0.0, 100.0);
+        player.fatigue - before
+    }
+
+    /// Likelihood (0-1) of the manager resting the player this week
+    pub fn rotation_pressure(&self, player: &Player, matches_in_week: usize) -> f32 {
+        let congestion_factor = match self.congestion_level(matches_in_week) {
+            CongestionLevel::Normal => 0.0,
+            CongestionLevel::Busy => 0.25,
+            CongestionLevel::Congested => 0.5,
+        };
+        let fatigue_factor = player.fatigue / 100.0 * 0.5;
+
+        (congestion_factor + fatigue_factor).clamp(0.0, 1.0)
+    }
+
+    /// Moves the time engine's match day events to follow postponed fixtures
+    pub fn sync_time_engine(&self, postponements: &[Postponement], time_engine: &mut TimeEngine) {
+        for postponement in postponements {
+            let fixture_id = postponement.fixture_id;
+            let removed = time_engine.remove_events(|event| {
+                event.event_type == ScheduledEventType::MatchDay(fixture_id)
+            });
+
+            // Only the player's own club has its matches queued
+            if let (true, Some(new_date)) = (removed > 0, postponement.new_date) {
+                time_engine.schedule_event(ScheduledEvent::new(
+                    kick_off(new_date),
+                    ScheduledEventType::MatchDay(fixture_id),
+                    EventPriority::Medium,
+                    false,
+                ));
+            }
+        }
+    }
+
+    /// Chance of a fixture being called off, higher in the winter months
+    fn weather_postponement_chance(&self, date: NaiveDate) -> f32 {
+        match date.month() {
+            12 | 1 | 2 => 0.03,
+            11 | 3 => 0.01,
+            _ => 0.002,
+        }
+    }
+
+    /// Finds a fixture that leaves a team without its rest days, preferring to move league games
+    fn find_clash(&self, competitions: &[Competition]) -> Option<Uuid> {
+        // Youth and reserve sides are separate squads, so their games never clash with the seniors'
+        let active: Vec<(bool, &crate::entities::Fixture)> = competitions.iter()
+            .filter(|competition| !competition.competition_type.is_youth())
+            .flat_map(|competition| {
+                let is_league = matches!(competition.competition_type, CompetitionType::League);
+                competition.fixtures.iter().map(move |fixture| (is_league, fixture))
+            })
+            .filter(|(_, fixture)| fixture.status == MatchStatus::Scheduled)
+            .collect();
+
+        for (i, (first_is_league, first)) in active.iter().enumerate() {
+            for (second_is_league, second) in active.iter().skip(i + 1) {
+                let shares_team = [first.home_team, first.away_team].iter()
+                    .any(|team| *team == second.home_team || *team == second.away_team);
+                let too_close = (first.scheduled_date - second.scheduled_date).num_days().abs() <= MIN_REST_DAYS;
+                if !shares_team || !too_close || (*first_is_league && *second_is_league) {
+                    continue;
+                }
+
+                // Cup ties keep their date, the league game makes way
+                return Some(if *second_is_league || !*first_is_league { second.id } else { first.id });
+            }
+        }
+
+        None
+    }
+
+    /// Whether a fixture in this state still takes up a date
+    fn occupies_calendar(&self, status: &MatchStatus) -> bool {
+        !matches!(status, MatchStatus::Postponed | MatchStatus::Cancelled)
+    }
+}
+
+/// Why a fixture was moved
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum PostponementReason {
+    CupTie,
+    Weather,
+}
+
+/// A record of a fixture being moved or called off
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Postponement {
+    pub fixture_id: Uuid,
+    pub competition_id: Uuid,
+    pub reason: PostponementReason,
+    pub original_date: NaiveDate,
+    pub new_date: Option<NaiveDate>, // None if the fixture had to be cancelled
+}
+
+/// How crowded a team's week is
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum CongestionLevel {
+    Normal,
+    Busy,
+    Congested,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{create_competition, create_fixture, create_test_player, date};
+
+    #[test]
+    fn test_league_fixture_makes_way_for_cup_tie() {
+        let engine = CalendarEngine::new();
+        let (home, away, cup_opponent) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+
+        // Saturday 5 September 2026: league game and cup tie on the same day
+        let league_fixture = create_fixture(home, away, date(2026, 9, 5));
+        let cup_fixture = create_fixture(home, cup_opponent, date(2026, 9, 5));
+        let league_fixture_id = league_fixture.id;
+        let mut competitions = vec![
+            create_competition(CompetitionType::League, vec![league_fixture]),
+            create_competition(CompetitionType::Knockout, vec![cup_fixture]),
+        ];
+
+        let postponements = engine.resolve_cup_clashes(&mut competitions);
+
+        assert_eq!(postponements.len(), 1);
+        assert_eq!(postponements[0].fixture_id, league_fixture_id);
+        assert_eq!(postponements[0].reason, PostponementReason::CupTie);
+
+        // Moved to the following Tuesday, and the cup tie keeps its date
+        let moved = &competitions[0].fixtures[0];
+        assert_eq!(moved.scheduled_date, date(2026, 9, 8));
+        assert_eq!(moved.status, MatchStatus::Scheduled);
+        assert_eq!(moved.postponed_from, Some(date(2026, 9, 5)));
+        assert_eq!(competitions[1].fixtures[0].scheduled_date, date(2026, 9, 5));
+        assert!(engine.resolve_cup_clashes(&mut competitions).is_empty());
+    }
+
+    #[test]
+    fn test_youth_fixtures_never_clash_with_senior_games() {
+        let engine = CalendarEngine::new();
+        let (home, away) = (Uuid::new_v4(), Uuid::new_v4());
+
+        // The under-18s share the senior calendar, so both sides play on the same Saturday
+        let mut competitions = vec![
+            create_competition(CompetitionType::League, vec![create_fixture(home, away, date(2026, 9, 5))]),
+            create_competition(CompetitionType::YouthLeague, vec![create_fixture(home, away, date(2026, 9, 5))]),
+            create_competition(CompetitionType::ReserveLeague, vec![create_fixture(away, home, date(2026, 9, 6))]),
+        ];
+
+        assert!(engine.resolve_cup_clashes(&mut competitions).is_empty());
+        assert!(competitions.iter().all(|competition| competition.fixtures[0].status == MatchStatus::Scheduled));
+    }
+
+    #[test]
+    fn test_rescheduling_never_double_books_a_team() {
+        let engine = CalendarEngine::new();
+        let teams: Vec<Uuid> = (0..4).map(|_| Uuid::new_v4()).collect();
+
+        // Two league games clash with cup ties, and the first midweek is already taken
+        let mut competitions = vec![
+            create_competition(CompetitionType::League, vec![
+                create_fixture(teams[0], teams[1], date(2026, 9, 5)),
+                create_fixture(teams[2], teams[3], date(2026, 9, 5)),
+                create_fixture(teams[0], teams[2], date(2026, 9, 12)),
+            ]),
+            create_competition(CompetitionType::Knockout, vec![
+                create_fixture(teams[1], teams[3], date(2026, 9, 6)),
+                create_fixture(teams[0], teams[3], date(2026, 9, 9)),
+            ]),
+        ];
+
+        let postponements = engine.resolve_cup_clashes(&mut competitions);
+        assert_eq!(postponements.len(), 2);
+
+        for team in &teams {
+            let mut dates: Vec<NaiveDate> = competitions.iter()
+                .flat_map(|competition| competition.fixtures.iter())
+                .filter(|fixture| fixture.home_team == *team || fixture.away_team == *team)
+                .map(|fixture| fixture.scheduled_date)
+                .collect();
+            dates.sort();
+            for pair in dates.windows(2) {
+                assert!((pair[1] - pair[0]).num_days() > MIN_REST_DAYS, "{:?} plays on {:?}", team, pair);
+            }
+        }
+
+        for postponement in &postponements {
+            let new_date = postponement.new_date.unwrap();
+            assert!(matches!(new_date.weekday(), Weekday::Tue | Weekday::Wed));
+        }
+    }
+
+    #[test]
+    fn test_fixture_cancelled_when_no_date_remains() {
+        let engine = CalendarEngine::new();
+        let (home, away) = (Uuid::new_v4(), Uuid::new_v4());
+        let fixture = create_fixture(home, away, date(2027, 5, 29));
+        let fixture_id = fixture.id;
+        let mut competitions = vec![create_competition(CompetitionType::League, vec![fixture])];
+
+        // The season ends on 31 May, before the next midweek
+        let postponement = engine.postpone_fixture(&mut competitions, fixture_id, PostponementReason::Weather).unwrap();
+
+        assert_eq!(postponement.new_date, None);
+        assert_eq!(competitions[0].fixtures[0].status, MatchStatus::Cancelled);
+
+        // A cancelled fixture can't be postponed again
+        assert!(engine.postpone_fixture(&mut competitions, fixture_id, PostponementReason::Weather).is_none());
+    }
+
+    #[test]
+    fn test_congestion_raises_fatigue_and_rotation_pressure() {
+        let engine = CalendarEngine::new();
+        let (club, a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let competitions = vec![create_competition(CompetitionType::League, vec![
+            create_fixture(club, a, date(2026, 9, 5)),
+            create_fixture(b, club, date(2026, 9, 8)),
+            create_fixture(club, c, date(2026, 9, 11)),
+        ])];
+
+        assert_eq!(engine.matches_in_week(&competitions, club, date(2026, 9, 5)), 3);
+        assert_eq!(engine.matches_in_week(&competitions, club, date(2026, 9, 9)), 1);
+        assert_eq!(engine.congestion_level(1), CongestionLevel::Normal);
+        assert_eq!(engine.congestion_level(2), CongestionLevel::Busy);
+        assert_eq!(engine.congestion_level(3), CongestionLevel::Congested);
+
+        let mut player = create_test_player();
+        player.fatigue = 10.0;
+        assert_eq!(engine.apply_congestion_fatigue(&mut player, 1), 0.0);
+        let relaxed_pressure = engine.rotation_pressure(&player, 1);
+
+        let added = engine.apply_congestion_fatigue(&mut player, 3);
+        assert!(added > 0.0);
+        assert_eq!(player.fatigue, 10.0 + added);
+        assert!(engine.rotation_pressure(&player, 3) > relaxed_pressure);
+    }
+
+    #[test]
+    fn test_sync_time_engine_moves_match_day() {
+        let engine = CalendarEngine::new();
+        let (home, away) = (Uuid::new_v4(), Uuid::new_v4());
+        let fixture = create_fixture(home, away, date(2026, 9, 5));
+        let fixture_id = fixture.id;
+        let mut competitions = vec![create_competition(CompetitionType::League, vec![fixture])];
+
+        let mut time_engine = TimeEngine::new(kick_off(date(2026, 9, 1)));
+        time_engine.schedule_event(ScheduledEvent::new(
+            kick_off(date(2026, 9, 5)),
+            ScheduledEventType::MatchDay(fixture_id),
+            EventPriority::Medium,
+            false,
+        ));
+
+        let postponement = engine.postpone_fixture(&mut competitions, fixture_id, PostponementReason::Weather).unwrap();
+        engine.sync_time_engine(&[postponement], &mut time_engine);
+
+        assert_eq!(time_engine.event_queue.len(), 1);
+        let event = time_engine.event_queue.peek().unwrap();
+        assert_eq!(event.event_type, ScheduledEventType::MatchDay(fixture_id));
+        assert_eq!(event.scheduled_time, kick_off(date(2026, 9, 8)));
+    }
+
+}
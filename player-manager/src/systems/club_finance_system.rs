@@ -0,0 +1,287 @@
+// src/systems/club_finance_system.rs
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::core::game_state::GameState;
+use crate::entities::{Competition, CompetitionType, MatchStatus, Player, Team};
+use crate::systems::{CompetitionEngine, LoanEngine};
+
+/// Prize money for each place finished above the bottom of a league, by tier
+const LEAGUE_PRIZE_PER_PLACE: [f32; 4] = [2_000_000.0, 500_000.0, 100_000.0, 25_000.0];
+/// Weekly TV money for clubs in each league tier
+const TV_MONEY_PER_WEEK: [f32; 4] = [2_000_000.0, 300_000.0, 80_000.0, 30_000.0];
+/// Prize for winning a cup tie
+const CUP_ROUND_PRIZE: f32 = 500_000.0;
+/// Prize for winning a cup
+const CUP_WINNER_PRIZE: f32 = 5_000_000.0;
+/// Weekly interest charged on debt
+const WEEKLY_INTEREST_RATE: f32 = 0.001;
+/// Share of the debt paid back each week when the club has the cash
+const WEEKLY_REPAYMENT_RATE: f32 = 0.01;
+/// Weekly revenue at which a club has the full revenue share of financial power
+const FULL_POWER_REVENUE: f32 = 5_000_000.0;
+/// Net reserves (balance less debt) at which a club has the full reserves share of financial power
+const FULL_POWER_RESERVES: f32 = 100_000_000.0;
+/// How quickly financial power moves towards what the books say, each week
+const FINANCIAL_POWER_DRIFT: f32 = 0.1;
+
+/// The ClubFinanceEngine runs the clubs' books week to week
+/// Matchday, TV and prize money come in, wages and interest go out, and financial power follows the results
+pub struct ClubFinanceEngine;
+
+impl Default for ClubFinanceEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ClubFinanceEngine {
+    /// Creates a new ClubFinanceEngine instance
+    pub fn new() -> Self {
+        ClubFinanceEngine
+    }
+
+    /// Runs a week of every club's finances
+    ///
+    /// Home matches in the last seven days earn gate receipts and cup ties won earn prize money.
+    /// TV money depends on the league tier. Wages are paid for every player on the books, loans
+    /// split by their agreed share. A club that runs out of cash borrows, and pays interest until
+    /// it can pay the debt back. Transfer fees are settled when deals are done.
+    pub fn process_week(&self, game_state: &mut GameState, today: NaiveDate) -> Vec<ClubFinanceReport> {
+        let mut reports = Vec::new();
+
+        for index in 0..game_state.clubs.len() {
+            let club = &game_state.clubs[index];
+            let matchday_revenue = self.matchday_revenue(club, &game_state.competitions, today);
+            let tv_revenue = self.league_tier(club.id, &game_state.competitions)
+                .map_or(0.0, |tier| TV_MONEY_PER_WEEK[self.tier_index(tier)]);
+            let prize_money = self.cup_ties_won(club.id, &game_state.competitions, today) as f32 * CUP_ROUND_PRIZE;
+            let players = std::iter::once(&game_state.player).chain(game_state.players.iter());
+            let wages = self.wage_bill(club, players);
+
+            let club = &mut game_state.clubs[index];
+            let finances = &mut club.finances;
+            finances.weekly_wage_bill = wages;
+            finances.revenue_per_week = matchday_revenue + tv_revenue;
+            finances.balance += matchday_revenue + tv_revenue + prize_money - wages;
+
+            let interest = finances.debt * WEEKLY_INTEREST_RATE;
+            finances.debt += interest;
+            if finances.balance < 0.0 {
+                finances.debt += -finances.balance;
+                finances.balance = 0.0;
+            } else {
+                let repayment = (finances.debt * WEEKLY_REPAYMENT_RATE).min(finances.balance);
+                finances.debt -= repayment;
+                finances.balance -= repayment;
+            }
+            self.update_financial_power(club);
+
+            reports.push(ClubFinanceReport {
+                club_id: club.id,
+                matchday_revenue,
+                tv_revenue,
+                prize_money,
+                wages,
+                interest,
+                balance: club.finances.balance,
+                debt: club.finances.debt,
+            });
+        }
+        reports
+    }
+
+    /// Pays out league and cup prize money once the season is over
+    pub fn award_prize_money(&self, game_state: &mut GameState) {
+        let competition_engine = CompetitionEngine::new();
+
+        for competition in game_state.competitions.iter().filter(|competition| !competition.competition_type.is_youth()) {
+            if matches!(competition.competition_type, CompetitionType::League) {
+                let per_place = LEAGUE_PRIZE_PER_PLACE[self.tier_index(competition.level)];
+                let places = competition.standings.len() as f32;
+                for standing in &competition.standings {
+                    let prize = per_place * (places - standing.position as f32 + 1.0).max(0.0);
+                    if let Some(club) = game_state.clubs.iter_mut().find(|club| club.id == standing.team_id) {
+                        club.finances.balance += prize;
+                    }
+                }
+            } else if let Some(winner) = competition_engine.get_competition_winner(competition) {
+                if let Some(club) = game_state.clubs.iter_mut().find(|club| club.id == winner) {
+                    club.finances.balance += CUP_WINNER_PRIZE;
+                }
+            }
+        }
+    }
+
+    /// Whether a club owes more than it holds, which forces sales and rules out raises
+    pub fn in_debt(&self, club: &Team) -> bool {
+        club.finances.balance < 0.0 || club.finances.debt > club.finances.balance
+    }
+
+    /// Gate receipts from home matches played in the last seven days
+    fn matchday_revenue(&self, club: &Team, competitions: &[Competition], today: NaiveDate) -> f32 {
+        let home_matches = competitions.iter()
+            .flat_map(|competition| competition.fixtures.iter())
+            .filter(|fixture| fixture.home_team == club.id && fixture.status == MatchStatus::Finished)
+            .filter(|fixture| fixture.scheduled_date > today - Duration::days(7) && fixture.scheduled_date <= today)
+            .count();
+
+        let attendance = club.facilities.stadium_capacity as f32 * (0.5 + club.reputation / 200.0).min(1.0);
+        let ticket_price = 20.0 + 5.0 * club.facilities.stadium_quality as f32;
+        home_matches as f32 * attendance * ticket_price
+    }
+
+    /// Cup ties the club won in the last seven days
+    fn cup_ties_won(&self, club_id: Uuid, competitions: &[Competition], today: NaiveDate) -> usize {
+        competitions.iter()
+            .filter(|competition| !matches!(competition.competition_type, CompetitionType::League))
+            .filter(|competition| !competition.competition_type.is_youth())
+            .flat_map(|competition| competition.fixtures.iter())
+            .filter(|fixture| fixture.scheduled_date > today - Duration::days(7) && fixture.scheduled_date <= today)
+            .filter(|fixture| fixture.result.as_ref().is_some_and(|result| result.winner == Some(club_id)))
+            .count()
+    }
+
+    /// What the club pays its players each week; loan wages are split between the two clubs
+    ///
+    /// Clubs with no players modelled keep their listed wage bill.
+    fn wage_bill<'a>(&self, club: &Team, players: impl Iterator<Item = &'a Player>) -> f32 {
+        let loans = LoanEngine::new();
+        let mut modelled = false;
+        let mut wages = 0.0;
+        for player in players {
+            let (parent_share, loan_share) = loans.wage_contributions(player);
+            wages += match &player.contract.loan {
+                Some(loan) if loan.loan_club_id == club.id => loan_share,
+                Some(loan) if loan.parent_club_id == club.id => parent_share,
+                None if player.contract.club_id == club.id => parent_share,
+                _ => continue,
+            };
+            modelled = true;
+        }
+
+        if modelled {
+            wages
+        } else {
+            club.finances.weekly_wage_bill
+        }
+    }
+
+    /// Moves financial power towards what the club's revenue and reserves support
+    fn update_financial_power(&self, club: &mut Team) {
+        let finances = &club.finances;
+        let revenue_share = (finances.revenue_per_week / FULL_POWER_REVENUE).min(1.0) * 70.0;
+        let reserves_share = ((finances.balance - finances.debt) / FULL_POWER_RESERVES).clamp(-1.0, 1.0) * 30.0;
+        let target = (revenue_share + reserves_share).clamp(0.0, 100.0);
+        club.financial_power += (target - club.financial_power) * FINANCIAL_POWER_DRIFT;
+    }
+
+    /// The tier of the league a club plays in
+    fn league_tier(&self, club_id: Uuid, competitions: &[Competition]) -> Option<u8> {
+        competitions.iter()
+            .find(|competition| {
+                matches!(competition.competition_type, CompetitionType::League) && competition.teams.contains(&club_id)
+            })
+            .map(|league| league.level)
+    }
+
+    /// Index into the per-tier tables, with the fourth tier and below sharing the last entry
+    fn tier_index(&self, tier: u8) -> usize {
+        (tier.max(1) as usize - 1).min(LEAGUE_PRIZE_PER_PLACE.len() - 1)
+    }
+}
+
+/// A club's money over one week
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClubFinanceReport {
+    pub club_id: Uuid,
+    pub matchday_revenue: f32,
+    pub tv_revenue: f32,
+    pub prize_money: f32,
+    pub wages: f32,
+    pub interest: f32,
+    pub balance: f32,
+    pub debt: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::MatchResult;
+    use crate::systems::TransferEngine;
+    use crate::test_support::{create_fixture, create_league, create_test_player, create_test_team, date};
+
+    #[test]
+    fn test_weekly_revenue_wages_and_borrowing() {
+        let engine = ClubFinanceEngine::new();
+        let home = create_test_team();
+        let mut struggling = create_test_team();
+        struggling.finances.balance = 100000.0;
+        let mut player = create_test_player();
+        player.contract.club_id = struggling.id;
+        player.contract.wage = 3000000.0;
+        let mut league = create_league(vec![(home.id, 1), (struggling.id, 2)], vec![]);
+        let mut fixture = create_fixture(home.id, struggling.id, date(2026, 9, 5));
+        fixture.status = MatchStatus::Finished;
+        league.fixtures.push(fixture);
+        let mut game_state = GameState::new(player, struggling.id);
+        game_state.clubs = vec![home.clone(), struggling.clone()];
+        game_state.competitions = vec![league];
+
+        let reports = engine.process_week(&mut game_state, date(2026, 9, 7));
+
+        // 20,000 seats at 87.5% full and £50 a ticket, plus top-flight TV money
+        assert_eq!(reports[0].matchday_revenue, 875000.0);
+        assert_eq!(reports[0].tv_revenue, 2000000.0);
+        assert_eq!(reports[0].wages, home.finances.weekly_wage_bill);
+        assert_eq!(game_state.clubs[0].finances.revenue_per_week, 2875000.0);
+        assert!(game_state.clubs[0].finances.balance > home.finances.balance);
+
+        // Wages beyond the club's means are covered by borrowing
+        assert_eq!(reports[1].wages, 3000000.0);
+        assert_eq!(game_state.clubs[1].finances.balance, 0.0);
+        assert_eq!(game_state.clubs[1].finances.debt, 900000.0);
+        assert!(engine.in_debt(&game_state.clubs[1]));
+        assert!(game_state.clubs[1].financial_power < struggling.financial_power);
+    }
+
+    #[test]
+    fn test_prize_money_and_no_raises_in_debt() {
+        let engine = ClubFinanceEngine::new();
+        let champion = create_test_team();
+        let mut runner_up = create_test_team();
+        let mut cup = create_league(vec![], vec![]);
+        cup.competition_type = CompetitionType::Knockout;
+        let mut final_tie = create_fixture(champion.id, runner_up.id, date(2027, 5, 20));
+        final_tie.status = MatchStatus::Finished;
+        final_tie.result = Some(MatchResult {
+            home_score: 2,
+            away_score: 1,
+            winner: Some(champion.id),
+        });
+        cup.fixtures.push(final_tie);
+        cup.standings = vec![];
+        let mut game_state = GameState::new(create_test_player(), champion.id);
+        game_state.clubs = vec![champion.clone(), runner_up.clone()];
+        game_state.competitions = vec![create_league(vec![(champion.id, 1), (runner_up.id, 2)], vec![]), cup];
+
+        let reports = engine.process_week(&mut game_state, date(2027, 5, 22));
+        assert_eq!(reports[0].prize_money, CUP_ROUND_PRIZE);
+
+        let balance = game_state.clubs[1].finances.balance;
+        engine.award_prize_money(&mut game_state);
+        assert_eq!(game_state.clubs[1].finances.balance, balance + 2000000.0);
+
+        // A club in debt keeps the wage where it is at renewal time
+        runner_up.finances.debt = runner_up.finances.balance * 2.0;
+        let mut player = create_test_player();
+        player.form = 9.0;
+        let transfer = TransferEngine::new();
+        let renewal = transfer.generate_contract_renewal(&player, &runner_up, &player.contract, date(2027, 5, 22));
+        assert_eq!(renewal.offered_wage, player.contract.wage);
+        let renewal = transfer.generate_contract_renewal(&player, &champion, &player.contract, date(2027, 5, 22));
+        assert!(renewal.offered_wage > player.contract.wage);
+    }
+
+}
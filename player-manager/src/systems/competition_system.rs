@@ -1,14 +1,23 @@
 // src/systems/competition_system.rs
+use rand::Rng;
 use serde::{Deserialize, Serialize};
-
 use uuid::Uuid;
 
-use crate::entities::{Team, Match, Competition, Fixture, Standing, FormResult};
+use crate::entities::{
+    Team, Match, Competition, Fixture, Standing, FormResult, TiebreakRule, EventType
+};
+use crate::systems::LeaderboardEngine;
 
 /// The CompetitionEngine manages leagues, cups, standings, and schedules
 /// It handles team performance tracking and competition progression
 pub struct CompetitionEngine;
 
+impl Default for CompetitionEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl CompetitionEngine {
     /// Creates a new CompetitionEngine instance
     pub fn new() -> Self {
@@ -31,47 +40,86 @@ impl CompetitionEngine {
                 goals_against: 0,
                 goal_difference: 0,
                 form: vec![],
+                away_goals_for: 0,
+                fair_play_points: 0,
+                lot: None,
             })
             .collect();
+        competition.player_stats.clear();
         
         // Generate fixtures
         competition.fixtures = self.generate_fixtures(&competition.teams, competition.id, &competition.name);
+        self.schedule_fixtures(&mut competition.fixtures, competition.current_season.start_date);
         
         // Update season info
         competition.current_season.is_active = true;
         competition.current_season.current_matchday = 1;
     }
 
-    /// Generates fixtures for a round-robin competition
+    /// Generates fixtures for a double round-robin competition
     fn generate_fixtures(&self, teams: &[Uuid], competition_id: Uuid, _competition_name: &str) -> Vec<Fixture> {
         let mut fixtures = Vec::new();
-        
-        // Simple round-robin: each team plays every other team twice (home and away)
-        for i in 0..teams.len() {
-            for j in 0..teams.len() {
-                if i != j {
-                    // First leg (home team i, away team j)
-                    fixtures.push(Fixture {
-                        id: Uuid::new_v4(),
-                        competition_id: competition_id,
-                        home_team: teams[i],
-                        away_team: teams[j],
-                        scheduled_date: chrono::Utc::now().date_naive(), // Convert to NaiveDate
-                        venue: teams[i], // Home team's venue
-                        status: crate::entities::MatchStatus::Scheduled,
-                        result: None,
-                        matchday: (fixtures.len() as u32 / (teams.len() as u32 - 1) + 1),
-                    });
-                    
-                    // Second leg (home team j, away team i)
-                    // Removed to avoid duplicate fixtures (the loop handles both i,j and j,i)
+        if teams.len() < 2 {
+            return fixtures;
+        }
+
+        // Circle method: one team stays fixed while the rest rotate, so every team
+        // plays exactly once per matchday. Odd-sized leagues get a bye slot.
+        let mut rotation: Vec<Option<Uuid>> = teams.iter().copied().map(Some).collect();
+        if rotation.len() % 2 == 1 {
+            rotation.push(None);
+        }
+        let slots = rotation.len();
+        let rounds = (slots - 1) as u32;
+
+        for round in 0..rounds {
+            for i in 0..slots / 2 {
+                if let (Some(first), Some(second)) = (rotation[i], rotation[slots - 1 - i]) {
+                    // Alternate home advantage between rounds
+                    let (home, away) = if (round as usize + i).is_multiple_of(2) {
+                        (first, second)
+                    } else {
+                        (second, first)
+                    };
+
+                    // First leg in the first half of the season, return leg in the second
+                    fixtures.push(self.create_fixture(competition_id, home, away, round + 1));
+                    fixtures.push(self.create_fixture(competition_id, away, home, round + 1 + rounds));
                 }
             }
+
+            if let Some(last) = rotation.pop() {
+                rotation.insert(1, last);
+            }
         }
-        
+
+        fixtures.sort_by_key(|fixture| fixture.matchday);
         fixtures
     }
 
+    /// Creates a scheduled fixture for a matchday
+    fn create_fixture(&self, competition_id: Uuid, home_team: Uuid, away_team: Uuid, matchday: u32) -> Fixture {
+        Fixture {
+            id: Uuid::new_v4(),
+            competition_id,
+            home_team,
+            away_team,
+            scheduled_date: chrono::Utc::now().date_naive(),
+            venue: home_team, // Home team's venue
+            status: crate::entities::MatchStatus::Scheduled,
+            result: None,
+            matchday,
+            postponed_from: None,
+        }
+    }
+
+    /// Spreads matchdays one week apart from the season start date
+    fn schedule_fixtures(&self, fixtures: &mut [Fixture], start_date: chrono::NaiveDate) {
+        for fixture in fixtures.iter_mut() {
+            fixture.scheduled_date = start_date + chrono::Duration::weeks(fixture.matchday as i64 - 1);
+        }
+    }
+
     /// Processes a completed match result and updates competition standings
     pub fn process_match_result(
         &self,
@@ -81,32 +129,60 @@ impl CompetitionEngine {
         away_team: &Team,
     ) {
         if let Some((home_goals, away_goals)) = match_result.fulltime_score {
-            // Find the fixture and update its result
-            if let Some(fixture) = competition.fixtures.iter_mut()
-                .find(|f| f.id == match_result.id) {
-                fixture.result = Some(crate::entities::MatchResult {
-                    home_score: home_goals,
-                    away_score: away_goals,
-                    winner: if home_goals > away_goals {
-                        Some(home_team.id)
-                    } else if away_goals > home_goals {
-                        Some(away_team.id)
-                    } else {
-                        None // Draw
-                    },
-                });
-                fixture.status = crate::entities::MatchStatus::Finished;
-            }
-            
-            // Update standings for both teams
-            self.update_standings(competition, home_team.id, home_goals, away_goals, true);
-            self.update_standings(competition, away_team.id, away_goals, home_goals, false);
+            self.apply_result(competition, match_result.id, home_team.id, away_team.id, home_goals, away_goals);
+            self.update_fair_play(competition, match_result);
+            LeaderboardEngine::new().record_match(competition, match_result);
             
-            // Sort standings by points, then goal difference, then goals scored
+            // Sort standings by points, then the competition's tiebreak rules
             self.sort_standings(competition);
         }
     }
 
+    /// Records a score for a fixture that wasn't played through the match engine
+    pub fn record_result(&self, competition: &mut Competition, fixture_id: Uuid, home_goals: u8, away_goals: u8) {
+        let Some((home_team, away_team)) = competition.fixtures.iter()
+            .find(|f| f.id == fixture_id)
+            .map(|f| (f.home_team, f.away_team))
+        else {
+            return;
+        };
+
+        self.apply_result(competition, fixture_id, home_team, away_team, home_goals, away_goals);
+        self.sort_standings(competition);
+    }
+
+    /// Stores a fixture's score and updates standings for both teams
+    fn apply_result(
+        &self,
+        competition: &mut Competition,
+        fixture_id: Uuid,
+        home_team: Uuid,
+        away_team: Uuid,
+        home_goals: u8,
+        away_goals: u8,
+    ) {
+        // Find the fixture and update its result
+        if let Some(fixture) = competition.fixtures.iter_mut()
+            .find(|f| f.id == fixture_id) {
+            fixture.result = Some(crate::entities::MatchResult {
+                home_score: home_goals,
+                away_score: away_goals,
+                winner: if home_goals > away_goals {
+                    Some(home_team)
+                } else if away_goals > home_goals {
+                    Some(away_team)
+                } else {
+                    None // Draw
+                },
+            });
+            fixture.status = crate::entities::MatchStatus::Finished;
+        }
+
+        // Update standings for both teams
+        self.update_standings(competition, home_team, home_goals, away_goals, true);
+        self.update_standings(competition, away_team, away_goals, home_goals, false);
+    }
+
     /// Updates the standings for a team after a match
     fn update_standings(
         &self,
@@ -114,7 +190,7 @@ impl CompetitionEngine {
         team_id: Uuid,
         team_goals: u8,
         opponent_goals: u8,
-        _is_home: bool,
+        is_home: bool,
     ) {
         if let Some(standing) = competition.standings.iter_mut().find(|s| s.team_id == team_id) {
             // Update basic stats
@@ -122,6 +198,9 @@ impl CompetitionEngine {
             standing.goals_for += team_goals as u32;
             standing.goals_against += opponent_goals as u32;
             standing.goal_difference = standing.goals_for as i32 - standing.goals_against as i32;
+            if !is_home {
+                standing.away_goals_for += team_goals as u32;
+            }
             
             // Determine result and update points/stats
             let result = if team_goals > opponent_goals {
@@ -145,16 +224,49 @@ impl CompetitionEngine {
         }
     }
 
-    /// Sorts the standings based on points, goal difference, and goals scored
+    /// Deducts fair-play points for the cards shown in a match
+    fn update_fair_play(&self, competition: &mut Competition, match_result: &Match) {
+        for event in &match_result.events {
+            let deduction = match event.event_type {
+                EventType::YellowCard => 1,
+                EventType::RedCard => 3,
+                _ => continue,
+            };
+
+            if let Some(standing) = competition.standings.iter_mut().find(|s| s.team_id == event.team_id) {
+                standing.fair_play_points -= deduction;
+            }
+        }
+    }
+
+    /// Sorts the standings by points, then by the competition's tiebreak rules
     fn sort_standings(&self, competition: &mut Competition) {
-        competition.standings.sort_by(|a, b| {
-            // Primary sort: points
-            b.points.cmp(&a.points)
-                // Secondary sort: goal difference
-                .then_with(|| b.goal_difference.cmp(&a.goal_difference))
-                // Tertiary sort: goals for
-                .then_with(|| b.goals_for.cmp(&a.goals_for))
-        });
+        // Primary sort: points
+        competition.standings.sort_by_key(|s| std::cmp::Reverse(s.points));
+
+        // Lots are only drawn when a competition uses them, once per team and season,
+        // so teams that stay level don't swap places every time the table is sorted
+        if competition.tiebreak_rules.contains(&TiebreakRule::DrawingOfLots) {
+            let mut rng = rand::thread_rng();
+            for standing in competition.standings.iter_mut().filter(|s| s.lot.is_none()) {
+                standing.lot = Some(rng.gen());
+            }
+        }
+
+        // Break ties within each group of teams level on points
+        let standings = std::mem::take(&mut competition.standings);
+        let mut ranked = Vec::with_capacity(standings.len());
+        let mut group: Vec<Standing> = Vec::new();
+
+        for standing in standings {
+            if group.last().is_some_and(|last| last.points != standing.points) {
+                let tied = std::mem::take(&mut group);
+                ranked.extend(self.break_ties(tied, &competition.tiebreak_rules, 0, &competition.fixtures));
+            }
+            group.push(standing);
+        }
+        ranked.extend(self.break_ties(group, &competition.tiebreak_rules, 0, &competition.fixtures));
+        competition.standings = ranked;
         
         // Assign positions
         for (i, standing) in competition.standings.iter_mut().enumerate() {
@@ -162,6 +274,112 @@ impl CompetitionEngine {
         }
     }
 
+    /// Orders a group of teams level on points by applying the rules from `rule_index` onwards
+    fn break_ties(
+        &self,
+        group: Vec<Standing>,
+        rules: &[TiebreakRule],
+        rule_index: usize,
+        fixtures: &[Fixture],
+    ) -> Vec<Standing> {
+        if group.len() < 2 || rule_index >= rules.len() {
+            return group;
+        }
+
+        let rule = rules[rule_index];
+        let team_ids: Vec<Uuid> = group.iter().map(|s| s.team_id).collect();
+        let group_size = group.len();
+
+        let mut keyed: Vec<(i64, Standing)> = group.into_iter()
+            .map(|standing| (self.tiebreak_value(rule, &standing, &team_ids, fixtures), standing))
+            .collect();
+        keyed.sort_by_key(|(value, _)| std::cmp::Reverse(*value));
+
+        let mut ranked = Vec::with_capacity(group_size);
+        let mut keyed = keyed.into_iter().peekable();
+
+        while let Some((value, standing)) = keyed.next() {
+            let mut subgroup = vec![standing];
+            while let Some((_, next)) = keyed.next_if(|(next_value, _)| *next_value == value) {
+                subgroup.push(next);
+            }
+
+            if subgroup.len() == group_size {
+                // The rule did not separate anyone, move on to the next one
+                ranked.extend(self.break_ties(subgroup, rules, rule_index + 1, fixtures));
+            } else {
+                // Head-to-head records change once the group shrinks, so the remaining
+                // teams are ranked again from the first rule among themselves only
+                ranked.extend(self.break_ties(subgroup, rules, 0, fixtures));
+            }
+        }
+
+        ranked
+    }
+
+    /// Gets a team's value for a tiebreak rule, higher is better
+    fn tiebreak_value(
+        &self,
+        rule: TiebreakRule,
+        standing: &Standing,
+        tied_teams: &[Uuid],
+        fixtures: &[Fixture],
+    ) -> i64 {
+        match rule {
+            TiebreakRule::GoalDifference => standing.goal_difference as i64,
+            TiebreakRule::GoalsFor => standing.goals_for as i64,
+            TiebreakRule::HeadToHeadPoints => {
+                self.head_to_head_record(standing.team_id, tied_teams, fixtures).points as i64
+            },
+            TiebreakRule::HeadToHeadGoalDifference => {
+                let record = self.head_to_head_record(standing.team_id, tied_teams, fixtures);
+                record.goals_for as i64 - record.goals_against as i64
+            },
+            TiebreakRule::HeadToHeadGoalsFor => {
+                self.head_to_head_record(standing.team_id, tied_teams, fixtures).goals_for as i64
+            },
+            TiebreakRule::AwayGoals => standing.away_goals_for as i64,
+            TiebreakRule::Wins => standing.won as i64,
+            TiebreakRule::FairPlay => standing.fair_play_points as i64,
+            TiebreakRule::DrawingOfLots => standing.lot.unwrap_or(0) as i64,
+        }
+    }
+
+    /// Builds a team's record from finished matches against the other tied teams only
+    fn head_to_head_record(&self, team_id: Uuid, tied_teams: &[Uuid], fixtures: &[Fixture]) -> HeadToHeadRecord {
+        let mut record = HeadToHeadRecord::default();
+
+        for fixture in fixtures {
+            if fixture.status != crate::entities::MatchStatus::Finished {
+                continue;
+            }
+            if !tied_teams.contains(&fixture.home_team) || !tied_teams.contains(&fixture.away_team) {
+                continue;
+            }
+            let Some(result) = &fixture.result else {
+                continue;
+            };
+
+            let (scored, conceded) = if fixture.home_team == team_id {
+                (result.home_score, result.away_score)
+            } else if fixture.away_team == team_id {
+                (result.away_score, result.home_score)
+            } else {
+                continue;
+            };
+
+            record.goals_for += scored as u32;
+            record.goals_against += conceded as u32;
+            record.points += match scored.cmp(&conceded) {
+                std::cmp::Ordering::Greater => 3,
+                std::cmp::Ordering::Equal => 1,
+                std::cmp::Ordering::Less => 0,
+            };
+        }
+
+        record
+    }
+
     /// Updates competition standings after a match
     pub fn update_competition_after_match(
         &self,
@@ -268,6 +486,14 @@ impl CompetitionEngine {
     }
 }
 
+/// A team's record in matches against the teams it is tied with
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct HeadToHeadRecord {
+    points: u32,
+    goals_for: u32,
+    goals_against: u32,
+}
+
 /// Competition type
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum CompetitionType {
@@ -290,9 +516,8 @@ pub struct MatchResult {
 mod tests {
     use super::*;
     use crate::entities::{Competition, Team, SquadRole, Contract, Position, Foot, CareerStats, HiddenAttributes};
-    use crate::core::game_state::SeasonInfo;
-    use crate::systems::social_system::ManagerProfile;
     use chrono::NaiveDate;
+    use std::collections::HashMap;
 
     #[test]
     fn test_initialize_season() {
@@ -306,11 +531,14 @@ mod tests {
             level: 1,
             season_start: chrono::Utc::now().date_naive(),
             season_end: chrono::Utc::now().date_naive(),
+            tiebreak_rules: TiebreakRule::goal_difference_first(),
+            player_stats: vec![],
             teams: team_ids.clone(),
             fixtures: vec![],
             standings: vec![],
             competition_type: crate::entities::CompetitionType::League,
             current_season: crate::entities::CurrentSeason {
+                year: String::new(),
                 is_active: false,
                 current_matchday: 1,
                 start_date: chrono::Utc::now().date_naive(),
@@ -348,6 +576,36 @@ mod tests {
         assert_eq!(fixtures.len(), 6);
     }
 
+    #[test]
+    fn test_generate_fixtures_one_match_per_team_per_matchday() {
+        let engine = CompetitionEngine::new();
+
+        let team_ids: Vec<Uuid> = (0..4).map(|_| Uuid::new_v4()).collect();
+        let fixtures = engine.generate_fixtures(&team_ids, Uuid::new_v4(), "Test League");
+
+        // 4 teams play 6 matchdays of 2 matches
+        assert_eq!(fixtures.len(), 12);
+        for matchday in 1..=6 {
+            let mut teams_playing: Vec<Uuid> = fixtures.iter()
+                .filter(|fixture| fixture.matchday == matchday)
+                .flat_map(|fixture| [fixture.home_team, fixture.away_team])
+                .collect();
+            teams_playing.sort();
+            teams_playing.dedup();
+            assert_eq!(teams_playing.len(), 4);
+        }
+
+        // Every pairing is played once at each ground
+        for home in &team_ids {
+            for away in team_ids.iter().filter(|away| *away != home) {
+                let legs = fixtures.iter()
+                    .filter(|fixture| fixture.home_team == *home && fixture.away_team == *away)
+                    .count();
+                assert_eq!(legs, 1);
+            }
+        }
+    }
+
     #[test]
     fn test_update_standings() {
         let engine = CompetitionEngine::new();
@@ -360,6 +618,8 @@ mod tests {
             level: 1,
             season_start: chrono::Utc::now().date_naive(),
             season_end: chrono::Utc::now().date_naive(),
+            tiebreak_rules: TiebreakRule::goal_difference_first(),
+            player_stats: vec![],
             teams: vec![team_id],
             fixtures: vec![],
             standings: vec![Standing {
@@ -374,9 +634,13 @@ mod tests {
                 goals_against: 0,
                 goal_difference: 0,
                 form: vec![],
+                away_goals_for: 0,
+                fair_play_points: 0,
+                lot: None,
             }],
             competition_type: crate::entities::CompetitionType::League,
             current_season: crate::entities::CurrentSeason {
+                year: String::new(),
                 is_active: false,
                 current_matchday: 1,
                 start_date: chrono::Utc::now().date_naive(),
@@ -412,6 +676,8 @@ mod tests {
             level: 1,
             season_start: chrono::Utc::now().date_naive(),
             season_end: chrono::Utc::now().date_naive(),
+            tiebreak_rules: TiebreakRule::goal_difference_first(),
+            player_stats: vec![],
             teams: vec![team_a, team_b, team_c],
             fixtures: vec![],
             standings: vec![
@@ -427,6 +693,9 @@ mod tests {
                     goals_against: 1,
                     goal_difference: 1,
                     form: vec![FormResult::Win],
+                    away_goals_for: 0,
+                    fair_play_points: 0,
+                    lot: None,
                 },
                 Standing {
                     team_id: team_b,
@@ -440,6 +709,9 @@ mod tests {
                     goals_against: 0,
                     goal_difference: 3,
                     form: vec![FormResult::Win],
+                    away_goals_for: 0,
+                    fair_play_points: 0,
+                    lot: None,
                 },
                 Standing {
                     team_id: team_c,
@@ -453,10 +725,14 @@ mod tests {
                     goals_against: 2,
                     goal_difference: -2,
                     form: vec![FormResult::Loss],
+                    away_goals_for: 0,
+                    fair_play_points: 0,
+                    lot: None,
                 },
             ],
             competition_type: crate::entities::CompetitionType::League,
             current_season: crate::entities::CurrentSeason {
+                year: String::new(),
                 is_active: false,
                 current_matchday: 1,
                 start_date: chrono::Utc::now().date_naive(),
@@ -479,6 +755,243 @@ mod tests {
         assert_eq!(competition.standings[2].position, 3);
     }
 
+    #[test]
+    fn test_three_way_tie_separated_by_head_to_head_points() {
+        let engine = CompetitionEngine::new();
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+
+        // C has the best overall goal difference, but A and B won the matches that count
+        let mut competition = create_tiebreak_competition(
+            TiebreakRule::head_to_head_first(),
+            vec![
+                create_standing(a, 10, 12, 10),
+                create_standing(b, 10, 12, 11),
+                create_standing(c, 10, 20, 10),
+            ],
+            vec![
+                create_finished_fixture(a, b, 1, 0),
+                create_finished_fixture(a, c, 2, 1),
+                create_finished_fixture(b, c, 1, 0),
+            ],
+        );
+
+        engine.sort_standings(&mut competition);
+
+        assert_eq!(standing_order(&competition), vec![a, b, c]);
+    }
+
+    #[test]
+    fn test_circular_three_way_tie_falls_to_head_to_head_goal_difference() {
+        let engine = CompetitionEngine::new();
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+
+        // A beat B, B beat C, C beat A: three head-to-head points each
+        let mut competition = create_tiebreak_competition(
+            TiebreakRule::head_to_head_first(),
+            vec![
+                create_standing(a, 10, 15, 10),
+                create_standing(b, 10, 10, 10),
+                create_standing(c, 10, 12, 10),
+            ],
+            vec![
+                create_finished_fixture(a, b, 1, 0),
+                create_finished_fixture(b, c, 3, 0),
+                create_finished_fixture(c, a, 2, 1),
+            ],
+        );
+
+        engine.sort_standings(&mut competition);
+
+        // Head-to-head goal difference: B +2, A 0, C -2
+        assert_eq!(standing_order(&competition), vec![b, a, c]);
+    }
+
+    #[test]
+    fn test_three_way_tie_reapplies_head_to_head_to_remaining_pair() {
+        let engine = CompetitionEngine::new();
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+
+        // Across the three teams A has 9 head-to-head points, B and C 4 each.
+        // C has the better three-team goal difference, but B took more points off C
+        let mut competition = create_tiebreak_competition(
+            TiebreakRule::head_to_head_first(),
+            vec![
+                create_standing(a, 20, 30, 20),
+                create_standing(b, 20, 30, 20),
+                create_standing(c, 20, 30, 20),
+            ],
+            vec![
+                create_finished_fixture(a, b, 3, 0),
+                create_finished_fixture(b, a, 0, 3),
+                create_finished_fixture(c, a, 4, 0),
+                create_finished_fixture(a, c, 1, 0),
+                create_finished_fixture(b, c, 1, 0),
+                create_finished_fixture(c, b, 0, 0),
+            ],
+        );
+
+        engine.sort_standings(&mut competition);
+
+        assert_eq!(standing_order(&competition), vec![a, b, c]);
+    }
+
+    #[test]
+    fn test_three_way_tie_level_on_head_to_head_uses_overall_record() {
+        let engine = CompetitionEngine::new();
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+
+        let mut competition = create_tiebreak_competition(
+            TiebreakRule::head_to_head_first(),
+            vec![
+                create_standing(a, 10, 11, 10),
+                create_standing(b, 10, 14, 10),
+                create_standing(c, 10, 14, 12),
+            ],
+            vec![
+                create_finished_fixture(a, b, 1, 1),
+                create_finished_fixture(b, c, 1, 1),
+                create_finished_fixture(c, a, 1, 1),
+            ],
+        );
+
+        engine.sort_standings(&mut competition);
+
+        assert_eq!(standing_order(&competition), vec![b, c, a]);
+    }
+
+    #[test]
+    fn test_wins_and_away_goals_tiebreaks() {
+        let engine = CompetitionEngine::new();
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+
+        let mut standing_a = create_standing(a, 10, 10, 10);
+        standing_a.won = 2;
+        standing_a.away_goals_for = 6;
+        let mut standing_b = create_standing(b, 10, 10, 10);
+        standing_b.won = 3;
+        standing_b.away_goals_for = 4;
+
+        let mut competition = create_tiebreak_competition(
+            vec![TiebreakRule::Wins],
+            vec![standing_a.clone(), standing_b.clone()],
+            vec![],
+        );
+        engine.sort_standings(&mut competition);
+        assert_eq!(standing_order(&competition), vec![b, a]);
+
+        let mut competition = create_tiebreak_competition(
+            vec![TiebreakRule::AwayGoals],
+            vec![standing_b, standing_a],
+            vec![],
+        );
+        engine.sort_standings(&mut competition);
+        assert_eq!(standing_order(&competition), vec![a, b]);
+    }
+
+    #[test]
+    fn test_fair_play_then_drawing_of_lots() {
+        let engine = CompetitionEngine::new();
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+
+        let mut standing_a = create_standing(a, 10, 10, 10);
+        standing_a.fair_play_points = -6;
+        let mut standing_b = create_standing(b, 10, 10, 10);
+        standing_b.fair_play_points = -2;
+        let mut standing_c = create_standing(c, 10, 10, 10);
+        standing_c.fair_play_points = -2;
+
+        let mut competition = create_tiebreak_competition(
+            vec![TiebreakRule::GoalDifference, TiebreakRule::FairPlay, TiebreakRule::DrawingOfLots],
+            vec![standing_a, standing_b, standing_c],
+            vec![],
+        );
+
+        engine.sort_standings(&mut competition);
+
+        // B and C can only be separated by lots, A has the worst disciplinary record
+        let order = standing_order(&competition);
+        assert!(order[..2].contains(&b) && order[..2].contains(&c));
+        assert_eq!(order[2], a);
+        let positions: Vec<u8> = competition.standings.iter().map(|s| s.position).collect();
+        assert_eq!(positions, vec![1, 2, 3]);
+
+        // The lots stand, so re-sorting after the next round doesn't reshuffle the tied teams
+        for _ in 0..10 {
+            engine.sort_standings(&mut competition);
+            assert_eq!(standing_order(&competition), order);
+        }
+    }
+
+    fn create_tiebreak_competition(
+        tiebreak_rules: Vec<TiebreakRule>,
+        standings: Vec<Standing>,
+        fixtures: Vec<Fixture>,
+    ) -> Competition {
+        Competition {
+            id: Uuid::new_v4(),
+            name: "Test League".to_string(),
+            country: "Test Country".to_string(),
+            level: 1,
+            season_start: chrono::Utc::now().date_naive(),
+            season_end: chrono::Utc::now().date_naive(),
+            tiebreak_rules,
+            player_stats: vec![],
+            teams: standings.iter().map(|s| s.team_id).collect(),
+            fixtures,
+            standings,
+            competition_type: crate::entities::CompetitionType::League,
+            current_season: crate::entities::CurrentSeason {
+                year: String::new(),
+                is_active: true,
+                current_matchday: 1,
+                start_date: chrono::Utc::now().date_naive(),
+                end_date: chrono::Utc::now().date_naive(),
+            },
+        }
+    }
+
+    fn create_standing(team_id: Uuid, points: u8, goals_for: u32, goals_against: u32) -> Standing {
+        Standing {
+            team_id,
+            position: 0,
+            points,
+            played: 0,
+            won: 0,
+            drawn: 0,
+            lost: 0,
+            goals_for,
+            goals_against,
+            goal_difference: goals_for as i32 - goals_against as i32,
+            form: vec![],
+            away_goals_for: 0,
+            fair_play_points: 0,
+            lot: None,
+        }
+    }
+
+    fn create_finished_fixture(home_team: Uuid, away_team: Uuid, home_score: u8, away_score: u8) -> Fixture {
+        Fixture {
+            id: Uuid::new_v4(),
+            competition_id: Uuid::new_v4(),
+            home_team,
+            away_team,
+            scheduled_date: chrono::Utc::now().date_naive(),
+            venue: home_team,
+            status: crate::entities::MatchStatus::Finished,
+            result: Some(crate::entities::MatchResult {
+                home_score,
+                away_score,
+                winner: None,
+            }),
+            matchday: 1,
+            postponed_from: None,
+        }
+    }
+
+    fn standing_order(competition: &Competition) -> Vec<Uuid> {
+        competition.standings.iter().map(|s| s.team_id).collect()
+    }
+
     #[test]
     fn test_calculate_team_strength() {
         let engine = CompetitionEngine::new();
@@ -574,6 +1087,7 @@ mod tests {
                 performance_bonuses: vec![],
                 contract_end_date: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
                 league_strength: 75.0,
+                loan: None,
             },
             career_stats: CareerStats {
                 seasons_played: 3,
@@ -587,6 +1101,14 @@ mod tests {
                 season_stats: vec![],
                 awards: vec![],
                 trophies: vec![],
+                current_season: crate::entities::SeasonStats::default(),
+                international_caps: 0,
+                international_goals: 0,
+                first_call_up: None,
+                youth_season: crate::entities::SeasonStats::default(),
+                youth_history: vec![],
+                youth_form: vec![],
+                club_spells: vec![],
             },
             relationships: HashMap::new(),
             injury_status: None,
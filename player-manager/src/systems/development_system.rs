@@ -13,6 +13,12 @@ use crate::systems::training_system::TrainingFocus;
 /// growth curves based on age, applies morale effects, and handles hidden attributes
 pub struct PlayerDevelopmentEngine;
 
+impl Default for PlayerDevelopmentEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl PlayerDevelopmentEngine {
     /// Creates a new PlayerDevelopmentEngine instance
     pub fn new() -> Self {
@@ -98,7 +104,7 @@ impl PlayerDevelopmentEngine {
     /// Normalizes performance rating to 0-1 scale
     fn normalize_performance(&self, rating: f32) -> f32 {
         // Convert 0-10 rating to 0-1 scale centered around 6.0 (average)
-        ((rating - 6.0) / 4.0).max(-1.0).min(1.0)
+        ((rating - 6.0) / 4.0).clamp(-1.0, 1.0)
     }
 
     /// Updates attributes for young players (ages 15-18)
@@ -340,7 +346,7 @@ impl PlayerDevelopmentEngine {
         
         // Apply fatigue penalty to all attributes temporarily
         // These are applied during match simulation, not permanently
-        player.fatigue = player.fatigue.min(100.0).max(0.0);
+        player.fatigue = player.fatigue.clamp(0.0, 100.0);
     }
 
     /// Applies injury effects to player attributes
@@ -397,7 +403,7 @@ impl PlayerDevelopmentEngine {
 
     /// Caps an attribute value between 1 and 100
     fn cap_attribute(&self, value: f32) -> f32 {
-        value.max(1.0).min(100.0)
+        value.clamp(1.0, 100.0)
     }
 
     /// Applies diminishing returns to growth based on current attribute level
@@ -489,8 +495,6 @@ enum GrowthCategory {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::entities::{Player, Position, Foot, CareerStats, Contract, SquadRole, HiddenAttributes};
-    use chrono::NaiveDate;
 
     #[test]
     fn test_age_development_factors() {
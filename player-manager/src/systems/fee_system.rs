@@ -0,0 +1,407 @@
+// src/systems/fee_system.rs
+use chrono::{Months, NaiveDate};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::core::game_state::{
+    AddOnCondition, BuyBackClause, FeeAddOn, FeeInstalment, FeeStructure, GameState, TransferRecord,
+};
+use crate::entities::{Player, Team};
+use crate::systems::{ClubFinanceEngine, CompetitionEngine, TransferEngine};
+
+/// Yearly instalments the unpaid part of a fee is spread over
+const INSTALMENT_YEARS: u32 = 2;
+/// Senior appearances at the buying club that trigger the appearance add-on
+const APPEARANCE_ADD_ON_TARGET: u32 = 50;
+/// Appearance add-on as a share of the fee
+const APPEARANCE_ADD_ON_SHARE: f32 = 0.1;
+/// Trophy add-on as a share of the fee
+const TROPHY_ADD_ON_SHARE: f32 = 0.05;
+/// Oldest player a selling club insists on a buy-back clause for
+const BUY_BACK_AGE: u8 = 21;
+/// Buy-back price as a multiple of the original fee
+const BUY_BACK_MARKUP: f32 = 2.0;
+/// How long a buy-back clause can be exercised for
+const BUY_BACK_YEARS: u32 = 4;
+/// How far below a club's level a player can be and still be worth buying back
+const BUY_BACK_ABILITY_GAP: f32 = 10.0;
+
+/// The FeeEngine structures transfer fees and follows the clauses attached to them
+/// Instalments, add-ons, sell-on shares and buy-backs stay with the player through later moves
+pub struct FeeEngine;
+
+impl Default for FeeEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FeeEngine {
+    /// Creates a new FeeEngine instance
+    pub fn new() -> Self {
+        FeeEngine
+    }
+
+    /// Splits an agreed fee into the deal the clubs sign
+    ///
+    /// Richer buyers pay more upfront and the rest follows in yearly instalments. Add-ons reward
+    /// appearances and trophies at the new club. Selling clubs keep a sell-on share of young
+    /// players, and a buy-back clause on the youngest.
+    pub fn structure_fee(&self, player: &Player, buying_club: &Team, fee: f32, today: NaiveDate) -> FeeStructure {
+        let wealth = buying_club.financial_power.clamp(0.0, 100.0) / 100.0;
+        let upfront = fee * (0.4 + 0.5 * wealth);
+        let instalment = (fee - upfront) / INSTALMENT_YEARS as f32;
+        let instalments = (1..=INSTALMENT_YEARS)
+            .filter(|_| instalment > 0.0)
+            .map(|year| FeeInstalment {
+                due: today + Months::new(12 * year),
+                amount: instalment,
+                paid: false,
+            })
+            .collect();
+
+        let add_ons = vec![
+            FeeAddOn {
+                condition: AddOnCondition::Appearances(APPEARANCE_ADD_ON_TARGET),
+                amount: fee * APPEARANCE_ADD_ON_SHARE,
+                paid: false,
+            },
+            FeeAddOn {
+                condition: AddOnCondition::Trophies(1),
+                amount: fee * TROPHY_ADD_ON_SHARE,
+                paid: false,
+            },
+        ];
+
+        let sell_on_percentage = match player.age {
+            0..=23 => 15.0,
+            24..=26 => 10.0,
+            _ => 0.0,
+        };
+        let buy_back = (player.age <= BUY_BACK_AGE).then(|| BuyBackClause {
+            fee: fee * BUY_BACK_MARKUP,
+            expires_on: today + Months::new(12 * BUY_BACK_YEARS),
+        });
+
+        FeeStructure {
+            upfront,
+            instalments,
+            add_ons,
+            sell_on_percentage,
+            buy_back,
+        }
+    }
+
+    /// Records a completed transfer and returns what has to be paid today
+    ///
+    /// The buying club pays the upfront part. If the selling club bought the player with a
+    /// sell-on clause, it owes the club it bought them from its share of the new fee.
+    pub fn record_transfer(
+        &self,
+        records: &mut Vec<TransferRecord>,
+        player_id: Uuid,
+        from_club_id: Uuid,
+        to_club_id: Uuid,
+        fee: FeeStructure,
+        today: NaiveDate,
+    ) -> Vec<FeePayment> {
+        let mut payments = Vec::new();
+        if fee.upfront > 0.0 {
+            payments.push(FeePayment {
+                payer_club_id: to_club_id,
+                payee_club_id: from_club_id,
+                amount: fee.upfront,
+                kind: FeePaymentKind::Upfront,
+            });
+        }
+
+        // Only the deal that brought the player to the selling club carries a sell-on for this sale
+        let acquired = records
+            .iter()
+            .rev()
+            .find(|record| record.player_id == player_id && record.to_club_id == from_club_id);
+        if let Some(acquired) = acquired.filter(|record| record.fee.sell_on_percentage > 0.0) {
+            payments.push(FeePayment {
+                payer_club_id: from_club_id,
+                payee_club_id: acquired.from_club_id,
+                amount: fee.guaranteed() * acquired.fee.sell_on_percentage / 100.0,
+                kind: FeePaymentKind::SellOn,
+            });
+        }
+
+        records.push(TransferRecord {
+            player_id,
+            from_club_id,
+            to_club_id,
+            date: today,
+            fee,
+            appearances: 0,
+            trophies: 0,
+        });
+        payments
+    }
+
+    /// Pays every instalment that has fallen due, wherever the player is now
+    pub fn pay_instalments(&self, game_state: &mut GameState, today: NaiveDate) -> Vec<FeePayment> {
+        let mut payments = Vec::new();
+        for record in game_state.transfer_system.transfer_records.iter_mut() {
+            for instalment in record.fee.instalments.iter_mut().filter(|instalment| !instalment.paid && instalment.due <= today) {
+                instalment.paid = true;
+                payments.push(FeePayment {
+                    payer_club_id: record.to_club_id,
+                    payee_club_id: record.from_club_id,
+                    amount: instalment.amount,
+                    kind: FeePaymentKind::Instalment,
+                });
+            }
+        }
+        self.apply_payments(&mut game_state.clubs, &payments);
+        payments
+    }
+
+    /// Counts the season's appearances and trophies towards add-ons and pays those now met
+    ///
+    /// Only players still at the club that bought them count; once sold on, the add-ons lapse.
+    pub fn settle_season(&self, game_state: &mut GameState) -> Vec<FeePayment> {
+        let competition_engine = CompetitionEngine::new();
+        let senior: Vec<_> = game_state
+            .competitions
+            .iter()
+            .filter(|competition| !competition.competition_type.is_youth())
+            .collect();
+        let mut payments = Vec::new();
+
+        for record in game_state.transfer_system.transfer_records.iter_mut() {
+            let current_club = std::iter::once(&game_state.player)
+                .chain(game_state.players.iter())
+                .find(|player| player.id == record.player_id)
+                .map(|player| player.contract.club_id);
+            if current_club != Some(record.to_club_id) || record.fee.add_ons.iter().all(|add_on| add_on.paid) {
+                continue;
+            }
+
+            record.appearances += senior
+                .iter()
+                .flat_map(|competition| competition.player_stats.iter())
+                .filter(|stats| stats.player_id == record.player_id && stats.team_id == record.to_club_id)
+                .map(|stats| stats.appearances)
+                .sum::<u32>();
+            record.trophies += senior
+                .iter()
+                .filter(|competition| competition_engine.get_competition_winner(competition) == Some(record.to_club_id))
+                .count() as u32;
+
+            for add_on in record.fee.add_ons.iter_mut().filter(|add_on| !add_on.paid) {
+                let met = match add_on.condition {
+                    AddOnCondition::Appearances(target) => record.appearances >= target,
+                    AddOnCondition::Trophies(target) => record.trophies >= target,
+                };
+                if met {
+                    add_on.paid = true;
+                    payments.push(FeePayment {
+                        payer_club_id: record.to_club_id,
+                        payee_club_id: record.from_club_id,
+                        amount: add_on.amount,
+                        kind: FeePaymentKind::AddOn,
+                    });
+                }
+            }
+        }
+
+        self.apply_payments(&mut game_state.clubs, &payments);
+        payments
+    }
+
+    /// The deal that gives a former club the right to buy the player back today, if any
+    pub fn buy_back_clause<'a>(&self, records: &'a [TransferRecord], player: &Player, today: NaiveDate) -> Option<&'a TransferRecord> {
+        if player.contract.loan.is_some() {
+            return None;
+        }
+        records
+            .iter()
+            .rev()
+            .find(|record| record.player_id == player.id && record.to_club_id == player.contract.club_id)
+            .filter(|record| record.fee.buy_back.as_ref().is_some_and(|clause| today <= clause.expires_on))
+    }
+
+    /// Whether a former club wants to use its buy-back clause and can afford to
+    pub fn will_exercise(&self, player: &Player, club: &Team, clause: &BuyBackClause) -> bool {
+        !ClubFinanceEngine::new().in_debt(club)
+            && TransferEngine::new().transfer_budget(club).min(club.finances.balance) >= clause.fee
+            && player.ability_score() >= club.reputation - BUY_BACK_ABILITY_GAP
+    }
+
+    /// Records the player's return to the club holding the buy-back, paid in full at the clause fee
+    ///
+    /// Call once the player has moved; `from_club_id` is the club they have just left.
+    pub fn exercise_buy_back(&self, records: &mut Vec<TransferRecord>, player_id: Uuid, from_club_id: Uuid, today: NaiveDate) -> Vec<FeePayment> {
+        let Some((former_club_id, clause)) = records
+            .iter_mut()
+            .rev()
+            .find(|record| record.player_id == player_id && record.to_club_id == from_club_id)
+            .and_then(|record| Some((record.from_club_id, record.fee.buy_back.take()?)))
+        else {
+            return Vec::new();
+        };
+        self.record_transfer(records, player_id, from_club_id, former_club_id, FeeStructure::upfront(clause.fee), today)
+    }
+
+    /// Gives up a buy-back clause, when the player turns the move down
+    pub fn waive_buy_back(&self, records: &mut [TransferRecord], player: &Player) {
+        if let Some(record) = records
+            .iter_mut()
+            .rev()
+            .find(|record| record.player_id == player.id && record.to_club_id == player.contract.club_id)
+        {
+            record.fee.buy_back = None;
+        }
+    }
+
+    /// Moves each payment between the clubs' balances
+    pub fn apply_payments(&self, clubs: &mut [Team], payments: &[FeePayment]) {
+        for payment in payments {
+            if let Some(club) = clubs.iter_mut().find(|club| club.id == payment.payer_club_id) {
+                club.finances.balance -= payment.amount;
+            }
+            if let Some(club) = clubs.iter_mut().find(|club| club.id == payment.payee_club_id) {
+                club.finances.balance += payment.amount;
+            }
+        }
+    }
+
+    /// A one-line summary of a fee deal, for news and the player's screens
+    pub fn describe_structure(&self, fee: &FeeStructure) -> String {
+        let mut parts = vec![format!("£{:.0} up front", fee.upfront)];
+        if !fee.instalments.is_empty() {
+            parts.push(format!("{} instalments of £{:.0}", fee.instalments.len(), fee.instalments[0].amount));
+        }
+        let add_ons = fee.maximum() - fee.guaranteed();
+        if add_ons > 0.0 {
+            parts.push(format!("£{:.0} in add-ons", add_ons));
+        }
+        if fee.sell_on_percentage > 0.0 {
+            parts.push(format!("{:.0}% sell-on", fee.sell_on_percentage));
+        }
+        if let Some(clause) = &fee.buy_back {
+            parts.push(format!("£{:.0} buy-back until {}", clause.fee, clause.expires_on.format("%d %b %Y")));
+        }
+        parts.join(", ")
+    }
+}
+
+/// Money owed between two clubs under a fee deal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeePayment {
+    pub payer_club_id: Uuid,
+    pub payee_club_id: Uuid,
+    pub amount: f32,
+    pub kind: FeePaymentKind,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum FeePaymentKind {
+    Upfront,
+    Instalment,
+    AddOn,
+    SellOn,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::{PlayerCompetitionStats, Position};
+    use crate::test_support::{create_league, create_player, create_test_team, create_world, date};
+
+    #[test]
+    fn test_fee_is_structured_and_sell_on_follows_the_player() {
+        let engine = FeeEngine::new();
+        let today = date(2025, 8, 1);
+        let youngster = create_player(70, 19, Position::CM);
+        let mut rich = create_test_team();
+        rich.financial_power = 100.0;
+        let mut poor = create_test_team();
+        poor.financial_power = 0.0;
+
+        let deal = engine.structure_fee(&youngster, &rich, 1000000.0, today);
+        assert!((deal.guaranteed() - 1000000.0).abs() < 1.0);
+        assert!(deal.maximum() > deal.guaranteed());
+        assert_eq!(deal.sell_on_percentage, 15.0);
+        assert_eq!(deal.buy_back.as_ref().unwrap().fee, 2000000.0);
+        assert!(engine.structure_fee(&youngster, &poor, 1000000.0, today).upfront < deal.upfront);
+        let veteran = create_player(70, 30, Position::CM);
+        let veteran_deal = engine.structure_fee(&veteran, &rich, 1000000.0, today);
+        assert_eq!(veteran_deal.sell_on_percentage, 0.0);
+        assert!(veteran_deal.buy_back.is_none());
+
+        // Boyhood club sells with a sell-on; the buyer later sells on and owes them a share
+        let (boyhood, buyer, next) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let mut records = Vec::new();
+        let payments = engine.record_transfer(&mut records, youngster.id, boyhood, buyer, deal, today);
+        assert_eq!(payments.len(), 1);
+        assert_eq!(payments[0].kind, FeePaymentKind::Upfront);
+
+        let payments = engine.record_transfer(&mut records, youngster.id, buyer, next, FeeStructure::upfront(4000000.0), date(2027, 8, 1));
+        let sell_on = payments.iter().find(|payment| payment.kind == FeePaymentKind::SellOn).unwrap();
+        assert_eq!(sell_on.payer_club_id, buyer);
+        assert_eq!(sell_on.payee_club_id, boyhood);
+        assert_eq!(sell_on.amount, 600000.0);
+    }
+
+    #[test]
+    fn test_instalments_add_ons_and_buy_back_are_paid_over_time() {
+        let engine = FeeEngine::new();
+        let today = date(2025, 8, 1);
+        let mut boyhood = create_test_team();
+        let mut buyer = create_test_team();
+        buyer.financial_power = 50.0;
+        let mut player = create_player(70, 19, Position::CM);
+        player.contract.club_id = buyer.id;
+        boyhood.reputation = 70.0;
+        let mut game_state = create_world(vec![boyhood.clone(), buyer.clone()], vec![player.clone()]);
+
+        let deal = engine.structure_fee(&player, &buyer, 30000.0, today);
+        let instalment = deal.instalments[0].amount;
+        let payments = engine.record_transfer(&mut game_state.transfer_system.transfer_records, player.id, boyhood.id, buyer.id, deal, today);
+        engine.apply_payments(&mut game_state.clubs, &payments);
+
+        // Nothing is due until a year after the move, then each instalment is paid once
+        assert!(engine.pay_instalments(&mut game_state, date(2026, 7, 31)).is_empty());
+        assert_eq!(engine.pay_instalments(&mut game_state, date(2026, 8, 1)).len(), 1);
+        assert!(engine.pay_instalments(&mut game_state, date(2026, 8, 2)).is_empty());
+        let paid_in = game_state.clubs[0].finances.balance - boyhood.finances.balance;
+        assert!((paid_in - (payments[0].amount + instalment)).abs() < 1.0);
+
+        // Fifty appearances in a title-winning season meet both add-ons
+        let stats = PlayerCompetitionStats {
+            player_id: player.id,
+            team_id: buyer.id,
+            appearances: 50,
+            ..PlayerCompetitionStats::default()
+        };
+        game_state.competitions = vec![create_league(vec![(buyer.id, 1)], vec![stats])];
+        let payments = engine.settle_season(&mut game_state);
+        assert_eq!(payments.len(), 2);
+        assert!(payments.iter().all(|payment| payment.kind == FeePaymentKind::AddOn));
+        assert!(engine.settle_season(&mut game_state).is_empty());
+
+        // Years later the boyhood club can still bring the player home
+        let later = date(2028, 7, 1);
+        let records = &game_state.transfer_system.transfer_records;
+        let clause = engine.buy_back_clause(records, &player, later).unwrap().fee.buy_back.clone().unwrap();
+        assert!(engine.will_exercise(&player, &game_state.clubs[0], &clause));
+        assert!(engine.buy_back_clause(records, &player, date(2030, 1, 1)).is_none());
+
+        game_state.players[0].contract.club_id = boyhood.id;
+        let records = &mut game_state.transfer_system.transfer_records;
+        let payments = engine.exercise_buy_back(records, player.id, buyer.id, later);
+        assert_eq!(payments[0].amount, 60000.0);
+        assert_eq!(payments[0].payer_club_id, boyhood.id);
+        // The buying club owes the boyhood club its sell-on share of the buy-back fee too
+        assert_eq!(payments[1].kind, FeePaymentKind::SellOn);
+        assert_eq!(records.len(), 2);
+        assert!(records[0].fee.buy_back.is_none());
+    }
+
+    // Helper functions for tests
+
+}
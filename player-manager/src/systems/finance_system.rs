@@ -0,0 +1,340 @@
+// src/systems/finance_system.rs
+use chrono::{Duration, Months, NaiveDate};
+
+use crate::core::game_state::{LedgerEntryKind, LifestyleItem, LifestylePurchase, NetWorthRecord, PlayerFinances};
+use crate::entities::{Agent, ContractTerms, Player};
+use crate::systems::{AgentEngine, LoanEngine};
+
+/// Share of a car's price it keeps as resale value
+const CAR_RESALE_SHARE: f32 = 0.6;
+/// Morale lift from a settled home life, each week
+const HOUSE_WEEKLY_MORALE: f32 = 0.5;
+/// Fitness gained each week from working with a personal trainer
+const TRAINER_WEEKLY_FITNESS: f32 = 1.0;
+/// Fatigue shed each week thanks to a personal trainer's recovery work
+const TRAINER_WEEKLY_RECOVERY: f32 = 2.0;
+
+/// The FinanceEngine runs the player's personal finances
+/// It pays wages, takes tax and the agent's fee, charges for lifestyle spending and tracks net worth
+pub struct FinanceEngine;
+
+impl Default for FinanceEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FinanceEngine {
+    /// Creates a new FinanceEngine instance
+    pub fn new() -> Self {
+        FinanceEngine
+    }
+
+    /// Runs the player's weekly finances
+    ///
+    /// The week's wage is paid, split between the parent and loan clubs while the player is on
+    /// loan, then income tax at the club country's rate is taken from the
+    /// wage and any bonuses paid in the last seven days. An agent who hasn't been paid for the
+    /// current contract takes their fee on its first payday. Lifestyle upkeep is charged and its
+    /// effects applied.
+    pub fn process_week(
+        &self,
+        player: &mut Player,
+        finances: &mut PlayerFinances,
+        country: &str,
+        agent: Option<&Agent>,
+        today: NaiveDate,
+    ) -> WeeklyStatement {
+        let (parent_share, loan_share) = if player.contract.club_id.is_nil() {
+            (0.0, 0.0)
+        } else {
+            LoanEngine::new().wage_contributions(player)
+        };
+        let wage = parent_share + loan_share;
+        if loan_share > 0.0 {
+            finances.record(today, LedgerEntryKind::Wage, "Weekly wage (parent club's share)".to_string(), parent_share);
+            finances.record(today, LedgerEntryKind::Wage, "Weekly wage (loan club's share)".to_string(), loan_share);
+        } else if wage > 0.0 {
+            finances.record(today, LedgerEntryKind::Wage, "Weekly wage".to_string(), wage);
+        }
+
+        let week_start = today - Duration::days(7);
+        let bonuses: f32 = finances.ledger.iter()
+            .filter(|entry| entry.kind == LedgerEntryKind::Bonus && entry.date > week_start && entry.date <= today)
+            .map(|entry| entry.amount)
+            .sum();
+
+        let tax = (wage + bonuses) * self.tax_rate(country);
+        if tax > 0.0 {
+            finances.record(today, LedgerEntryKind::Tax, format!("Income tax ({})", country), -tax);
+        }
+
+        let agent_fee = match agent {
+            Some(agent) if wage > 0.0 && !self.agent_paid_for_contract(player, finances) => {
+                let fee = AgentEngine::new().agent_fee(agent, &self.contract_terms(player));
+                self.charge_agent_fee(finances, agent, fee, today);
+                fee
+            }
+            _ => 0.0,
+        };
+
+        let upkeep: f32 = finances.lifestyle.iter().map(|purchase| self.weekly_upkeep(purchase.item)).sum();
+        if upkeep > 0.0 {
+            finances.record(today, LedgerEntryKind::Lifestyle, "Lifestyle upkeep".to_string(), -upkeep);
+        }
+        self.apply_lifestyle_effects(player, finances);
+
+        WeeklyStatement {
+            wage,
+            bonuses,
+            tax,
+            agent_fee,
+            upkeep,
+            net: wage + bonuses - tax - agent_fee - upkeep,
+        }
+    }
+
+    /// Pays an agent their fee for a deal, as worked out by `AgentEngine::agent_fee`
+    pub fn charge_agent_fee(&self, finances: &mut PlayerFinances, agent: &Agent, fee: f32, today: NaiveDate) {
+        if fee > 0.0 {
+            finances.record(today, LedgerEntryKind::AgentFee, format!("{}'s fee", agent.name), -fee);
+        }
+    }
+
+    /// Income tax rate for footballers playing in a country
+    pub fn tax_rate(&self, country: &str) -> f32 {
+        match country {
+            "England" | "Scotland" | "Wales" => 0.45,
+            "Spain" => 0.47,
+            "Italy" => 0.43,
+            "Germany" => 0.45,
+            "France" => 0.45,
+            "Portugal" => 0.48,
+            "Netherlands" => 0.495,
+            _ => 0.40,
+        }
+    }
+
+    /// Buys a lifestyle item if the player can afford it and doesn't already own one
+    pub fn buy_lifestyle(&self, player: &mut Player, finances: &mut PlayerFinances, item: LifestyleItem, today: NaiveDate) -> bool {
+        let price = self.purchase_price(item);
+        if finances.balance < price || finances.lifestyle.iter().any(|purchase| purchase.item == item) {
+            return false;
+        }
+
+        if price > 0.0 {
+            finances.record(today, LedgerEntryKind::Lifestyle, format!("Bought a {}", item.name()), -price);
+        }
+        finances.lifestyle.push(LifestylePurchase {
+            item,
+            bought_on: today,
+            price,
+        });
+
+        match item {
+            LifestyleItem::House => player.morale = (player.morale + 5.0).min(100.0),
+            LifestyleItem::Car => {
+                player.morale = (player.morale + 3.0).min(100.0);
+                player.local_reputation = (player.local_reputation + 0.5).min(100.0);
+            }
+            LifestyleItem::PersonalTrainer => {}
+        }
+        true
+    }
+
+    /// Up-front cost of a lifestyle item
+    pub fn purchase_price(&self, item: LifestyleItem) -> f32 {
+        match item {
+            LifestyleItem::House => 400_000.0,
+            LifestyleItem::Car => 60_000.0,
+            LifestyleItem::PersonalTrainer => 0.0,
+        }
+    }
+
+    /// Weekly running cost of a lifestyle item
+    pub fn weekly_upkeep(&self, item: LifestyleItem) -> f32 {
+        match item {
+            LifestyleItem::House => 500.0,
+            LifestyleItem::Car => 100.0,
+            LifestyleItem::PersonalTrainer => 1_500.0,
+        }
+    }
+
+    /// The player's cash plus what their house and car are worth
+    pub fn net_worth(&self, finances: &PlayerFinances) -> f32 {
+        let assets: f32 = finances.lifestyle.iter()
+            .map(|purchase| match purchase.item {
+                LifestyleItem::House => purchase.price,
+                LifestyleItem::Car => purchase.price * CAR_RESALE_SHARE,
+                LifestyleItem::PersonalTrainer => 0.0,
+            })
+            .sum();
+        finances.balance + assets
+    }
+
+    /// Records the player's net worth at the end of a season, for their career legacy
+    pub fn record_net_worth(&self, finances: &mut PlayerFinances, season: &str) {
+        let net_worth = self.net_worth(finances);
+        finances.net_worth_history.push(NetWorthRecord {
+            season: season.to_string(),
+            net_worth,
+        });
+    }
+
+    /// Weekly effects of what the player has spent their money on
+    fn apply_lifestyle_effects(&self, player: &mut Player, finances: &PlayerFinances) {
+        for purchase in &finances.lifestyle {
+            match purchase.item {
+                LifestyleItem::House => player.morale = (player.morale + HOUSE_WEEKLY_MORALE).min(100.0),
+                LifestyleItem::PersonalTrainer => {
+                    player.fitness = (player.fitness + TRAINER_WEEKLY_FITNESS).min(100.0);
+                    player.fatigue = (player.fatigue - TRAINER_WEEKLY_RECOVERY).max(0.0);
+                }
+                LifestyleItem::Car => {}
+            }
+        }
+    }
+
+    /// Whether an agent fee has already been paid since the current contract started
+    fn agent_paid_for_contract(&self, player: &Player, finances: &PlayerFinances) -> bool {
+        let start = player.contract.contract_end_date
+            .checked_sub_months(Months::new(12 * player.contract.length_years as u32))
+            .unwrap_or(player.contract.contract_end_date);
+        finances.ledger.iter().any(|entry| entry.kind == LedgerEntryKind::AgentFee && entry.date >= start)
+    }
+
+    /// The current contract's terms, for working out the agent's fee; the signing-on fee is long spent
+    fn contract_terms(&self, player: &Player) -> ContractTerms {
+        ContractTerms {
+            wage: player.contract.wage,
+            length_years: player.contract.length_years,
+            squad_role: player.contract.squad_role,
+            release_clause: player.contract.release_clause,
+            signing_on_fee: 0.0,
+            performance_bonuses: player.contract.performance_bonuses.clone(),
+        }
+    }
+}
+
+/// What came in and went out in a week
+#[derive(Debug, Clone, Copy)]
+pub struct WeeklyStatement {
+    pub wage: f32,
+    pub bonuses: f32,
+    pub tax: f32,
+    pub agent_fee: f32,
+    pub upkeep: f32,
+    pub net: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::{Loan, SquadRole};
+    use crate::test_support::{create_test_player, date};
+    
+    use uuid::Uuid;
+
+    #[test]
+    fn test_weekly_wage_tax_and_agent_fee() {
+        let engine = FinanceEngine::new();
+        let mut player = create_test_player();
+        player.contract.wage = 10000.0;
+        player.contract.length_years = 3;
+        player.contract.contract_end_date = date(2029, 6, 30);
+        let agent = create_agent(10.0);
+        let mut finances = PlayerFinances::new();
+        finances.record(date(2026, 8, 7), LedgerEntryKind::Bonus, "20 appearances".to_string(), 5000.0);
+
+        let statement = engine.process_week(&mut player, &mut finances, "England", Some(&agent), date(2026, 8, 10));
+
+        // The agent takes a year's wages at their rate once, on the contract's first payday
+        assert_eq!(statement.tax, 15000.0 * 0.45);
+        assert_eq!(statement.agent_fee, 52000.0);
+        assert_eq!(statement.net, 15000.0 - 6750.0 - 52000.0);
+        assert_eq!(finances.balance, statement.net);
+        assert!(finances.ledger.iter().any(|entry| entry.kind == LedgerEntryKind::AgentFee));
+
+        // No more fees on this contract, and old bonuses aren't taxed again
+        let statement = engine.process_week(&mut player, &mut finances, "Spain", Some(&agent), date(2026, 8, 17));
+        assert_eq!(statement.agent_fee, 0.0);
+        assert_eq!(statement.tax, 10000.0 * 0.47);
+
+        // A fee the agent already took when negotiating a new contract isn't charged again
+        player.contract.contract_end_date = date(2031, 6, 30);
+        engine.charge_agent_fee(&mut finances, &agent, 60000.0, date(2028, 7, 1));
+        let statement = engine.process_week(&mut player, &mut finances, "England", Some(&agent), date(2028, 8, 10));
+        assert_eq!(statement.agent_fee, 0.0);
+    }
+
+    #[test]
+    fn test_loan_wage_is_paid_by_both_clubs() {
+        let engine = FinanceEngine::new();
+        let mut player = create_test_player();
+        player.contract.wage = 10000.0;
+        player.contract.loan = Some(Loan {
+            parent_club_id: player.contract.club_id,
+            loan_club_id: Uuid::new_v4(),
+            loan_manager_id: Uuid::new_v4(),
+            start_date: date(2026, 8, 1),
+            end_date: date(2027, 6, 30),
+            promised_role: SquadRole::FirstTeam,
+            promised_minutes_share: 0.7,
+            wage_split: 0.25,
+            purchase_option: None,
+            recall_allowed: true,
+            appearances: 0,
+        });
+        let mut finances = PlayerFinances::new();
+
+        let statement = engine.process_week(&mut player, &mut finances, "England", None, date(2026, 8, 10));
+
+        assert_eq!(statement.wage, 10000.0);
+        let wages: Vec<f32> = finances.ledger.iter()
+            .filter(|entry| entry.kind == LedgerEntryKind::Wage)
+            .map(|entry| entry.amount)
+            .collect();
+        assert_eq!(wages, vec![7500.0, 2500.0]);
+    }
+
+    #[test]
+    fn test_lifestyle_spending_and_net_worth() {
+        let engine = FinanceEngine::new();
+        let mut player = create_test_player();
+        player.contract.wage = 0.0;
+        player.morale = 50.0;
+        player.fitness = 80.0;
+        let mut finances = PlayerFinances::new();
+        finances.record(date(2026, 8, 1), LedgerEntryKind::Wage, "Savings".to_string(), 300000.0);
+
+        assert!(!engine.buy_lifestyle(&mut player, &mut finances, LifestyleItem::House, date(2026, 8, 3)));
+        finances.record(date(2026, 8, 3), LedgerEntryKind::Wage, "Savings".to_string(), 200000.0);
+        assert!(engine.buy_lifestyle(&mut player, &mut finances, LifestyleItem::House, date(2026, 8, 3)));
+        assert!(!engine.buy_lifestyle(&mut player, &mut finances, LifestyleItem::House, date(2026, 8, 3)));
+        assert!(engine.buy_lifestyle(&mut player, &mut finances, LifestyleItem::PersonalTrainer, date(2026, 8, 3)));
+        assert_eq!(finances.balance, 100000.0);
+        assert_eq!(player.morale, 55.0);
+
+        let statement = engine.process_week(&mut player, &mut finances, "England", None, date(2026, 8, 10));
+        assert_eq!(statement.upkeep, 2000.0);
+        assert_eq!(player.fitness, 81.0);
+        assert_eq!(player.morale, 55.5);
+
+        engine.record_net_worth(&mut finances, "2026-27");
+        assert_eq!(finances.net_worth_history[0].net_worth, 98000.0 + 400000.0);
+    }
+
+    // Helper functions for tests
+    fn create_agent(fee_percentage: f32) -> Agent {
+        Agent {
+            id: Uuid::new_v4(),
+            name: "Test Agent".to_string(),
+            negotiation_skill: 50,
+            greed: 50,
+            network_reach: 50,
+            loyalty: 50,
+            fee_percentage,
+            network: vec![],
+        }
+    }
+}
@@ -0,0 +1,394 @@
+// src/systems/free_agency_system.rs
+use chrono::{Months, NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::core::game_state::{PreContract, TransferSystemState, TrialPeriod};
+use crate::core::time_engine::{EventPriority, ScheduledEvent, ScheduledEventType, TimeEngine};
+use crate::entities::{ContractTerms, Player, SquadRole, Team};
+use crate::systems::TransferEngine;
+
+/// Months before expiry that foreign clubs may agree a pre-contract
+const PRE_CONTRACT_MONTHS: u32 = 6;
+/// How far above the player's level a club can be and still want them
+const REPUTATION_REACH: f32 = 10.0;
+/// How far below a club's level a free agent can be and still be offered a trial
+const TRIAL_REACH: f32 = 20.0;
+/// Length of a trial period
+const TRIAL_DAYS: i64 = 14;
+/// Form a trialist needs to show to earn a contract
+const TRIAL_PASS_FORM: f32 = 6.5;
+/// Fitness a trialist needs to earn a contract
+const TRIAL_PASS_FITNESS: f32 = 70.0;
+/// Weeks of wages paid as a signing-on fee, since the buying club saves the transfer fee
+const FREE_TRANSFER_SIGNING_WEEKS: f32 = 10.0;
+
+/// The FreeAgencyEngine handles contracts running out
+/// It lets foreign clubs agree pre-contracts, releases out-of-contract players and runs the free-agent market
+pub struct FreeAgencyEngine;
+
+impl Default for FreeAgencyEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FreeAgencyEngine {
+    /// Creates a new FreeAgencyEngine instance
+    pub fn new() -> Self {
+        FreeAgencyEngine
+    }
+
+    /// Queues the player's contract expiry, replacing any expiry queued for an older contract
+    pub fn schedule_contract_expiry(&self, player: &Player, time_engine: &mut TimeEngine) {
+        let player_id = player.id;
+        time_engine.remove_events(|event| event.event_type == ScheduledEventType::ContractExpires(player_id));
+        time_engine.schedule_event(ScheduledEvent::new(
+            player.contract.contract_end_date.and_time(NaiveTime::MIN).and_utc(),
+            ScheduledEventType::ContractExpires(player_id),
+            EventPriority::High,
+            false,
+        ));
+    }
+
+    /// Whether the player has no club
+    pub fn is_free_agent(&self, state: &TransferSystemState) -> bool {
+        state.free_agent_since.is_some()
+    }
+
+    /// Whether the contract is in its final six months, when foreign clubs may agree a pre-contract
+    pub fn pre_contract_window_open(&self, player: &Player, today: NaiveDate) -> bool {
+        let end = player.contract.contract_end_date;
+        let opens = end.checked_sub_months(Months::new(PRE_CONTRACT_MONTHS)).unwrap_or(end);
+        today >= opens && today < end
+    }
+
+    /// Pre-contract offers from clubs abroad, which pay no fee for a player whose contract is running down
+    pub fn generate_pre_contract_offers(
+        &self,
+        player: &Player,
+        state: &TransferSystemState,
+        current_club: &Team,
+        all_teams: &[Team],
+        today: NaiveDate,
+    ) -> Vec<FreeTransferOffer> {
+        if state.pre_contract.is_some() || !self.pre_contract_window_open(player, today) {
+            return Vec::new();
+        }
+
+        let ability = player.ability_score();
+        all_teams
+            .iter()
+            .filter(|team| team.id != current_club.id && team.country != current_club.country)
+            .filter(|team| ability + REPUTATION_REACH >= team.reputation)
+            .map(|team| FreeTransferOffer {
+                id: Uuid::new_v4(),
+                club_id: team.id,
+                terms: self.free_transfer_terms(player, team, ability, today),
+                trial_days: None,
+            })
+            .collect()
+    }
+
+    /// Agrees a pre-contract; the move happens when the current contract expires
+    ///
+    /// Returns false if the window is closed, a pre-contract is already signed or the club isn't abroad.
+    pub fn sign_pre_contract(
+        &self,
+        player: &Player,
+        state: &mut TransferSystemState,
+        current_club: &Team,
+        club: &Team,
+        offer: &FreeTransferOffer,
+        today: NaiveDate,
+    ) -> bool {
+        if state.pre_contract.is_some()
+            || !self.pre_contract_window_open(player, today)
+            || club.country == current_club.country
+        {
+            return false;
+        }
+
+        state.pre_contract = Some(PreContract {
+            club_id: club.id,
+            signed_on: today,
+            terms: offer.terms.clone(),
+        });
+        true
+    }
+
+    /// Acts on a `ContractExpires` event: the player joins their pre-contract club or becomes a free agent
+    ///
+    /// Returns None if the contract has since been extended or has already been handled.
+    pub fn handle_contract_expiry(
+        &self,
+        player: &mut Player,
+        state: &mut TransferSystemState,
+        all_teams: &[Team],
+        today: NaiveDate,
+    ) -> Option<ExpiryOutcome> {
+        if today < player.contract.contract_end_date || self.is_free_agent(state) {
+            return None;
+        }
+
+        // A loan can't outlast the contract it hangs on
+        if let Some(loan) = player.contract.loan.take() {
+            player.contract.club_id = loan.parent_club_id;
+        }
+        let former_club_id = player.contract.club_id;
+
+        let new_club = state.pre_contract.take().and_then(|pre_contract| {
+            all_teams
+                .iter()
+                .find(|team| team.id == pre_contract.club_id)
+                .map(|team| (team, pre_contract.terms))
+        });
+        if let Some((club, terms)) = new_club {
+            self.join_club(player, state, club, &terms, today);
+            return Some(ExpiryOutcome::JoinedClub(club.id));
+        }
+
+        player.contract.club_id = Uuid::nil();
+        player.contract.wage = 0.0;
+        player.contract.release_clause = None;
+        player.contract.performance_bonuses.clear();
+        state.free_agent_since = Some(today);
+        state.transfer_request = None;
+        Some(ExpiryOutcome::FreeAgent(former_club_id))
+    }
+
+    /// Offers on the free-agent market: clubs the player clearly suits offer a contract, others a trial
+    pub fn generate_free_agent_offers(
+        &self,
+        player: &Player,
+        state: &TransferSystemState,
+        all_teams: &[Team],
+        today: NaiveDate,
+    ) -> Vec<FreeTransferOffer> {
+        if !self.is_free_agent(state) || state.trial.is_some() {
+            return Vec::new();
+        }
+
+        let ability = player.ability_score();
+        all_teams
+            .iter()
+            .filter(|team| ability + TRIAL_REACH >= team.reputation)
+            .map(|team| FreeTransferOffer {
+                id: Uuid::new_v4(),
+                club_id: team.id,
+                terms: self.free_transfer_terms(player, team, ability, today),
+                trial_days: (ability < team.reputation).then_some(TRIAL_DAYS),
+            })
+            .collect()
+    }
+
+    /// Takes up a free-agent offer: signs straight away or starts the trial
+    ///
+    /// Returns true if the player signed.
+    pub fn accept_free_agent_offer(
+        &self,
+        player: &mut Player,
+        state: &mut TransferSystemState,
+        club: &Team,
+        offer: &FreeTransferOffer,
+        today: NaiveDate,
+    ) -> bool {
+        match offer.trial_days {
+            Some(days) => {
+                state.trial = Some(TrialPeriod {
+                    club_id: club.id,
+                    start_date: today,
+                    end_date: today + chrono::Duration::days(days),
+                    terms: offer.terms.clone(),
+                });
+                false
+            }
+            None => {
+                self.join_club(player, state, club, &offer.terms, today);
+                true
+            }
+        }
+    }
+
+    /// Ends a trial on its last day; the club signs the player if they have impressed
+    pub fn process_trial(
+        &self,
+        player: &mut Player,
+        state: &mut TransferSystemState,
+        all_teams: &[Team],
+        today: NaiveDate,
+    ) -> Option<TrialOutcome> {
+        let trial = state.trial.as_ref()?;
+        if today < trial.end_date {
+            return None;
+        }
+
+        let trial = state.trial.take()?;
+        let impressed = player.form >= TRIAL_PASS_FORM
+            && player.fitness >= TRIAL_PASS_FITNESS
+            && player.injury_status.is_none();
+        match all_teams.iter().find(|team| team.id == trial.club_id) {
+            Some(club) if impressed => {
+                self.join_club(player, state, club, &trial.terms, today);
+                Some(TrialOutcome::Signed(club.id))
+            }
+            _ => Some(TrialOutcome::Released(trial.club_id)),
+        }
+    }
+
+    /// Signs the player to a new club on the agreed terms; free transfers don't wait for a window
+    fn join_club(&self, player: &mut Player, state: &mut TransferSystemState, club: &Team, terms: &ContractTerms, today: NaiveDate) {
+        TransferEngine::new().sign_contract(player, club, terms, today);
+        state.free_agent_since = None;
+        state.trial = None;
+        state.transfer_request = None;
+    }
+
+    /// Terms a club offers when it signs the player without paying a fee
+    fn free_transfer_terms(&self, player: &Player, club: &Team, ability: f32, today: NaiveDate) -> ContractTerms {
+        let offer = TransferEngine::new().generate_transfer_offer(player, club, &player.contract, today);
+        let squad_role = if ability >= club.reputation + REPUTATION_REACH {
+            SquadRole::KeyPlayer
+        } else if ability >= club.reputation {
+            SquadRole::FirstTeam
+        } else if ability >= club.reputation - REPUTATION_REACH {
+            SquadRole::Rotation
+        } else {
+            SquadRole::Backup
+        };
+
+        ContractTerms {
+            wage: offer.offered_wage,
+            length_years: offer.contract_length_years,
+            squad_role,
+            release_clause: None,
+            signing_on_fee: offer.offered_wage * FREE_TRANSFER_SIGNING_WEEKS,
+            performance_bonuses: vec![],
+        }
+    }
+}
+
+/// A club's offer to sign a player without a transfer fee
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FreeTransferOffer {
+    pub id: Uuid,
+    pub club_id: Uuid,
+    pub terms: ContractTerms,
+    pub trial_days: Option<i64>, // Set when the club wants a look at the player first
+}
+
+/// What happened when the player's contract ran out
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ExpiryOutcome {
+    JoinedClub(Uuid), // The pre-contract club
+    FreeAgent(Uuid),  // The club the player left
+}
+
+/// How a trial ended
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum TrialOutcome {
+    Signed(Uuid),
+    Released(Uuid),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{create_test_player, create_test_team, date};
+    
+    use chrono::{Datelike, TimeZone, Utc};
+
+    #[test]
+    fn test_pre_contract_with_foreign_club() {
+        let engine = FreeAgencyEngine::new();
+        let mut player = create_test_player();
+        let mut state = TransferSystemState::new();
+        let mut current = create_test_team();
+        current.id = player.contract.club_id;
+        let domestic = create_test_team();
+        let mut foreign = create_test_team();
+        foreign.country = "Abroad".to_string();
+        let mut elite = create_test_team();
+        elite.country = "Abroad".to_string();
+        elite.reputation = 95.0;
+        let teams = vec![current.clone(), domestic.clone(), foreign.clone(), elite];
+
+        // Contract ends on 1 January 2026, so clubs abroad may talk from 1 July
+        assert!(engine.generate_pre_contract_offers(&player, &state, &current, &teams, date(2025, 6, 30)).is_empty());
+        let offers = engine.generate_pre_contract_offers(&player, &state, &current, &teams, date(2025, 7, 1));
+        assert_eq!(offers.len(), 1);
+        assert_eq!(offers[0].club_id, foreign.id);
+        assert!(offers[0].terms.signing_on_fee > 0.0);
+
+        assert!(!engine.sign_pre_contract(&player, &mut state, &current, &domestic, &offers[0], date(2025, 7, 1)));
+        assert!(engine.sign_pre_contract(&player, &mut state, &current, &foreign, &offers[0], date(2025, 7, 1)));
+        assert!(engine.generate_pre_contract_offers(&player, &state, &current, &teams, date(2025, 7, 8)).is_empty());
+
+        assert!(engine.handle_contract_expiry(&mut player, &mut state, &teams, date(2025, 12, 31)).is_none());
+        let outcome = engine.handle_contract_expiry(&mut player, &mut state, &teams, date(2026, 1, 1));
+
+        assert_eq!(outcome, Some(ExpiryOutcome::JoinedClub(foreign.id)));
+        assert_eq!(player.contract.club_id, foreign.id);
+        assert_eq!(player.contract.wage, offers[0].terms.wage);
+        assert_eq!(player.contract.contract_end_date.year(), 2026 + offers[0].terms.length_years as i32);
+        assert!(state.pre_contract.is_none());
+        assert!(!engine.is_free_agent(&state));
+    }
+
+    #[test]
+    fn test_free_agent_market_and_trial() {
+        let engine = FreeAgencyEngine::new();
+        let mut player = create_test_player();
+        let former_club = player.contract.club_id;
+        let mut state = TransferSystemState::new();
+        let mut modest = create_test_team();
+        modest.reputation = 70.0;
+        let mut ambitious = create_test_team();
+        ambitious.reputation = 90.0;
+        let mut elite = create_test_team();
+        elite.reputation = 99.0;
+        let teams = vec![modest.clone(), ambitious.clone(), elite];
+
+        let outcome = engine.handle_contract_expiry(&mut player, &mut state, &teams, date(2026, 1, 1));
+        assert_eq!(outcome, Some(ExpiryOutcome::FreeAgent(former_club)));
+        assert!(engine.is_free_agent(&state));
+        assert!(player.contract.club_id.is_nil());
+        assert_eq!(player.contract.wage, 0.0);
+        assert_eq!(TransferEngine::new().selling_valuation(&player, date(2026, 1, 1)), 0.0);
+        assert!(engine.handle_contract_expiry(&mut player, &mut state, &teams, date(2026, 1, 2)).is_none());
+
+        let offers = engine.generate_free_agent_offers(&player, &state, &teams, date(2026, 1, 5));
+        assert_eq!(offers.len(), 2);
+        let straight = offers.iter().find(|offer| offer.club_id == modest.id).unwrap();
+        assert!(straight.trial_days.is_none());
+        let trial = offers.iter().find(|offer| offer.club_id == ambitious.id).unwrap();
+        assert_eq!(trial.trial_days, Some(TRIAL_DAYS));
+
+        assert!(!engine.accept_free_agent_offer(&mut player, &mut state, &ambitious, trial, date(2026, 1, 5)));
+        assert!(engine.generate_free_agent_offers(&player, &state, &teams, date(2026, 1, 5)).is_empty());
+        assert!(engine.process_trial(&mut player, &mut state, &teams, date(2026, 1, 18)).is_none());
+        let result = engine.process_trial(&mut player, &mut state, &teams, date(2026, 1, 19));
+
+        assert_eq!(result, Some(TrialOutcome::Signed(ambitious.id)));
+        assert_eq!(player.contract.club_id, ambitious.id);
+        assert!(matches!(player.contract.squad_role, SquadRole::Backup));
+        assert!(!engine.is_free_agent(&state));
+    }
+
+    #[test]
+    fn test_contract_expiry_event_fires() {
+        let engine = FreeAgencyEngine::new();
+        let player = create_test_player();
+        let mut time_engine = TimeEngine::new(Utc.with_ymd_and_hms(2025, 12, 31, 23, 30, 0).unwrap());
+
+        engine.schedule_contract_expiry(&player, &mut time_engine);
+        engine.schedule_contract_expiry(&player, &mut time_engine);
+        assert_eq!(time_engine.event_queue.len(), 1);
+
+        time_engine.advance_time().unwrap();
+        let fired = time_engine.take_triggered_events();
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].event_type, ScheduledEventType::ContractExpires(player.id));
+        assert!(time_engine.take_triggered_events().is_empty());
+    }
+}
@@ -0,0 +1,521 @@
+// src/systems/international_system.rs
+use chrono::{Datelike, NaiveDate};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::core::game_state::GameState;
+use crate::entities::{
+    InternationalFixture, InternationalMatchType, InternationalTournament, MatchResult, NationalTeam,
+    NationalTeamManager, Player, Position
+};
+use crate::systems::reputation_system::{MatchImportance, TeamPerformance};
+use crate::systems::{ReputationEngine, TournamentEngine};
+
+/// Lowest selection score a manager will call up
+const MIN_CALL_UP_SCORE: f32 = 45.0;
+/// Youngest age for a senior call-up
+const MIN_SENIOR_AGE: u8 = 17;
+/// International windows as (month, day) of the first matchday
+const INTERNATIONAL_WINDOWS: [(u32, u32); 5] = [(9, 1), (10, 6), (11, 10), (3, 20), (6, 1)];
+/// Days between the two matches of an international window
+const DAYS_BETWEEN_WINDOW_MATCHES: i64 = 3;
+/// Nations invited to play a side left without an opponent
+const GUEST_NATIONS: [&str; 6] = ["Brazil", "France", "Argentina", "Germany", "Spain", "Italy"];
+/// Fatigue added to a starter for each match at a tournament finals
+const TOURNAMENT_MATCH_FATIGUE: f32 = 10.0;
+
+/// The InternationalEngine runs national teams during international breaks
+/// It picks squads from reputation and form, plays friendlies and qualifiers and tracks caps
+pub struct InternationalEngine;
+
+impl Default for InternationalEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InternationalEngine {
+    /// Creates a new InternationalEngine instance
+    pub fn new() -> Self {
+        InternationalEngine
+    }
+
+    /// Creates a national team for every nationality that doesn't have one yet
+    pub fn generate_national_teams(&self, national_teams: &mut Vec<NationalTeam>, players: &[&Player]) {
+        let mut rng = rand::thread_rng();
+
+        for player in players {
+            if national_teams.iter().any(|team| team.nationality == player.nationality) {
+                continue;
+            }
+
+            national_teams.push(NationalTeam {
+                id: Uuid::new_v4(),
+                nationality: player.nationality.clone(),
+                name: format!("{} National Team", player.nationality),
+                manager: NationalTeamManager {
+                    form_weight: rng.gen_range(0.2..0.6),
+                    squad_size: 23,
+                },
+                squad: Vec::new(),
+            });
+        }
+    }
+
+    /// How strongly a manager rates a player, 0-100
+    pub fn selection_score(&self, player: &Player, manager: &NationalTeamManager) -> f32 {
+        let reputation = player.international_reputation * 0.7 + player.local_reputation * 0.3;
+        // Form is the average of recent match ratings
+        let form = (player.form * 10.0).clamp(0.0, 100.0);
+
+        reputation * (1.0 - manager.form_weight) + form * manager.form_weight
+    }
+
+    /// Picks the squad for the next international window from fit, eligible players
+    pub fn select_squad(&self, team: &mut NationalTeam, players: &[&Player]) -> Vec<Uuid> {
+        let mut candidates: Vec<(&Player, f32)> = players.iter()
+            .filter(|player| player.nationality == team.nationality)
+            .filter(|player| player.age >= MIN_SENIOR_AGE && player.injury_status.is_none())
+            .map(|player| (*player, self.selection_score(player, &team.manager)))
+            .filter(|(_, score)| *score >= MIN_CALL_UP_SCORE)
+            .collect();
+        candidates.sort_by(|a, b| b.1.total_cmp(&a.1));
+        candidates.truncate(team.manager.squad_size);
+
+        team.squad = candidates.iter().map(|(player, _)| player.id).collect();
+        team.squad.clone()
+    }
+
+    /// First matchdays of the international windows that fall inside a season
+    pub fn international_break_dates(&self, season_start: NaiveDate, season_end: NaiveDate) -> Vec<NaiveDate> {
+        let mut dates: Vec<NaiveDate> = (season_start.year()..=season_end.year())
+            .flat_map(|year| {
+                INTERNATIONAL_WINDOWS.iter().filter_map(move |(month, day)| NaiveDate::from_ymd_opt(year, *month, *day))
+            })
+            .filter(|date| *date >= season_start && *date <= season_end)
+            .collect();
+        dates.sort();
+        dates
+    }
+
+    /// Autumn and spring windows are for qualifiers, the rest for friendlies
+    pub fn match_type_for(&self, date: NaiveDate) -> InternationalMatchType {
+        match date.month() {
+            9..=11 | 3 => InternationalMatchType::Qualifier,
+            _ => InternationalMatchType::Friendly,
+        }
+    }
+
+    /// Calls up squads and plays both matches of an international window
+    /// Nations with a tournament qualifier that day play it, the rest are paired off for friendlies
+    pub fn run_international_break(
+        &self,
+        national_teams: &mut Vec<NationalTeam>,
+        tournaments: &mut Vec<InternationalTournament>,
+        players: &mut [&mut Player],
+        date: NaiveDate,
+    ) -> InternationalBreakReport {
+        let views: Vec<&Player> = players.iter().map(|player| &**player).collect();
+        self.generate_national_teams(national_teams, &views);
+        let mut report = InternationalBreakReport {
+            call_ups: self.call_up_squads(national_teams.iter_mut().collect(), players, date),
+            fixtures: Vec::new(),
+        };
+
+        let tournament_engine = TournamentEngine::new();
+        tournament_engine.ensure_tournaments(tournaments, national_teams, date);
+
+        let mut rng = rand::thread_rng();
+        for round in 0..2 {
+            let match_date = date + chrono::Duration::days(round * DAYS_BETWEEN_WINDOW_MATCHES);
+            let qualifiers = tournament_engine.play_qualifiers(tournaments, national_teams, players, match_date);
+            let busy: Vec<Uuid> = qualifiers.iter()
+                .flat_map(|fixture| [fixture.home_team, fixture.away_team])
+                .collect();
+            report.fixtures.extend(qualifiers);
+
+            // Only nations with a squad and no qualifier take the field for a friendly
+            let mut playing: Vec<usize> = (0..national_teams.len())
+                .filter(|i| !national_teams[*i].squad.is_empty() && !busy.contains(&national_teams[*i].id))
+                .collect();
+            playing.shuffle(&mut rng);
+            for pair in playing.chunks(2) {
+                let (home, away) = match *pair {
+                    [home, away] => (home, away),
+                    [home] => (home, self.guest_opponent(national_teams, home, &busy)),
+                    _ => continue,
+                };
+                let played = self.play_match(
+                    &national_teams[home],
+                    &national_teams[away],
+                    players,
+                    match_date,
+                    InternationalMatchType::Friendly,
+                );
+                report.fixtures.push(played.fixture);
+            }
+        }
+
+        report
+    }
+
+    /// Runs an international window across the whole game world
+    pub fn run_international_break_for_world(&self, game_state: &mut GameState, date: NaiveDate) -> InternationalBreakReport {
+        let mut players: Vec<&mut Player> = std::iter::once(&mut game_state.player)
+            .chain(game_state.players.iter_mut())
+            .collect();
+        let report = self.run_international_break(
+            &mut game_state.national_teams,
+            &mut game_state.international_tournaments,
+            &mut players,
+            date,
+        );
+        game_state.international_fixtures.extend(report.fixtures.iter().cloned());
+        report
+    }
+
+    /// Picks the squads of the given national teams and records first call-ups
+    pub fn call_up_squads(
+        &self,
+        national_teams: Vec<&mut NationalTeam>,
+        players: &mut [&mut Player],
+        date: NaiveDate,
+    ) -> Vec<CallUp> {
+        let views: Vec<&Player> = players.iter().map(|player| &**player).collect();
+        let mut call_ups = Vec::new();
+        for team in national_teams {
+            for player_id in self.select_squad(team, &views) {
+                call_ups.push(CallUp { national_team: team.id, player_id, first_call_up: false });
+            }
+        }
+
+        for call_up in call_ups.iter_mut() {
+            if let Some(player) = players.iter_mut().find(|player| player.id == call_up.player_id) {
+                if player.career_stats.first_call_up.is_none() {
+                    player.career_stats.first_call_up = Some(date);
+                    call_up.first_call_up = true;
+                }
+            }
+        }
+
+        call_ups
+    }
+
+    /// Adds guest nations until there are at least `count` national teams
+    pub fn invite_guest_nations(&self, national_teams: &mut Vec<NationalTeam>, count: usize) {
+        while national_teams.len() < count {
+            self.invite_guest_nation(national_teams);
+        }
+    }
+
+    /// Index of a nation without a called-up squad to face the odd side out, inviting one if needed
+    fn guest_opponent(&self, national_teams: &mut Vec<NationalTeam>, team: usize, busy: &[Uuid]) -> usize {
+        let mut rng = rand::thread_rng();
+        let squadless: Vec<usize> = (0..national_teams.len())
+            .filter(|i| *i != team && national_teams[*i].squad.is_empty() && !busy.contains(&national_teams[*i].id))
+            .collect();
+        if let Some(opponent) = squadless.choose(&mut rng) {
+            return *opponent;
+        }
+
+        self.invite_guest_nation(national_teams)
+    }
+
+    /// Adds a national team for the first guest nation not already in the game, returning its index
+    fn invite_guest_nation(&self, national_teams: &mut Vec<NationalTeam>) -> usize {
+        let mut rng = rand::thread_rng();
+        let nationality = GUEST_NATIONS.iter()
+            .find(|nation| national_teams.iter().all(|existing| existing.nationality != **nation))
+            .copied()
+            .map(|nation| nation.to_string())
+            .unwrap_or_else(|| format!("Invitational {}", national_teams.len() + 1));
+        national_teams.push(NationalTeam {
+            id: Uuid::new_v4(),
+            nationality: nationality.clone(),
+            name: format!("{} National Team", nationality),
+            manager: NationalTeamManager {
+                form_weight: rng.gen_range(0.2..0.6),
+                squad_size: 23,
+            },
+            squad: Vec::new(),
+        });
+        national_teams.len() - 1
+    }
+
+    /// Plays one international, crediting caps, goals and reputation to the starting elevens
+    pub(crate) fn play_match(
+        &self,
+        home: &NationalTeam,
+        away: &NationalTeam,
+        players: &mut [&mut Player],
+        date: NaiveDate,
+        match_type: InternationalMatchType,
+    ) -> PlayedInternational {
+        let mut rng = rand::thread_rng();
+        let home_xi = self.starting_eleven(home, players);
+        let away_xi = self.starting_eleven(away, players);
+        let home_strength = self.team_strength(home, &home_xi, players);
+        let away_strength = self.team_strength(away, &away_xi, players);
+
+        // Six chances each, converted more often by the stronger side
+        let mut goals_for = |strength: f32, opponent: f32| -> u8 {
+            let conversion = (0.2 * strength / opponent.max(1.0)).clamp(0.05, 0.45);
+            (0..6).filter(|_| rng.gen::<f32>() < conversion).count() as u8
+        };
+        let home_goals = goals_for(home_strength * 1.1, away_strength); // Home advantage
+        let away_goals = goals_for(away_strength, home_strength);
+
+        let mut appearances = self.credit_players(&home_xi, players, home_goals, away_goals, match_type, away_strength);
+        appearances.extend(self.credit_players(&away_xi, players, away_goals, home_goals, match_type, home_strength));
+
+        let fixture = InternationalFixture {
+            id: Uuid::new_v4(),
+            home_team: home.id,
+            away_team: away.id,
+            date,
+            match_type,
+            result: Some(MatchResult {
+                home_score: home_goals,
+                away_score: away_goals,
+                winner: match home_goals.cmp(&away_goals) {
+                    std::cmp::Ordering::Greater => Some(home.id),
+                    std::cmp::Ordering::Less => Some(away.id),
+                    std::cmp::Ordering::Equal => None,
+                },
+            }),
+        };
+
+        PlayedInternational { fixture, home_strength, away_strength, appearances }
+    }
+
+    /// The eleven best-rated players in the squad
+    fn starting_eleven(&self, team: &NationalTeam, players: &[&mut Player]) -> Vec<Uuid> {
+        let mut squad: Vec<(Uuid, f32)> = players.iter()
+            .filter(|player| team.squad.contains(&player.id))
+            .map(|player| (player.id, self.selection_score(player, &team.manager)))
+            .collect();
+        squad.sort_by(|a, b| b.1.total_cmp(&a.1));
+        squad.into_iter().take(11).map(|(id, _)| id).collect()
+    }
+
+    /// Average selection score of the eleven, with empty places filled by fringe players
+    fn team_strength(&self, team: &NationalTeam, eleven: &[Uuid], players: &[&mut Player]) -> f32 {
+        let total: f32 = players.iter()
+            .filter(|player| eleven.contains(&player.id))
+            .map(|player| self.selection_score(player, &team.manager))
+            .sum();
+        let fringe = (11 - eleven.len().min(11)) as f32 * MIN_CALL_UP_SCORE * 0.8;
+
+        (total + fringe) / 11.0
+    }
+
+    /// Gives every starter a cap, shares out the goals and updates reputation
+    /// Returns each starter's goal tally for the match
+    fn credit_players(
+        &self,
+        eleven: &[Uuid],
+        players: &mut [&mut Player],
+        goals_scored: u8,
+        goals_conceded: u8,
+        match_type: InternationalMatchType,
+        opponent_strength: f32,
+    ) -> Vec<(Uuid, u32)> {
+        let mut rng = rand::thread_rng();
+        let reputation = ReputationEngine::new();
+
+        // Forwards are the likeliest scorers, but anyone on the pitch can get one
+        let mut scorers: Vec<Uuid> = Vec::new();
+        let weights: Vec<(Uuid, f32)> = players.iter()
+            .filter(|player| eleven.contains(&player.id))
+            .map(|player| (player.id, self.scoring_weight(player)))
+            .collect();
+        for _ in 0..goals_scored {
+            if let Ok((scorer, _)) = weights.choose_weighted(&mut rng, |(_, weight)| *weight) {
+                scorers.push(*scorer);
+            }
+        }
+
+        let team_performance = match goals_scored.cmp(&goals_conceded) {
+            std::cmp::Ordering::Greater => TeamPerformance::Win,
+            std::cmp::Ordering::Equal => TeamPerformance::Draw,
+            std::cmp::Ordering::Less => TeamPerformance::Loss,
+        };
+        let importance = match match_type {
+            InternationalMatchType::Qualifier => MatchImportance::Continental,
+            InternationalMatchType::Friendly => MatchImportance::Friendly,
+            InternationalMatchType::Tournament => MatchImportance::International,
+        };
+
+        let mut appearances = Vec::new();
+        for player in players.iter_mut().filter(|player| eleven.contains(&player.id)) {
+            let goals = scorers.iter().filter(|scorer| **scorer == player.id).count() as u32;
+            player.career_stats.international_caps += 1;
+            player.career_stats.international_goals += goals;
+
+            let rating = (6.5 + goals as f32 + if goals_scored > goals_conceded { 0.3 } else { 0.0 }).min(10.0);
+            reputation.update_reputation(player, rating, importance, goals > 0, opponent_strength, team_performance);
+
+            // Tournament football comes thick and fast, high stamina softens it
+            if match_type == InternationalMatchType::Tournament {
+                let fatigue = TOURNAMENT_MATCH_FATIGUE * (1.0 - player.physical.stamina as f32 / 200.0);
+                player.fatigue = (player.fatigue + fatigue).clamp(0.0, 100.0);
+            }
+            appearances.push((player.id, goals));
+        }
+
+        appearances
+    }
+
+    /// Relative chance of a player scoring an international goal
+    fn scoring_weight(&self, player: &Player) -> f32 {
+        let position_factor = match player.primary_position {
+            Position::CF | Position::SS => 4.0,
+            Position::RW | Position::LW | Position::AM => 2.5,
+            Position::CM | Position::RM | Position::LM => 1.2,
+            Position::DM | Position::CB | Position::RB | Position::LB | Position::FB => 0.5,
+            Position::GK => 0.01,
+        };
+        position_factor * (player.technical.shooting as f32 / 50.0).max(0.1)
+    }
+}
+
+/// A player named in a national team squad
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CallUp {
+    pub national_team: Uuid,
+    pub player_id: Uuid,
+    pub first_call_up: bool,
+}
+
+/// The outcome of one international and who played in it
+pub(crate) struct PlayedInternational {
+    pub fixture: InternationalFixture,
+    pub home_strength: f32,
+    pub away_strength: f32,
+    pub appearances: Vec<(Uuid, u32)>, // Starters and their goals
+}
+
+/// Everything that happened during an international window
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InternationalBreakReport {
+    pub call_ups: Vec<CallUp>,
+    pub fixtures: Vec<InternationalFixture>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::{Injury, InjurySeverity, InjuryType};
+    use crate::test_support::create_test_player;
+
+    #[test]
+    fn test_squad_selection() {
+        let engine = InternationalEngine::new();
+        let star = create_player("England", 80.0);
+        let squad_player = create_player("England", 55.0);
+        let mut unknown = create_player("England", 5.0);
+        unknown.form = 3.0;
+        let mut injured = create_player("England", 90.0);
+        injured.injury_status = Some(Injury {
+            injury_type: InjuryType::MuscleStrain,
+            severity: InjurySeverity::Minor,
+            weeks_remaining: 2,
+            affected_attributes: vec![],
+        });
+        let mut too_young = create_player("England", 90.0);
+        too_young.age = 16;
+        let foreigner = create_player("Spain", 90.0);
+
+        let players = vec![&star, &squad_player, &unknown, &injured, &too_young, &foreigner];
+        let mut national_teams = Vec::new();
+        engine.generate_national_teams(&mut national_teams, &players);
+        assert_eq!(national_teams.len(), 2);
+
+        let england = national_teams.iter_mut().find(|team| team.nationality == "England").unwrap();
+        let squad = engine.select_squad(england, &players);
+        assert_eq!(squad, vec![star.id, squad_player.id]);
+    }
+
+    #[test]
+    fn test_manager_weighs_form() {
+        let engine = InternationalEngine::new();
+        let mut in_form = create_player("England", 50.0);
+        in_form.form = 9.0;
+        let mut out_of_form = create_player("England", 50.0);
+        out_of_form.form = 5.0;
+
+        let form_manager = NationalTeamManager { form_weight: 0.6, squad_size: 23 };
+        let reputation_manager = NationalTeamManager { form_weight: 0.2, squad_size: 23 };
+        let gap = |manager: &NationalTeamManager| {
+            engine.selection_score(&in_form, manager) - engine.selection_score(&out_of_form, manager)
+        };
+
+        assert!(gap(&form_manager) > gap(&reputation_manager));
+        assert!(gap(&reputation_manager) > 0.0);
+    }
+
+    #[test]
+    fn test_international_break() {
+        let engine = InternationalEngine::new();
+        let friendly_date = NaiveDate::from_ymd_opt(2027, 6, 1).unwrap();
+        let qualifier_date = NaiveDate::from_ymd_opt(2027, 10, 6).unwrap();
+        assert_eq!(engine.match_type_for(friendly_date), InternationalMatchType::Friendly);
+        assert_eq!(engine.match_type_for(qualifier_date), InternationalMatchType::Qualifier);
+
+        let mut player = create_player("England", 70.0);
+        let mut mut_players = vec![&mut player];
+        let mut national_teams = Vec::new();
+        let mut tournaments = Vec::new();
+        let report = engine.run_international_break(&mut national_teams, &mut tournaments, &mut mut_players, friendly_date);
+
+        // The only nation with a squad plays friendlies against guest nations
+        assert_eq!(report.call_ups.len(), 1);
+        assert!(report.call_ups[0].first_call_up);
+        assert_eq!(report.fixtures.len(), 2);
+        assert!(report.fixtures.iter().all(|fixture| fixture.match_type == InternationalMatchType::Friendly));
+        assert!(report.fixtures.iter().all(|fixture| fixture.result.is_some()));
+        assert_eq!(player.career_stats.international_caps, 2);
+        assert_eq!(player.career_stats.first_call_up, Some(friendly_date));
+
+        // Qualifying for the 2028 finals has been drawn, with every nation playing in the autumn windows
+        assert_eq!(tournaments.len(), 1);
+        assert_eq!(tournaments[0].year, 2028);
+        let mut mut_players = vec![&mut player];
+        let report = engine.run_international_break(&mut national_teams, &mut tournaments, &mut mut_players, qualifier_date);
+        assert_eq!(report.fixtures.len(), national_teams.len());
+        assert!(report.fixtures.iter().all(|fixture| fixture.match_type == InternationalMatchType::Qualifier));
+
+        // The first call-up is only celebrated once
+        assert!(!report.call_ups[0].first_call_up);
+        assert_eq!(player.career_stats.international_caps, 4);
+        assert_eq!(player.career_stats.first_call_up, Some(friendly_date));
+    }
+
+    #[test]
+    fn test_break_dates_within_season() {
+        let engine = InternationalEngine::new();
+        let dates = engine.international_break_dates(
+            NaiveDate::from_ymd_opt(2026, 8, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2027, 5, 31).unwrap(),
+        );
+
+        assert_eq!(dates, vec![
+            NaiveDate::from_ymd_opt(2026, 9, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 10, 6).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 11, 10).unwrap(),
+            NaiveDate::from_ymd_opt(2027, 3, 20).unwrap(),
+        ]);
+    }
+
+    fn create_player(nationality: &str, international_reputation: f32) -> Player {
+        let mut player = create_test_player();
+        player.id = Uuid::new_v4();
+        player.nationality = nationality.to_string();
+        player.age = 24;
+        player.international_reputation = international_reputation;
+        player.local_reputation = international_reputation;
+        player
+    }
+}
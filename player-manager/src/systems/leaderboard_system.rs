@@ -0,0 +1,482 @@
+// src/systems/leaderboard_system.rs
+use chrono::Datelike;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::entities::{
+    Competition, EventType, Match, MatchStatus, MonthlyStats, PlayerCompetitionStats, Position
+};
+use crate::systems::reputation_system::SeasonPerformance;
+
+/// The LeaderboardEngine aggregates player statistics across a competition season
+/// It ranks players for the scoring, assist, rating, clean sheet and discipline charts
+pub struct LeaderboardEngine;
+
+impl Default for LeaderboardEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LeaderboardEngine {
+    /// Creates a new LeaderboardEngine instance
+    pub fn new() -> Self {
+        LeaderboardEngine
+    }
+
+    /// Adds every player who featured in a finished match to the competition's totals
+    pub fn record_match(&self, competition: &mut Competition, game_match: &Match) {
+        if game_match.status != MatchStatus::Finished {
+            return;
+        }
+        let Some((home_goals, away_goals)) = game_match.fulltime_score
+            .or_else(|| game_match.result.as_ref().map(|result| (result.home_score, result.away_score)))
+        else {
+            return;
+        };
+
+        for (player_id, rating) in &game_match.player_ratings {
+            let Some(team_id) = self.team_for_player(game_match, *player_id) else {
+                continue;
+            };
+
+            let count = |event_type: EventType| {
+                game_match.events.iter()
+                    .filter(|event| event.player_involved == *player_id && event.event_type == event_type)
+                    .count() as u32
+            };
+            let goals = count(EventType::Goal);
+            let assists = count(EventType::Assist);
+            let goals_conceded = if team_id == game_match.home_team { away_goals } else { home_goals };
+            let kept_clean_sheet = goals_conceded == 0 && self.is_goalkeeper(game_match, *player_id);
+
+            let stats = match competition.player_stats.iter().position(|stats| stats.player_id == *player_id) {
+                Some(index) => &mut competition.player_stats[index],
+                None => {
+                    competition.player_stats.push(PlayerCompetitionStats {
+                        player_id: *player_id,
+                        ..PlayerCompetitionStats::default()
+                    });
+                    competition.player_stats.last_mut().unwrap()
+                }
+            };
+
+            // Players moving mid-season are credited to their latest club
+            stats.team_id = team_id;
+            stats.appearances += 1;
+            stats.goals += goals;
+            stats.assists += assists;
+            stats.yellow_cards += count(EventType::YellowCard);
+            stats.red_cards += count(EventType::RedCard);
+            stats.rating_total += rating;
+            if kept_clean_sheet {
+                stats.clean_sheets += 1;
+            }
+
+            let (year, month) = (game_match.date.year(), game_match.date.month());
+            let monthly = match stats.monthly.iter().position(|m| m.year == year && m.month == month) {
+                Some(index) => &mut stats.monthly[index],
+                None => {
+                    stats.monthly.push(MonthlyStats { year, month, ..MonthlyStats::default() });
+                    stats.monthly.last_mut().unwrap()
+                }
+            };
+            monthly.appearances += 1;
+            monthly.goals += goals;
+            monthly.assists += assists;
+            monthly.rating_total += rating;
+        }
+    }
+
+    /// Players with the most goals
+    pub fn top_scorers(&self, competition: &Competition, limit: usize) -> Vec<LeaderboardEntry> {
+        self.rank(competition, limit, |stats| Some(stats.goals as f32))
+    }
+
+    /// Players with the most assists
+    pub fn top_assists(&self, competition: &Competition, limit: usize) -> Vec<LeaderboardEntry> {
+        self.rank(competition, limit, |stats| Some(stats.assists as f32))
+    }
+
+    /// Players with the best average rating who have made enough appearances
+    pub fn top_rated(&self, competition: &Competition, min_appearances: u32, limit: usize) -> Vec<LeaderboardEntry> {
+        self.rank(competition, limit, |stats| {
+            (stats.appearances >= min_appearances).then(|| stats.average_rating())
+        })
+    }
+
+    /// Goalkeepers with the most clean sheets
+    pub fn clean_sheets(&self, competition: &Competition, limit: usize) -> Vec<LeaderboardEntry> {
+        self.rank(competition, limit, |stats| Some(stats.clean_sheets as f32))
+    }
+
+    /// Players with the worst disciplinary record (1 point per yellow, 3 per red)
+    pub fn most_cards(&self, competition: &Competition, limit: usize) -> Vec<LeaderboardEntry> {
+        self.rank(competition, limit, |stats| Some((stats.yellow_cards + stats.red_cards * 3) as f32))
+    }
+
+    /// The best-rated player in a calendar month, with goal involvements breaking ties
+    pub fn player_of_the_month(
+        &self,
+        competition: &Competition,
+        year: i32,
+        month: u32,
+        min_appearances: u32,
+    ) -> Option<LeaderboardEntry> {
+        competition.player_stats.iter()
+            .filter_map(|stats| {
+                stats.monthly.iter()
+                    .find(|m| m.year == year && m.month == month && m.appearances >= min_appearances)
+                    .map(|monthly| (stats, monthly))
+            })
+            .max_by(|(_, a), (_, b)| {
+                a.average_rating().total_cmp(&b.average_rating())
+                    .then_with(|| (a.goals + a.assists).cmp(&(b.goals + b.assists)))
+            })
+            .map(|(stats, monthly)| LeaderboardEntry {
+                player_id: stats.player_id,
+                team_id: stats.team_id,
+                appearances: monthly.appearances,
+                value: monthly.average_rating(),
+            })
+    }
+
+    /// A player's season in this competition, ready for award contender scoring
+    pub fn season_performance(&self, competition: &Competition, player_id: Uuid) -> Option<SeasonPerformance> {
+        competition.player_stats.iter()
+            .find(|stats| stats.player_id == player_id)
+            .map(|stats| SeasonPerformance {
+                average_match_rating: stats.average_rating(),
+                goals: stats.goals,
+                assists: stats.assists,
+                appearances: stats.appearances,
+                clean_sheets: stats.clean_sheets,
+            })
+    }
+
+    /// Sorts players by a stat, highest first, with fewer appearances ranking higher on ties
+    /// Players without the stat, or on zero, are left off the board
+    fn rank<F>(&self, competition: &Competition, limit: usize, value: F) -> Vec<LeaderboardEntry>
+    where
+        F: Fn(&PlayerCompetitionStats) -> Option<f32>,
+    {
+        let mut entries: Vec<LeaderboardEntry> = competition.player_stats.iter()
+            .filter_map(|stats| {
+                value(stats).map(|value| LeaderboardEntry {
+                    player_id: stats.player_id,
+                    team_id: stats.team_id,
+                    appearances: stats.appearances,
+                    value,
+                })
+            })
+            .filter(|entry| entry.value > 0.0)
+            .collect();
+
+        entries.sort_by(|a, b| {
+            b.value.total_cmp(&a.value).then_with(|| a.appearances.cmp(&b.appearances))
+        });
+        entries.truncate(limit);
+        entries
+    }
+
+    /// Finds which side a player turned out for
+    fn team_for_player(&self, game_match: &Match, player_id: Uuid) -> Option<Uuid> {
+        game_match.lineup.players.iter()
+            .find(|p| p.player_id == player_id)
+            .map(|p| p.team_id)
+            .or_else(|| {
+                game_match.events.iter()
+                    .find(|event| event.player_involved == player_id)
+                    .map(|event| event.team_id)
+            })
+    }
+
+    /// Checks whether a player was in goal
+    fn is_goalkeeper(&self, game_match: &Match, player_id: Uuid) -> bool {
+        game_match.lineup.formation.goalkeeper == player_id
+            || game_match.lineup.players.iter()
+                .any(|p| p.player_id == player_id && matches!(p.position, Position::GK))
+    }
+}
+
+/// A single row in a leaderboard
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaderboardEntry {
+    pub player_id: Uuid,
+    pub team_id: Uuid,
+    pub appearances: u32,
+    pub value: f32, // Goals, assists, average rating, clean sheets or card points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::{
+        CompetitionType, CurrentSeason, Formation, MatchEvent,
+        MatchHalf, MatchLineup, PitchZone, PlayerInMatch, PlayerMatchStats,
+        TacticalStyle, Tactics, TiebreakRule
+    };
+    use crate::systems::reputation_system::TeamSuccess;
+    use crate::systems::ReputationEngine;
+    use crate::test_support::{create_test_player, date};
+    use chrono::NaiveDate;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_record_match_aggregates_player_stats() {
+        let engine = LeaderboardEngine::new();
+        let (home, away) = (Uuid::new_v4(), Uuid::new_v4());
+        let (striker, keeper, defender) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let mut competition = create_test_competition(vec![home, away]);
+
+        let lineup = vec![
+            (striker, home, Position::CF),
+            (keeper, home, Position::GK),
+            (defender, away, Position::CB),
+        ];
+        let ratings = [(striker, 8.0), (keeper, 7.0), (defender, 6.0)];
+        let events = vec![
+            create_event(striker, home, EventType::Goal),
+            create_event(striker, home, EventType::Goal),
+            create_event(defender, away, EventType::YellowCard),
+        ];
+        let game_match = create_test_match(home, away, (2, 0), date(2026, 9, 5), &lineup, &ratings, events);
+
+        engine.record_match(&mut competition, &game_match);
+        engine.record_match(&mut competition, &game_match);
+
+        let striker_stats = competition.player_stats.iter().find(|s| s.player_id == striker).unwrap();
+        assert_eq!(striker_stats.appearances, 2);
+        assert_eq!(striker_stats.goals, 4);
+        assert_eq!(striker_stats.team_id, home);
+        assert_eq!(striker_stats.average_rating(), 8.0);
+        assert_eq!(striker_stats.monthly.len(), 1);
+
+        let keeper_stats = competition.player_stats.iter().find(|s| s.player_id == keeper).unwrap();
+        assert_eq!(keeper_stats.clean_sheets, 2);
+
+        // Outfield players never get clean sheets, and the away side conceded anyway
+        let defender_stats = competition.player_stats.iter().find(|s| s.player_id == defender).unwrap();
+        assert_eq!(defender_stats.clean_sheets, 0);
+        assert_eq!(defender_stats.yellow_cards, 2);
+    }
+
+    #[test]
+    fn test_unfinished_matches_are_ignored() {
+        let engine = LeaderboardEngine::new();
+        let (home, away, striker) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let mut competition = create_test_competition(vec![home, away]);
+
+        let mut game_match = create_test_match(
+            home, away, (1, 0), date(2026, 9, 5),
+            &[(striker, home, Position::CF)], &[(striker, 7.5)], vec![create_event(striker, home, EventType::Goal)],
+        );
+        game_match.status = MatchStatus::Scheduled;
+
+        engine.record_match(&mut competition, &game_match);
+        assert!(competition.player_stats.is_empty());
+    }
+
+    #[test]
+    fn test_leaderboard_ordering_and_filters() {
+        let engine = LeaderboardEngine::new();
+        let team = Uuid::new_v4();
+        let mut competition = create_test_competition(vec![team]);
+
+        let prolific = create_stats(team, 10, 12, 2, 7.2);
+        let efficient = create_stats(team, 6, 12, 5, 7.6);
+        let one_off = create_stats(team, 1, 1, 0, 9.5);
+        let mut booked = create_stats(team, 10, 0, 0, 6.5);
+        booked.yellow_cards = 2;
+        booked.red_cards = 1;
+        competition.player_stats = vec![prolific.clone(), efficient.clone(), one_off.clone(), booked.clone()];
+
+        // Level on goals, fewer appearances ranks higher
+        let scorers = engine.top_scorers(&competition, 10);
+        assert_eq!(scorers.len(), 3);
+        assert_eq!(scorers[0].player_id, efficient.player_id);
+        assert_eq!(scorers[1].player_id, prolific.player_id);
+
+        let assists = engine.top_assists(&competition, 1);
+        assert_eq!(assists.len(), 1);
+        assert_eq!(assists[0].player_id, efficient.player_id);
+
+        // The one-off 9.5 doesn't meet the appearance threshold
+        let rated = engine.top_rated(&competition, 5, 10);
+        assert_eq!(rated[0].player_id, efficient.player_id);
+        assert!(rated.iter().all(|entry| entry.player_id != one_off.player_id));
+
+        let cards = engine.most_cards(&competition, 10);
+        assert_eq!(cards.len(), 1);
+        assert_eq!(cards[0].value, 5.0);
+
+        assert!(engine.clean_sheets(&competition, 10).is_empty());
+    }
+
+    #[test]
+    fn test_player_of_the_month() {
+        let engine = LeaderboardEngine::new();
+        let (home, away) = (Uuid::new_v4(), Uuid::new_v4());
+        let (star, steady) = (Uuid::new_v4(), Uuid::new_v4());
+        let mut competition = create_test_competition(vec![home, away]);
+        let lineup = vec![(star, home, Position::AM), (steady, away, Position::CM)];
+
+        // The star is brilliant in September but poor in October
+        for (day, star_rating) in [(date(2026, 9, 5), 8.5), (date(2026, 9, 12), 8.3), (date(2026, 10, 3), 5.5), (date(2026, 10, 10), 5.8)] {
+            let game_match = create_test_match(
+                home, away, (1, 1), day, &lineup, &[(star, star_rating), (steady, 7.0)], vec![],
+            );
+            engine.record_match(&mut competition, &game_match);
+        }
+
+        let september = engine.player_of_the_month(&competition, 2026, 9, 2).unwrap();
+        assert_eq!(september.player_id, star);
+        let october = engine.player_of_the_month(&competition, 2026, 10, 2).unwrap();
+        assert_eq!(october.player_id, steady);
+        assert!(engine.player_of_the_month(&competition, 2026, 11, 2).is_none());
+    }
+
+    #[test]
+    fn test_season_performance_feeds_award_scoring() {
+        let engine = LeaderboardEngine::new();
+        let team = Uuid::new_v4();
+        let mut competition = create_test_competition(vec![team]);
+        let star = create_stats(team, 30, 25, 10, 7.8);
+        let squad_player = create_stats(team, 30, 2, 1, 6.6);
+        competition.player_stats = vec![star.clone(), squad_player.clone()];
+
+        let star_season = engine.season_performance(&competition, star.player_id).unwrap();
+        assert_eq!(star_season.goals, 25);
+        assert_eq!(star_season.appearances, 30);
+        assert!((star_season.average_match_rating - 7.8).abs() < 0.001);
+        assert!(engine.season_performance(&competition, Uuid::new_v4()).is_none());
+
+        let reputation = ReputationEngine::new();
+        let player = create_test_player();
+        let star_score = reputation.calculate_award_contender_score(&player, star_season, TeamSuccess::MidTable);
+        let squad_score = reputation.calculate_award_contender_score(
+            &player,
+            engine.season_performance(&competition, squad_player.player_id).unwrap(),
+            TeamSuccess::MidTable,
+        );
+        assert!(star_score > squad_score);
+    }
+
+    fn create_stats(team_id: Uuid, appearances: u32, goals: u32, assists: u32, average_rating: f32) -> PlayerCompetitionStats {
+        PlayerCompetitionStats {
+            player_id: Uuid::new_v4(),
+            team_id,
+            appearances,
+            goals,
+            assists,
+            rating_total: average_rating * appearances as f32,
+            ..PlayerCompetitionStats::default()
+        }
+    }
+
+    fn create_test_competition(teams: Vec<Uuid>) -> Competition {
+        Competition {
+            id: Uuid::new_v4(),
+            name: "Test League".to_string(),
+            country: "Test Country".to_string(),
+            level: 1,
+            teams,
+            fixtures: vec![],
+            standings: vec![],
+            competition_type: CompetitionType::League,
+            season_start: date(2026, 8, 1),
+            season_end: date(2027, 5, 31),
+            current_season: CurrentSeason {
+                year: "2026-27".to_string(),
+                is_active: true,
+                current_matchday: 1,
+                start_date: date(2026, 8, 1),
+                end_date: date(2027, 5, 31),
+            },
+            tiebreak_rules: TiebreakRule::goal_difference_first(),
+            player_stats: vec![],
+        }
+    }
+
+    fn create_test_match(
+        home_team: Uuid,
+        away_team: Uuid,
+        score: (u8, u8),
+        match_date: NaiveDate,
+        lineup: &[(Uuid, Uuid, Position)],
+        ratings: &[(Uuid, f32)],
+        events: Vec<MatchEvent>,
+    ) -> Match {
+        Match {
+            id: Uuid::new_v4(),
+            competition_id: Uuid::new_v4(),
+            home_team,
+            away_team,
+            date: match_date,
+            venue: home_team,
+            status: MatchStatus::Finished,
+            result: None,
+            events,
+            half_results: None,
+            player_ratings: ratings.iter().copied().collect::<HashMap<_, _>>(),
+            fulltime_score: Some(score),
+            competition_type: CompetitionType::League,
+            lineup: MatchLineup {
+                formation: Formation {
+                    goalkeeper: Uuid::new_v4(),
+                    defenders: vec![],
+                    midfielders: vec![],
+                    forwards: vec![],
+                },
+                players: lineup.iter()
+                    .map(|(player_id, team_id, position)| PlayerInMatch {
+                        player_id: *player_id,
+                        team_id: *team_id,
+                        position: *position,
+                        shirt_number: 1,
+                        rating: None,
+                        events: vec![],
+                        minutes_played: 90,
+                        substitution_minute: None,
+                        was_substituted_on: false,
+                        was_substituted_off: false,
+                        stats: PlayerMatchStats::default(),
+                    })
+                    .collect(),
+                tactics: Tactics {
+                    style: TacticalStyle::Balanced,
+                    mentality: 0.0,
+                    tempo: 0.5,
+                    width: 0.5,
+                    pressing_intensity: 0.5,
+                },
+                home_starting_xi: vec![],
+                away_starting_xi: vec![],
+            },
+        }
+    }
+
+    fn create_event(player_id: Uuid, team_id: Uuid, event_type: EventType) -> MatchEvent {
+        MatchEvent {
+            event_type,
+            minute: 10,
+            team_id,
+            player_id,
+            description: String::new(),
+            rating_impact: None,
+            id: Uuid::new_v4(),
+            match_id: Uuid::new_v4(),
+            half: MatchHalf::First,
+            player_involved: player_id,
+            secondary_player: None,
+            pitch_zone: PitchZone::Box,
+            total_impact_score: 0.0,
+            base_impact: 0.0,
+            success: true,
+            time_multiplier: 1.0,
+            position_multiplier: 1.0,
+            difficulty_multiplier: 1.0,
+            clutch_multiplier: 1.0,
+        }
+    }
+}
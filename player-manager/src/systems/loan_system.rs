@@ -0,0 +1,495 @@
+// src/systems/loan_system.rs
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::entities::{Competition, Loan, Player, SeasonStats, SquadRole, Team};
+use crate::core::game_state::{FeeStructure, TransferRecord};
+use crate::systems::social_system::PersonalityFactors;
+use crate::systems::{FeeEngine, SocialEngine};
+
+/// Oldest a player can be and still go out on a development loan
+const MAX_LOAN_AGE: u8 = 23;
+/// How far below the parent club's reputation a club must be to count as lower tier
+const LOWER_TIER_REPUTATION_GAP: f32 = 5.0;
+/// Shortfall in promised minutes the player shrugs off
+const MINUTES_TOLERANCE: f32 = 0.25;
+/// Relationship damage when a loan delivers none of the promised minutes
+const BROKEN_PROMISE_PENALTY: f32 = 30.0;
+/// Relationship boost when a loan delivers what was promised
+const KEPT_PROMISE_BONUS: f32 = 5.0;
+/// Average rating that persuades a loan club to take up its purchase option
+const PURCHASE_RATING: f32 = 7.0;
+/// Loan club matches the parent club waits for before judging a loan
+const RECALL_REVIEW_MATCHES: u32 = 8;
+
+/// The LoanEngine sends young players out on loan to get first-team football
+/// It matches prospects with lower-tier clubs, tracks the minutes they get and brings them back
+pub struct LoanEngine;
+
+impl Default for LoanEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LoanEngine {
+    /// Creates a new LoanEngine instance
+    pub fn new() -> Self {
+        LoanEngine
+    }
+
+    /// Whether the parent club would let a player go out on loan
+    pub fn is_loan_candidate(&self, player: &Player) -> bool {
+        player.age <= MAX_LOAN_AGE
+            && player.contract.loan.is_none()
+            && matches!(
+                player.contract.squad_role,
+                SquadRole::Prospect | SquadRole::Backup | SquadRole::Rotation
+            )
+    }
+
+    /// Collects loan offers from lower-tier clubs that would give the player football
+    pub fn generate_loan_offers(&self, player: &Player, parent: &Team, all_teams: &[Team], today: NaiveDate) -> Vec<LoanOffer> {
+        if !self.is_loan_candidate(player) {
+            return Vec::new();
+        }
+
+        let ability = player.ability_score();
+        all_teams
+            .iter()
+            .filter(|team| team.id != parent.id && team.reputation <= parent.reputation - LOWER_TIER_REPUTATION_GAP)
+            .filter_map(|team| {
+                // Clubs promise more football the more the player would stand out
+                let (promised_role, promised_minutes_share) = if ability >= team.reputation {
+                    (SquadRole::FirstTeam, 0.7)
+                } else if ability >= team.reputation - 15.0 {
+                    (SquadRole::Rotation, 0.4)
+                } else {
+                    return None;
+                };
+
+                let purchase_option = (team.financial_power >= 60.0)
+                    .then(|| ability * 10_000.0 * (1.0 + player.hidden.potential_ceiling as f32 / 100.0));
+
+                Some(LoanOffer {
+                    id: Uuid::new_v4(),
+                    loan_club_id: team.id,
+                    loan_manager_id: team.manager_id(),
+                    promised_role,
+                    promised_minutes_share,
+                    wage_split: (team.financial_power / 100.0).clamp(0.25, 1.0),
+                    purchase_option,
+                    end_date: self.season_end(today),
+                })
+            })
+            .collect()
+    }
+
+    /// Sends the player out on loan, starting a new club spell for their stats
+    pub fn accept_loan(&self, player: &mut Player, offer: &LoanOffer, today: NaiveDate) {
+        let parent_club_id = player.contract.club_id;
+        self.start_spell(player, offer.loan_club_id);
+
+        player.contract.loan = Some(Loan {
+            parent_club_id,
+            loan_club_id: offer.loan_club_id,
+            loan_manager_id: offer.loan_manager_id,
+            start_date: today,
+            end_date: offer.end_date,
+            promised_role: offer.promised_role,
+            promised_minutes_share: offer.promised_minutes_share,
+            wage_split: offer.wage_split,
+            purchase_option: offer.purchase_option,
+            recall_allowed: true,
+            appearances: 0,
+        });
+        player.contract.club_id = offer.loan_club_id;
+    }
+
+    /// Counts a senior appearance towards the loan, if the player was playing for the loan club
+    pub fn record_appearance(&self, player: &mut Player, club_id: Uuid) {
+        if let Some(loan) = player.contract.loan.as_mut().filter(|loan| loan.loan_club_id == club_id) {
+            loan.appearances += 1;
+        }
+    }
+
+    /// The wage as (paid by the parent club, paid by the loan club)
+    pub fn wage_contributions(&self, player: &Player) -> (f32, f32) {
+        match &player.contract.loan {
+            Some(loan) => {
+                let loan_share = player.contract.wage * loan.wage_split;
+                (player.contract.wage - loan_share, loan_share)
+            }
+            None => (player.contract.wage, 0.0),
+        }
+    }
+
+    /// Share of the loan club's senior matches since the loan began that the player appeared in
+    ///
+    /// Returns (appearances, matches played by the loan club).
+    pub fn minutes_delivered(&self, player: &Player, competitions: &[Competition], today: NaiveDate) -> (u32, u32) {
+        let Some(loan) = &player.contract.loan else {
+            return (0, 0);
+        };
+
+        let matches = competitions
+            .iter()
+            .filter(|competition| !competition.competition_type.is_youth())
+            .flat_map(|competition| competition.fixtures.iter())
+            .filter(|fixture| fixture.home_team == loan.loan_club_id || fixture.away_team == loan.loan_club_id)
+            .filter(|fixture| fixture.result.is_some())
+            .filter(|fixture| fixture.scheduled_date >= loan.start_date && fixture.scheduled_date <= today)
+            .count() as u32;
+
+        (loan.appearances.min(matches), matches)
+    }
+
+    /// Ends the loan when its end date arrives
+    pub fn process_day(&self, player: &mut Player, competitions: &[Competition], today: NaiveDate) -> Option<LoanReview> {
+        let loan = player.contract.loan.as_ref()?;
+        if today < loan.end_date {
+            return None;
+        }
+        Some(self.finish_loan(player, competitions, today, false))
+    }
+
+    /// Whether the parent club wants the player back because the loan isn't giving them football
+    pub fn should_recall(&self, player: &Player, competitions: &[Competition], today: NaiveDate) -> bool {
+        let Some(loan) = &player.contract.loan else {
+            return false;
+        };
+        let (appearances, matches) = self.minutes_delivered(player, competitions, today);
+
+        loan.recall_allowed
+            && matches >= RECALL_REVIEW_MATCHES
+            && (appearances as f32 / matches as f32) < loan.promised_minutes_share / 2.0
+    }
+
+    /// The parent club calls the player back early, if the loan allows it
+    pub fn recall(&self, player: &mut Player, competitions: &[Competition], today: NaiveDate) -> Option<LoanReview> {
+        if !player.contract.loan.as_ref()?.recall_allowed {
+            return None;
+        }
+        Some(self.finish_loan(player, competitions, today, true))
+    }
+
+    /// Closes the loan: reviews the minutes, settles the relationship and sends the player back
+    ///
+    /// A loan club impressed enough to take up its purchase option makes an offer, which the
+    /// player can accept with `accept_purchase`.
+    fn finish_loan(&self, player: &mut Player, competitions: &[Competition], today: NaiveDate, recalled: bool) -> LoanReview {
+        let (appearances, matches) = self.minutes_delivered(player, competitions, today);
+        let loan = player.contract.loan.take().expect("finish_loan is only called during a loan");
+        let minutes_share = if matches == 0 { 0.0 } else { appearances as f32 / matches as f32 };
+
+        // A loan that doesn't deliver the promised football sours things with the loan manager
+        let shortfall = ((loan.promised_minutes_share - minutes_share) / loan.promised_minutes_share).max(0.0);
+        let change = if matches == 0 {
+            0.0
+        } else if shortfall > MINUTES_TOLERANCE {
+            -BROKEN_PROMISE_PENALTY * shortfall
+        } else {
+            KEPT_PROMISE_BONUS
+        };
+        let factors = PersonalityFactors::new(player.hidden.loyalty, player.hidden.ego, player.mental.teamwork, 50, 50);
+        let relationship = SocialEngine::new().update_relationship(&mut player.relationships, loan.loan_manager_id, change, &factors);
+
+        // A strong loan at a club with a purchase option earns an offer to stay
+        let purchase_offered = !recalled
+            && loan.purchase_option.is_some()
+            && appearances > 0
+            && player.career_stats.current_season.average_rating >= PURCHASE_RATING;
+
+        self.start_spell(player, loan.parent_club_id);
+        player.contract.club_id = loan.parent_club_id;
+        let outcome = if purchase_offered {
+            LoanOutcome::PurchaseOffered
+        } else if recalled {
+            LoanOutcome::Recalled
+        } else {
+            LoanOutcome::Completed
+        };
+
+        LoanReview {
+            loan_club_id: loan.loan_club_id,
+            loan_manager_id: loan.loan_manager_id,
+            parent_club_id: loan.parent_club_id,
+            appearances,
+            matches,
+            minutes_share,
+            promised_minutes_share: loan.promised_minutes_share,
+            relationship_change: change,
+            relationship,
+            promised_role: loan.promised_role,
+            purchase_fee: if purchase_offered { loan.purchase_option } else { None },
+            outcome,
+        }
+    }
+
+    /// Whether a player would take up a loan club's offer to make the move permanent
+    ///
+    /// Players move when the loan club's role promises more football than they get at home.
+    pub fn wants_purchase(&self, player: &Player, review: &LoanReview) -> bool {
+        review.outcome == LoanOutcome::PurchaseOffered
+            && review.promised_role.ambition_factor() < player.contract.squad_role.ambition_factor()
+    }
+
+    /// Completes a loan club's purchase of the player at the option fee
+    ///
+    /// The fee is recorded as an up-front transfer and paid from the loan club's balance to the
+    /// parent club's. Returns false if the review carried no offer.
+    pub fn accept_purchase(
+        &self,
+        player: &mut Player,
+        review: &LoanReview,
+        records: &mut Vec<TransferRecord>,
+        clubs: &mut [Team],
+        today: NaiveDate,
+    ) -> bool {
+        let Some(fee) = review.purchase_fee.filter(|_| review.outcome == LoanOutcome::PurchaseOffered) else {
+            return false;
+        };
+
+        self.start_spell(player, review.loan_club_id);
+        player.contract.club_id = review.loan_club_id;
+        player.contract.squad_role = review.promised_role;
+
+        let fees = FeeEngine::new();
+        let payments = fees.record_transfer(
+            records,
+            player.id,
+            review.parent_club_id,
+            review.loan_club_id,
+            FeeStructure::upfront(fee),
+            today,
+        );
+        fees.apply_payments(clubs, &payments);
+        true
+    }
+
+    /// Closes the current club spell and starts a fresh one at the new club
+    fn start_spell(&self, player: &mut Player, new_club_id: Uuid) {
+        let career = &mut player.career_stats;
+        let season_year = career.current_season.season_year.clone();
+        let mut spell = std::mem::take(&mut career.current_season);
+        spell.club_id.get_or_insert(player.contract.club_id);
+        if spell.appearances > 0 {
+            career.club_spells.push(spell);
+        }
+        career.current_season = SeasonStats {
+            season_year,
+            club_id: Some(new_club_id),
+            ..SeasonStats::default()
+        };
+    }
+
+    /// Loans run to the end of the season, which finishes on 30 June
+    fn season_end(&self, today: NaiveDate) -> NaiveDate {
+        let year = if today.month() >= 7 { today.year() + 1 } else { today.year() };
+        NaiveDate::from_ymd_opt(year, 6, 30).unwrap()
+    }
+}
+
+/// A lower-tier club's offer to take a player on loan
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoanOffer {
+    pub id: Uuid,
+    pub loan_club_id: Uuid,
+    pub loan_manager_id: Uuid,
+    pub promised_role: SquadRole,
+    pub promised_minutes_share: f32, // 0-1, share of matches the club promises
+    pub wage_split: f32,             // 0-1, share of the wage the loan club pays
+    pub purchase_option: Option<f32>,
+    pub end_date: NaiveDate,
+}
+
+/// How a loan ended
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum LoanOutcome {
+    Completed,
+    Recalled,
+    PurchaseOffered, // Back at the parent club, with an offer from the loan club to stay
+}
+
+/// Summary of a finished loan
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoanReview {
+    pub loan_club_id: Uuid,
+    pub loan_manager_id: Uuid,
+    pub parent_club_id: Uuid,
+    pub appearances: u32,
+    pub matches: u32,
+    pub minutes_share: f32,
+    pub promised_minutes_share: f32,
+    pub relationship_change: f32, // Before personality modifiers
+    pub relationship: f32,        // Relationship with the loan club's manager afterwards
+    pub promised_role: SquadRole, // Role the loan club offers if it buys the player
+    pub purchase_fee: Option<f32>, // Set when the loan club takes up its purchase option
+    pub outcome: LoanOutcome,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::game_state::SeasonArchive;
+    use crate::entities::{CompetitionType, MatchResult, MatchStatus};
+    use crate::systems::SeasonEngine;
+    use crate::test_support::{create_competition, create_fixture, create_test_player, create_test_team, date};
+
+    #[test]
+    fn test_loan_offers_come_from_lower_tier_clubs() {
+        let engine = LoanEngine::new();
+        let mut player = create_test_player();
+        player.contract.squad_role = SquadRole::Prospect;
+        let mut parent = create_test_team();
+        parent.reputation = 85.0;
+        let mut lower = create_test_team();
+        lower.reputation = 70.0;
+        lower.staff = vec![Uuid::new_v4()];
+        let mut poor = create_test_team();
+        poor.reputation = 70.0;
+        poor.financial_power = 30.0;
+        let mut rival = create_test_team();
+        rival.reputation = 84.0;
+        let teams = vec![parent.clone(), lower.clone(), poor.clone(), rival];
+
+        let offers = engine.generate_loan_offers(&player, &parent, &teams, date(2026, 8, 1));
+
+        assert_eq!(offers.len(), 2);
+        let offer = offers.iter().find(|o| o.loan_club_id == lower.id).unwrap();
+        assert!(matches!(offer.promised_role, SquadRole::FirstTeam));
+        assert_eq!(offer.loan_manager_id, lower.staff[0]);
+        assert!(offer.purchase_option.is_some());
+        assert_eq!(offer.end_date, date(2027, 6, 30));
+        let offer = offers.iter().find(|o| o.loan_club_id == poor.id).unwrap();
+        assert!(offer.purchase_option.is_none());
+        assert_eq!(offer.wage_split, 0.3);
+
+        // Established first-teamers aren't loaned out
+        player.contract.squad_role = SquadRole::FirstTeam;
+        assert!(engine.generate_loan_offers(&player, &parent, &teams, date(2026, 8, 1)).is_empty());
+    }
+
+    #[test]
+    fn test_recall_after_broken_promise() {
+        let engine = LoanEngine::new();
+        let mut player = create_test_player();
+        player.contract.squad_role = SquadRole::Prospect;
+        let parent_id = player.contract.club_id;
+        player.career_stats.current_season = SeasonStats {
+            season_year: "2026-27".to_string(),
+            appearances: 3,
+            ..SeasonStats::default()
+        };
+        let offer = create_offer(None);
+
+        engine.accept_loan(&mut player, &offer, date(2026, 8, 1));
+        assert_eq!(player.contract.club_id, offer.loan_club_id);
+        assert_eq!(player.contract.loan.as_ref().unwrap().parent_club_id, parent_id);
+        assert_eq!(player.career_stats.club_spells.len(), 1);
+        assert_eq!(player.career_stats.club_spells[0].club_id, Some(parent_id));
+        assert_eq!(player.career_stats.current_season.club_id, Some(offer.loan_club_id));
+        let (parent_share, loan_share) = engine.wage_contributions(&player);
+        assert_eq!(loan_share, player.contract.wage * 0.5);
+        assert_eq!(parent_share + loan_share, player.contract.wage);
+
+        // Two appearances out of ten loan club matches, against a promise of 70%
+        player.career_stats.current_season.appearances = 2;
+        engine.record_appearance(&mut player, offer.loan_club_id);
+        engine.record_appearance(&mut player, offer.loan_club_id);
+        // Games for anyone else don't count towards the loan
+        engine.record_appearance(&mut player, parent_id);
+        let competitions = vec![create_loan_club_league(offer.loan_club_id, 10)];
+        assert!(!engine.should_recall(&player, &competitions, date(2026, 9, 1)));  // Too early to judge
+        assert!(engine.should_recall(&player, &competitions, date(2026, 12, 1)));
+        let review = engine.recall(&mut player, &competitions, date(2026, 12, 1)).unwrap();
+
+        assert_eq!(review.outcome, LoanOutcome::Recalled);
+        assert_eq!((review.appearances, review.matches), (2, 10));
+        assert!(review.relationship_change < 0.0);
+        assert!(player.relationships[&offer.loan_manager_id] < 50.0);
+        assert!(!player.relationships.contains_key(&offer.loan_club_id));
+        assert_eq!(player.contract.club_id, parent_id);
+        assert!(player.contract.loan.is_none());
+
+        // Each club spell is kept apart when the season is archived
+        let archive = SeasonArchive { season: "2026-27".to_string(), competitions: vec![], awards: vec![] };
+        SeasonEngine::new().archive_player_season(&mut player, &archive, parent_id, None);
+        let clubs: Vec<Option<Uuid>> = player.career_stats.season_stats.iter().map(|s| s.club_id).collect();
+        assert_eq!(clubs, vec![Some(parent_id), Some(offer.loan_club_id), Some(parent_id)]);
+    }
+
+    #[test]
+    fn test_successful_loan_made_permanent() {
+        let engine = LoanEngine::new();
+        let mut player = create_test_player();
+        player.contract.squad_role = SquadRole::Backup;
+        let parent_id = player.contract.club_id;
+        let offer = create_offer(Some(500_000.0));
+        engine.accept_loan(&mut player, &offer, date(2026, 8, 1));
+        for _ in 0..8 {
+            engine.record_appearance(&mut player, offer.loan_club_id);
+        }
+        player.career_stats.current_season.average_rating = 7.4;
+        let competitions = vec![create_loan_club_league(offer.loan_club_id, 10)];
+
+        assert!(engine.process_day(&mut player, &competitions, date(2027, 6, 29)).is_none());
+        let review = engine.process_day(&mut player, &competitions, date(2027, 6, 30)).unwrap();
+
+        // The loan club makes an offer, but the player is back home until they accept it
+        assert_eq!(review.outcome, LoanOutcome::PurchaseOffered);
+        assert_eq!(review.purchase_fee, Some(500_000.0));
+        assert!(player.relationships[&offer.loan_manager_id] > 50.0);
+        assert_eq!(player.contract.club_id, parent_id);
+        assert!(engine.wants_purchase(&player, &review));
+
+        let mut parent = create_test_team();
+        parent.id = parent_id;
+        let mut loan_club = create_test_team();
+        loan_club.id = offer.loan_club_id;
+        let mut clubs = vec![parent, loan_club];
+        let mut records = Vec::new();
+        assert!(engine.accept_purchase(&mut player, &review, &mut records, &mut clubs, date(2027, 6, 30)));
+
+        assert_eq!(player.contract.club_id, offer.loan_club_id);
+        assert!(matches!(player.contract.squad_role, SquadRole::FirstTeam));
+        assert_eq!(clubs[0].finances.balance, 1_000_000.0 + 500_000.0);
+        assert_eq!(clubs[1].finances.balance, 1_000_000.0 - 500_000.0);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].from_club_id, parent_id);
+
+        // A recalled loan brings no offer to accept
+        let mut review = review;
+        review.outcome = LoanOutcome::Recalled;
+        assert!(!engine.wants_purchase(&player, &review));
+        assert!(!engine.accept_purchase(&mut player, &review, &mut records, &mut clubs, date(2027, 7, 1)));
+    }
+
+    // Helper functions for tests
+    fn create_offer(purchase_option: Option<f32>) -> LoanOffer {
+        LoanOffer {
+            id: Uuid::new_v4(),
+            loan_club_id: Uuid::new_v4(),
+            loan_manager_id: Uuid::new_v4(),
+            promised_role: SquadRole::FirstTeam,
+            promised_minutes_share: 0.7,
+            wage_split: 0.5,
+            purchase_option,
+            end_date: date(2027, 6, 30),
+        }
+    }
+
+    fn create_loan_club_league(club: Uuid, played: usize) -> Competition {
+        let fixtures = (0..played)
+            .map(|week| {
+                let mut fixture = create_fixture(club, Uuid::new_v4(), date(2026, 8, 8) + chrono::Duration::weeks(week as i64));
+                fixture.status = MatchStatus::Finished;
+                fixture.result = Some(MatchResult { home_score: 1, away_score: 0, winner: Some(club) });
+                fixture
+            })
+            .collect();
+        create_competition(CompetitionType::League, fixtures)
+    }
+
+}
@@ -3,10 +3,24 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use uuid::Uuid;
 
+use chrono::NaiveDate;
+
+use crate::core::game_state::GameState;
 use crate::entities::{
     Match, MatchEvent, Player, EventType, Position, PitchZone, 
-    MatchHalf, PlayerMatchStats, MatchLineup
+    MatchHalf, PlayerMatchStats, MatchLineup, CompetitionType, Fixture, Formation, MatchResult,
+    MatchStatus, PlayerInMatch, Tactics, TacticalStyle, Team
 };
+use crate::systems::{CompetitionEngine, LoanEngine};
+
+/// Chances each side creates in a simulated fixture
+const FIXTURE_CHANCES: usize = 6;
+/// Strength boost for playing at home
+const HOME_ADVANTAGE: f32 = 1.1;
+/// Likelihood that a goal has an assist credited
+const ASSIST_CHANCE: f64 = 0.7;
+/// Rating a starter on an average day starts from
+const BASE_FIXTURE_RATING: f32 = 6.3;
 
 /// The MatchEngine simulates football matches and produces player ratings
 /// It generates match events based on player attributes, form, morale, and other factors
@@ -14,6 +28,12 @@ pub struct MatchEngine {
     rng: rand::rngs::ThreadRng,
 }
 
+impl Default for MatchEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl MatchEngine {
     /// Creates a new MatchEngine instance
     pub fn new() -> Self {
@@ -59,6 +79,300 @@ impl MatchEngine {
         game_match
     }
 
+    /// Plays every senior fixture that is due by the date and hasn't been played yet
+    ///
+    /// Fixtures run across the whole world. Each result goes through the competition engine, which
+    /// updates the standings and the leaderboards, and each starter's season and career stats are
+    /// updated. Returns the matches played.
+    pub fn play_match_day(&mut self, game_state: &mut GameState, date: NaiveDate) -> Vec<Match> {
+        let competition_engine = CompetitionEngine::new();
+        let GameState { leagues, competitions, clubs, player, players, .. } = game_state;
+        let everyone: Vec<&Player> = std::iter::once(&*player).chain(players.iter()).collect();
+        let mut played: Vec<Match> = Vec::new();
+
+        // Leagues listed in both collections are played once and copied across afterwards
+        let league_ids: Vec<Uuid> = leagues.iter().map(|league| league.id).collect();
+        let senior = leagues.iter_mut()
+            .chain(competitions.iter_mut().filter(|competition| !league_ids.contains(&competition.id)))
+            .filter(|competition| !competition.competition_type.is_youth());
+        for competition in senior {
+            let due: Vec<Fixture> = competition.fixtures.iter()
+                .filter(|fixture| fixture.result.is_none() && fixture.scheduled_date <= date)
+                .filter(|fixture| fixture.status != MatchStatus::Cancelled)
+                .cloned()
+                .collect();
+
+            for fixture in due {
+                let home = clubs.iter().find(|club| club.id == fixture.home_team);
+                let away = clubs.iter().find(|club| club.id == fixture.away_team);
+                let (Some(home), Some(away)) = (home, away) else {
+                    continue;
+                };
+                let game_match = self.play_fixture(&fixture, competition.competition_type.clone(), home, away, &everyone);
+                competition_engine.process_match_result(competition, &game_match, home, away);
+                played.push(game_match);
+            }
+        }
+        for competition in competitions.iter_mut() {
+            if let Some(league) = leagues.iter().find(|league| league.id == competition.id) {
+                *competition = league.clone();
+            }
+        }
+
+        // Every starter has the match added to their season and career stats, and to any loan they are on
+        let loans = LoanEngine::new();
+        for game_match in &played {
+            let Some((home_goals, away_goals)) = game_match.fulltime_score else {
+                continue;
+            };
+            for starter in &game_match.lineup.players {
+                let rating = game_match.player_ratings.get(&starter.player_id).copied().unwrap_or(BASE_FIXTURE_RATING);
+                let conceded = if starter.team_id == game_match.home_team { away_goals } else { home_goals };
+                let mut everyone = std::iter::once(&mut *player).chain(players.iter_mut());
+                if let Some(player) = everyone.find(|player| player.id == starter.player_id) {
+                    player.career_stats.record_match(&starter.stats, rating, conceded as u32);
+                    loans.record_appearance(player, starter.team_id);
+                }
+            }
+        }
+        played
+    }
+
+    /// Simulates a fixture between the best available elevens of two clubs
+    ///
+    /// The stronger side converts more of its chances. Goals and assists go to the starters, forwards
+    /// most often, and every starter is rated on their ability, the result and their contributions.
+    pub fn play_fixture(
+        &mut self,
+        fixture: &Fixture,
+        competition_type: CompetitionType,
+        home: &Team,
+        away: &Team,
+        players: &[&Player],
+    ) -> Match {
+        let home_xi = self.pick_eleven(home.id, players);
+        let away_xi = self.pick_eleven(away.id, players);
+        let home_strength = self.eleven_strength(&home_xi);
+        let away_strength = self.eleven_strength(&away_xi);
+        let home_goals = self.simulate_goals(home_strength * HOME_ADVANTAGE, away_strength);
+        let away_goals = self.simulate_goals(away_strength, home_strength);
+
+        let mut events = self.goal_events(fixture.id, home.id, &home_xi, home_goals);
+        events.extend(self.goal_events(fixture.id, away.id, &away_xi, away_goals));
+
+        let mut lineup_players = Vec::new();
+        let mut player_ratings = HashMap::new();
+        let sides = [
+            (home.id, &home_xi, home_strength, home_goals, away_goals),
+            (away.id, &away_xi, away_strength, away_goals, home_goals),
+        ];
+        for (team_id, eleven, strength, scored, conceded) in sides {
+            for (index, player) in eleven.iter().enumerate() {
+                let count = |event_type: EventType| {
+                    events.iter()
+                        .filter(|event| event.player_involved == player.id && event.event_type == event_type)
+                        .count() as u8
+                };
+                let stats = PlayerMatchStats {
+                    goals: count(EventType::Goal),
+                    assists: count(EventType::Assist),
+                    minutes_played: 90,
+                    ..PlayerMatchStats::default()
+                };
+                let rating = self.fixture_rating(player, &stats, strength, scored, conceded);
+                player_ratings.insert(player.id, rating);
+                lineup_players.push(PlayerInMatch {
+                    player_id: player.id,
+                    team_id,
+                    position: player.primary_position,
+                    shirt_number: index as u8 + 1,
+                    rating: Some(rating),
+                    events: vec![],
+                    minutes_played: 90,
+                    substitution_minute: None,
+                    was_substituted_on: false,
+                    was_substituted_off: false,
+                    stats,
+                });
+            }
+        }
+
+        let ids = |eleven: &[&Player], keep: fn(Position) -> bool| -> Vec<Uuid> {
+            eleven.iter().filter(|player| keep(player.primary_position)).map(|player| player.id).collect()
+        };
+        Match {
+            id: fixture.id,
+            competition_id: fixture.competition_id,
+            home_team: home.id,
+            away_team: away.id,
+            date: fixture.scheduled_date,
+            venue: fixture.venue,
+            status: MatchStatus::Finished,
+            result: Some(MatchResult {
+                home_score: home_goals,
+                away_score: away_goals,
+                winner: match home_goals.cmp(&away_goals) {
+                    std::cmp::Ordering::Greater => Some(home.id),
+                    std::cmp::Ordering::Less => Some(away.id),
+                    std::cmp::Ordering::Equal => None,
+                },
+            }),
+            events,
+            half_results: None,
+            player_ratings,
+            fulltime_score: Some((home_goals, away_goals)),
+            competition_type,
+            lineup: MatchLineup {
+                formation: Formation {
+                    goalkeeper: ids(&home_xi, |position| matches!(position, Position::GK)).first().copied().unwrap_or_default(),
+                    defenders: ids(&home_xi, |position| matches!(position, Position::CB | Position::RB | Position::LB | Position::FB)),
+                    midfielders: ids(&home_xi, |position| {
+                        matches!(position, Position::DM | Position::CM | Position::AM | Position::RM | Position::LM)
+                    }),
+                    forwards: ids(&home_xi, |position| matches!(position, Position::RW | Position::LW | Position::CF | Position::SS)),
+                },
+                players: lineup_players,
+                tactics: Tactics {
+                    style: TacticalStyle::Balanced,
+                    mentality: 0.0,
+                    tempo: 0.5,
+                    width: 0.5,
+                    pressing_intensity: 0.5,
+                },
+                home_starting_xi: home_xi.iter().map(|player| player.id).collect(),
+                away_starting_xi: away_xi.iter().map(|player| player.id).collect(),
+            },
+        }
+    }
+
+    /// The club's best fit goalkeeper and ten best fit outfield players, picked on ability and form
+    fn pick_eleven<'a>(&self, club_id: Uuid, players: &[&'a Player]) -> Vec<&'a Player> {
+        let selection = |player: &Player| player.ability_score() + (player.form - 6.5) * 3.0;
+        let mut available: Vec<&Player> = players.iter()
+            .copied()
+            .filter(|player| player.contract.club_id == club_id && player.injury_status.is_none())
+            .collect();
+        available.sort_by(|a, b| selection(b).total_cmp(&selection(a)));
+
+        let goalkeeper = available.iter().position(|player| matches!(player.primary_position, Position::GK));
+        let mut eleven: Vec<&Player> = goalkeeper.map(|index| available.remove(index)).into_iter().collect();
+        let outfield = available.iter().filter(|player| !matches!(player.primary_position, Position::GK));
+        eleven.extend(outfield.take(10).copied());
+        // A squad short of outfield players puts spare goalkeepers on the pitch
+        let spare = available.iter().filter(|player| matches!(player.primary_position, Position::GK));
+        eleven.extend(spare.take(11 - eleven.len()).copied());
+        eleven
+    }
+
+    /// Average ability of the eleven, with empty places filled by a weak stand-in
+    fn eleven_strength(&self, eleven: &[&Player]) -> f32 {
+        let total: f32 = eleven.iter().map(|player| player.ability_score()).sum();
+        let stand_ins = (11 - eleven.len().min(11)) as f32 * 30.0;
+        (total + stand_ins) / 11.0
+    }
+
+    /// Goals from a side's chances, converted more often against weaker opposition
+    fn simulate_goals(&mut self, strength: f32, opponent: f32) -> u8 {
+        let conversion = (0.2 * strength / opponent.max(1.0)).clamp(0.05, 0.45);
+        (0..FIXTURE_CHANCES).filter(|_| self.rng.gen::<f32>() < conversion).count() as u8
+    }
+
+    /// A goal event for each goal a side scored, most with an assist from a teammate
+    fn goal_events(&mut self, match_id: Uuid, team_id: Uuid, eleven: &[&Player], goals: u8) -> Vec<MatchEvent> {
+        let scoring_weight = |position: Position| match position {
+            Position::CF | Position::SS => 5.0,
+            Position::RW | Position::LW | Position::AM => 3.0,
+            Position::CM | Position::RM | Position::LM | Position::DM => 1.5,
+            Position::GK => 0.0,
+            _ => 0.5,
+        };
+        let creating_weight = |position: Position| match position {
+            Position::RW | Position::LW | Position::AM | Position::RM | Position::LM => 3.0,
+            Position::CM => 2.0,
+            Position::CF | Position::SS => 1.5,
+            Position::GK => 0.1,
+            _ => 1.0,
+        };
+
+        let mut events = Vec::new();
+        if eleven.is_empty() {
+            return events;
+        }
+        for _ in 0..goals {
+            let minute = self.rng.gen_range(1..=90);
+            let weighted: Vec<(Uuid, f32)> = eleven.iter().map(|player| (player.id, scoring_weight(player.primary_position))).collect();
+            let scorer = self.weighted_random_selection(&weighted);
+            let assister = if self.rng.gen_bool(ASSIST_CHANCE) {
+                let weighted: Vec<(Uuid, f32)> = eleven.iter()
+                    .filter(|player| player.id != scorer)
+                    .map(|player| (player.id, creating_weight(player.primary_position)))
+                    .collect();
+                (!weighted.is_empty()).then(|| self.weighted_random_selection(&weighted))
+            } else {
+                None
+            };
+
+            events.push(self.fixture_event(match_id, team_id, scorer, assister, EventType::Goal, minute));
+            if let Some(assister) = assister {
+                events.push(self.fixture_event(match_id, team_id, assister, Some(scorer), EventType::Assist, minute));
+            }
+        }
+        events
+    }
+
+    /// A goal or assist in a simulated fixture
+    fn fixture_event(
+        &self,
+        match_id: Uuid,
+        team_id: Uuid,
+        player_id: Uuid,
+        secondary_player: Option<Uuid>,
+        event_type: EventType,
+        minute: u8,
+    ) -> MatchEvent {
+        let base_impact = self.get_base_impact(&event_type);
+        MatchEvent {
+            description: format!("{:?} at minute {}", event_type, minute),
+            event_type,
+            minute,
+            team_id,
+            player_id,
+            rating_impact: None,
+            id: Uuid::new_v4(),
+            match_id,
+            half: if minute <= 45 { MatchHalf::First } else { MatchHalf::Second },
+            player_involved: player_id,
+            secondary_player,
+            pitch_zone: PitchZone::Box,
+            total_impact_score: base_impact,
+            base_impact,
+            success: true,
+            time_multiplier: 1.0,
+            position_multiplier: 1.0,
+            difficulty_multiplier: 1.0,
+            clutch_multiplier: 1.0,
+        }
+    }
+
+    /// A starter's rating: ability against the team's level, the result, and their goals and assists
+    fn fixture_rating(&mut self, player: &Player, stats: &PlayerMatchStats, team_strength: f32, scored: u8, conceded: u8) -> f32 {
+        let result = match scored.cmp(&conceded) {
+            std::cmp::Ordering::Greater => 0.4,
+            std::cmp::Ordering::Equal => 0.0,
+            std::cmp::Ordering::Less => -0.4,
+        };
+        let defending = matches!(player.primary_position, Position::GK | Position::CB | Position::RB | Position::LB | Position::FB);
+        let clean_sheet = if defending && conceded == 0 { 0.5 } else { 0.0 };
+        let rating = BASE_FIXTURE_RATING
+            + (player.ability_score() - team_strength) / 25.0
+            + stats.goals as f32 * 0.9
+            + stats.assists as f32 * 0.5
+            + result
+            + clean_sheet
+            + self.rng.gen_range(-0.6..=0.6);
+        rating.clamp(4.5, 9.9)
+    }
+
     /// Generates events for a specific minute of the match
     fn generate_events_for_minute(&mut self, match_state: &mut MatchState, minute: u8) -> Vec<MatchEvent> {
         let mut events = Vec::new();
@@ -220,10 +534,7 @@ impl MatchEngine {
     /// Generates an action for a specific player
     fn generate_action_for_player(&mut self, match_state: &MatchState, player_id: Uuid, minute: u8) -> Option<MatchEvent> {
         // Find the player
-        let player = match self.find_player_by_id(match_state, player_id) {
-            Some(p) => p,
-            None => return None,
-        };
+        let player = self.find_player_by_id(match_state, player_id)?;
         
         // Determine action type based on position and game state
         let action_type = self.decide_action_type(player, match_state, minute);
@@ -246,7 +557,7 @@ impl MatchEngine {
             clutch_multiplier: self.calculate_clutch_multiplier(minute, match_state.score_difference, match_state.match_importance),
             total_impact_score: 0.0, // This will be calculated after all multipliers
             team_id: match_state.home_team_id, // Assuming home team for this example
-            player_id: player_id,
+            player_id,
             description: format!("Action by player {} at minute {}", player_id, minute),
             rating_impact: Some(0.0), // Placeholder value
         };
@@ -267,10 +578,7 @@ impl MatchEngine {
     /// Generates a defensive action
     fn generate_defensive_action(&mut self, match_state: &MatchState, player_id: Uuid, minute: u8) -> Option<MatchEvent> {
         // Find the player
-        let player = match self.find_player_by_id(match_state, player_id) {
-            Some(p) => p,
-            None => return None,
-        };
+        let player = self.find_player_by_id(match_state, player_id)?;
         
         // Determine defensive action type
         let action_type = self.decide_defensive_action_type(player);
@@ -293,7 +601,7 @@ impl MatchEngine {
             clutch_multiplier: self.calculate_clutch_multiplier(minute, match_state.score_difference, match_state.match_importance),
             total_impact_score: 0.0,
             team_id: match_state.home_team_id, // Assuming home team for this example
-            player_id: player_id,
+            player_id,
             description: format!("Defensive action by player {} at minute {}", player_id, minute),
             rating_impact: Some(0.0), // Placeholder value
         };
@@ -316,14 +624,14 @@ impl MatchEngine {
         // Check home team
         for player_ref in &match_state.home_players {
             if player_ref.player.id == player_id {
-                return Some(&player_ref.player);
+                return Some(player_ref.player);
             }
         }
         
         // Check away team
         for player_ref in &match_state.away_players {
             if player_ref.player.id == player_id {
-                return Some(&player_ref.player);
+                return Some(player_ref.player);
             }
         }
         
@@ -715,11 +1023,13 @@ impl MatchEngine {
         
         // Important matches
         match match_importance {
+            MatchImportance::Youth => 0.7,
             MatchImportance::Friendly => 0.8,
             MatchImportance::League => 1.0,
             MatchImportance::Cup => 1.2,
             MatchImportance::Final => 1.5,
             MatchImportance::Continental => 1.4,
+            MatchImportance::International => 1.6,
         };
         
         // Combination of late + close + important = very high multiplier
@@ -965,11 +1275,13 @@ impl<'a> MatchState<'a> {
 /// Match importance levels
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum MatchImportance {
+    Youth, // Youth and reserve leagues
     Friendly,
     League,
     Cup,
     Final,
     Continental,
+    International, // Finals of a World Cup or continental championship
 }
 
 impl Match {
@@ -1016,6 +1328,9 @@ impl Default for PlayerMatchStats {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::entities::{Loan, SquadRole};
+    use crate::systems::SquadEngine;
+    use crate::test_support::{create_fixture, create_league, create_test_team, create_world, date};
 
     #[test]
     fn test_match_engine_creation() {
@@ -1055,4 +1370,102 @@ mod tests {
         let score = engine.calculate_involvement_score(&empty_events);
         assert_eq!(score, 0.0);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_match_day_plays_due_fixtures_into_the_league() {
+        let home = create_test_team();
+        let away = create_test_team();
+        let mut league = create_league(vec![(home.id, 1), (away.id, 2)], vec![]);
+        league.fixtures = vec![
+            create_fixture(home.id, away.id, date(2026, 8, 8)),
+            create_fixture(away.id, home.id, date(2026, 8, 15)),
+        ];
+        let mut game_state = create_world(vec![home.clone(), away.clone()], vec![]);
+        SquadEngine::new().populate_world(&mut game_state, date(2026, 8, 1));
+        game_state.competitions = vec![league];
+
+        let played = MatchEngine::new().play_match_day(&mut game_state, date(2026, 8, 8));
+
+        assert_eq!(played.len(), 1);
+        let game_match = &played[0];
+        assert_eq!(game_match.lineup.home_starting_xi.len(), 11);
+        assert_eq!(game_match.lineup.away_starting_xi.len(), 11);
+        assert_eq!(game_match.player_ratings.len(), 22);
+        let league = &game_state.competitions[0];
+        assert!(league.fixtures[0].result.is_some());
+        assert!(league.fixtures[1].result.is_none());
+        assert!(league.standings.iter().all(|standing| standing.played == 1));
+        // The leaderboards credit everyone who started
+        assert_eq!(league.player_stats.len(), 22);
+        let (home_goals, away_goals) = game_match.fulltime_score.unwrap();
+        let goals: u32 = league.player_stats.iter().map(|stats| stats.goals).sum();
+        assert_eq!(goals, (home_goals + away_goals) as u32);
+
+        // Starters have the match in their season and career stats
+        let starter = game_state.players.iter().find(|player| player.id == game_match.lineup.home_starting_xi[0]).unwrap();
+        assert_eq!(starter.career_stats.current_season.appearances, 1);
+        assert_eq!(starter.career_stats.total_appearances, 1);
+        assert_eq!(starter.career_stats.average_rating, game_match.player_ratings[&starter.id]);
+        let goals: u32 = game_state.players.iter().map(|player| player.career_stats.current_season.goals).sum();
+        assert_eq!(goals, (home_goals + away_goals) as u32);
+
+        // A fixture that has been played isn't played again
+        assert!(MatchEngine::new().play_match_day(&mut game_state, date(2026, 8, 8)).is_empty());
+    }
+
+    #[test]
+    fn test_loan_appearances_come_from_matches_played() {
+        let home = create_test_team();
+        let away = create_test_team();
+        let mut league = create_league(vec![(home.id, 1), (away.id, 2)], vec![]);
+        league.fixtures = vec![create_fixture(home.id, away.id, date(2026, 8, 8))];
+        let mut game_state = create_world(vec![home.clone(), away.clone()], vec![]);
+        SquadEngine::new().populate_world(&mut game_state, date(2026, 8, 1));
+        game_state.competitions = vec![league];
+        // The home side's best outfield player is sure to start
+        let loanee = game_state.players.iter_mut()
+            .filter(|player| player.contract.club_id == home.id && !matches!(player.primary_position, Position::GK))
+            .max_by(|a, b| a.ability_score().total_cmp(&b.ability_score()))
+            .unwrap();
+        loanee.contract.loan = Some(Loan {
+            parent_club_id: Uuid::new_v4(),
+            loan_club_id: home.id,
+            loan_manager_id: home.manager_id(),
+            start_date: date(2026, 8, 1),
+            end_date: date(2027, 6, 30),
+            promised_role: SquadRole::FirstTeam,
+            promised_minutes_share: 0.7,
+            wage_split: 0.5,
+            purchase_option: None,
+            recall_allowed: true,
+            appearances: 0,
+        });
+        let loanee_id = loanee.id;
+
+        MatchEngine::new().play_match_day(&mut game_state, date(2026, 8, 8));
+
+        let loanee = game_state.players.iter().find(|player| player.id == loanee_id).unwrap();
+        assert_eq!(loanee.contract.loan.as_ref().unwrap().appearances, 1);
+        let (appearances, matches) = LoanEngine::new().minutes_delivered(loanee, &game_state.competitions, date(2026, 8, 8));
+        assert_eq!((appearances, matches), (1, 1));
+    }
+
+    #[test]
+    fn test_league_listed_twice_is_played_once() {
+        let home = create_test_team();
+        let away = create_test_team();
+        let mut league = create_league(vec![(home.id, 1), (away.id, 2)], vec![]);
+        league.fixtures = vec![create_fixture(home.id, away.id, date(2026, 8, 8))];
+        let mut game_state = create_world(vec![home, away], vec![]);
+        SquadEngine::new().populate_world(&mut game_state, date(2026, 8, 1));
+        game_state.leagues = vec![league.clone()];
+        game_state.competitions = vec![league];
+
+        let played = MatchEngine::new().play_match_day(&mut game_state, date(2026, 8, 8));
+
+        assert_eq!(played.len(), 1);
+        assert!(game_state.competitions[0].fixtures[0].result.is_some());
+        assert!(game_state.competitions[0].standings.iter().all(|standing| standing.played == 1));
+        assert!(MatchEngine::new().play_match_day(&mut game_state, date(2026, 8, 9)).is_empty());
+    }
+}
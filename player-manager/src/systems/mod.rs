@@ -6,6 +6,28 @@ pub mod social_system;
 pub mod training_system;
 pub mod competition_system;
 pub mod transfer_system;
+pub mod season_system;
+pub mod leaderboard_system;
+pub mod calendar_system;
+pub mod awards_system;
+pub mod international_system;
+pub mod tournament_system;
+pub mod youth_system;
+pub mod agent_system;
+pub mod rumour_system;
+pub mod loan_system;
+pub mod free_agency_system;
+pub mod window_system;
+pub mod world_transfer_system;
+pub mod squad_system;
+pub mod bonus_system;
+pub mod finance_system;
+pub mod valuation_system;
+pub mod club_finance_system;
+pub mod fee_system;
+pub mod renewal_system;
+pub mod opportunity_system;
+pub mod priority_system;
 
 pub use development_system::PlayerDevelopmentEngine;
 pub use morale_system::MoraleEngine;
@@ -14,4 +36,26 @@ pub use reputation_system::ReputationEngine;
 pub use social_system::SocialEngine;
 pub use training_system::TrainingSystem;
 pub use competition_system::CompetitionEngine;
-pub use transfer_system::TransferEngine;
\ No newline at end of file
+pub use transfer_system::TransferEngine;
+pub use season_system::SeasonEngine;
+pub use leaderboard_system::LeaderboardEngine;
+pub use calendar_system::CalendarEngine;
+pub use awards_system::AwardsEngine;
+pub use international_system::InternationalEngine;
+pub use tournament_system::TournamentEngine;
+pub use youth_system::YouthEngine;
+pub use agent_system::AgentEngine;
+pub use rumour_system::RumourEngine;
+pub use loan_system::LoanEngine;
+pub use free_agency_system::FreeAgencyEngine;
+pub use window_system::TransferWindowEngine;
+pub use world_transfer_system::WorldTransferEngine;
+pub use squad_system::SquadEngine;
+pub use bonus_system::BonusEngine;
+pub use finance_system::FinanceEngine;
+pub use valuation_system::ValuationEngine;
+pub use club_finance_system::ClubFinanceEngine;
+pub use fee_system::FeeEngine;
+pub use renewal_system::RenewalEngine;
+pub use opportunity_system::OpportunityEngine;
+pub use priority_system::PriorityEngine;
@@ -7,6 +7,12 @@ use crate::entities::{Player, SquadRole};
 /// It influences performance consistency, development rate, and social interaction outcomes
 pub struct MoraleEngine;
 
+impl Default for MoraleEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl MoraleEngine {
     /// Creates a new MoraleEngine instance
     pub fn new() -> Self {
@@ -14,6 +20,7 @@ impl MoraleEngine {
     }
 
     /// Updates player morale based on various influencing factors
+    #[allow(clippy::too_many_arguments)]
     pub fn update_player_morale(
         &self,
         player: &mut Player,
@@ -59,7 +66,7 @@ impl MoraleEngine {
         }
 
         // Apply morale change with boundaries
-        player.morale = (player.morale + morale_change).max(0.0).min(100.0);
+        player.morale = (player.morale + morale_change).clamp(0.0, 100.0);
     }
 
     /// Calculates morale change based on match performance
@@ -142,7 +149,7 @@ impl MoraleEngine {
         match attention {
             MediaAttention::Positive => 1.5 * ego_factor,
             MediaAttention::Neutral => 0.0,
-            MediaAttention::Negative => -1.0 * ego_factor,
+            MediaAttention::Negative => -ego_factor,
             MediaAttention::IntensePositive => 3.0 * ego_factor,
             MediaAttention::IntenseNegative => -4.0 * ego_factor,
         }
@@ -263,12 +270,12 @@ impl PersonalityProfile {
         pressure_sensitivity: f32,
     ) -> Self {
         PersonalityProfile {
-            team_connection: team_connection.max(0.0).min(1.0),
-            recognition_need: recognition_need.max(0.0).min(1.0),
-            resilience: resilience.max(0.0).min(1.0),
-            club_loyalty: club_loyalty.max(0.0).min(1.0),
-            ambition_level: ambition_level.max(0.0).min(1.0),
-            pressure_sensitivity: pressure_sensitivity.max(0.0).min(1.0),
+            team_connection: team_connection.clamp(0.0, 1.0),
+            recognition_need: recognition_need.clamp(0.0, 1.0),
+            resilience: resilience.clamp(0.0, 1.0),
+            club_loyalty: club_loyalty.clamp(0.0, 1.0),
+            ambition_level: ambition_level.clamp(0.0, 1.0),
+            pressure_sensitivity: pressure_sensitivity.clamp(0.0, 1.0),
         }
     }
 }
@@ -276,6 +283,12 @@ impl PersonalityProfile {
 /// The PersonalityEngine handles personality-driven behaviors and reactions
 pub struct PersonalityEngine;
 
+impl Default for PersonalityEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl PersonalityEngine {
     pub fn new() -> Self {
         PersonalityEngine
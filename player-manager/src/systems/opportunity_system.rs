@@ -0,0 +1,426 @@
+// src/systems/opportunity_system.rs
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::core::event_engine::{Consequence, ConsequenceType, DecisionOption, DecisionType, UserDecisionRequest};
+use crate::core::game_state::{CareerOpportunity, OpportunityKind, OpportunityRecord};
+use crate::entities::{NationalTeam, Player, Position, SquadRole, Team};
+use crate::systems::social_system::PersonalityFactors;
+use crate::systems::transfer_system::PositionGroup;
+use crate::systems::SocialEngine;
+
+/// Days between career opportunities
+const OPPORTUNITY_INTERVAL_DAYS: i64 = 28;
+/// Days before the same kind of opportunity comes round again at a club
+const REPEAT_INTERVAL_DAYS: i64 = 365;
+/// Relationship with the club a player needs before being offered the armband
+const CAPTAINCY_RELATIONSHIP: f32 = 60.0;
+/// Leadership, from teamwork and composure, a captain needs
+const CAPTAINCY_LEADERSHIP: f32 = 70.0;
+/// Form that earns a player a bigger role
+const PROMOTION_FORM: f32 = 7.0;
+/// International reputation at which the national team takes an interest
+const NATIONAL_TEAM_REPUTATION: f32 = 25.0;
+/// Youngest age at which a player is asked to mentor
+const MENTOR_AGE: u8 = 28;
+/// Oldest teammate who can be mentored
+const MENTEE_AGE: u8 = 20;
+
+/// The OpportunityEngine offers the player career choices that aren't transfers
+/// Captaincy, a bigger role, a new position, the national team and mentoring each come with a trade-off
+pub struct OpportunityEngine;
+
+impl Default for OpportunityEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OpportunityEngine {
+    /// Creates a new OpportunityEngine instance
+    pub fn new() -> Self {
+        OpportunityEngine
+    }
+
+    /// The next career opportunity for the player, at most one every four weeks
+    ///
+    /// Opportunities are tried in order: captaincy, a role promotion, a position change,
+    /// the national team, then mentoring. A kind already offered at the club in the last
+    /// year is skipped, and a captain is never offered the armband again at the same club.
+    pub fn generate_opportunity(
+        &self,
+        player: &Player,
+        club: &Team,
+        teammates: &[Player],
+        national_team: Option<&NationalTeam>,
+        history: &[OpportunityRecord],
+        today: NaiveDate,
+    ) -> Option<CareerOpportunity> {
+        if club.id != player.contract.club_id || player.contract.loan.is_some() {
+            return None;
+        }
+        if history.last().is_some_and(|record| (today - record.opportunity.offered_on).num_days() < OPPORTUNITY_INTERVAL_DAYS) {
+            return None;
+        }
+
+        let recently_offered = |kind: &OpportunityKind| {
+            history.iter().any(|record| {
+                record.opportunity.club_id == club.id
+                    && std::mem::discriminant(&record.opportunity.kind) == std::mem::discriminant(kind)
+                    && (today - record.opportunity.offered_on).num_days() < REPEAT_INTERVAL_DAYS
+            })
+        };
+        let is_captain = history.iter().any(|record| {
+            record.accepted && record.opportunity.club_id == club.id && matches!(record.opportunity.kind, OpportunityKind::Captaincy)
+        });
+
+        let candidates = [
+            (!is_captain).then(|| self.captaincy(player, club)).flatten(),
+            self.role_promotion(player, club, teammates),
+            self.position_change(player),
+            national_team.and_then(|team| self.national_team(player, team)),
+            self.mentorship(player, club, teammates),
+        ];
+        candidates
+            .into_iter()
+            .flatten()
+            .find(|kind| !recently_offered(kind))
+            .map(|kind| CareerOpportunity {
+                id: Uuid::new_v4(),
+                club_id: club.id,
+                offered_on: today,
+                kind,
+            })
+    }
+
+    /// Puts an opportunity to the player as a decision: the first option accepts, the second declines
+    pub fn decision_request(&self, player: &Player, opportunity: &CareerOpportunity) -> UserDecisionRequest {
+        let option = |text: String, accepted: bool| DecisionOption {
+            id: Uuid::new_v4(),
+            text,
+            consequences: self.consequences(&self.effects(player, opportunity, accepted)),
+            requirements: vec![],
+        };
+        let (accept, decline) = match &opportunity.kind {
+            OpportunityKind::Captaincy => (
+                "Take the armband: more standing in the dressing room, more duties off the pitch",
+                "Turn it down and focus on your own game",
+            ),
+            OpportunityKind::RolePromotion { .. } => (
+                "Step up: more minutes, a heavier load and a teammate put out",
+                "Stay where you are and keep fresh",
+            ),
+            OpportunityKind::PositionChange { .. } => (
+                "Retrain in the new position for guaranteed minutes",
+                "Stick to your best position and fight for your place",
+            ),
+            OpportunityKind::NationalTeam { .. } => (
+                "Join the national team camp, at the cost of rest your club wanted you to have",
+                "Stay with your club and rest",
+            ),
+            OpportunityKind::Mentorship { .. } => (
+                "Take the youngster under your wing, giving up some of your own training time",
+                "Keep your focus on your own training",
+            ),
+        };
+
+        UserDecisionRequest {
+            event_id: opportunity.id,
+            decision_type: DecisionType::CareerOpportunity,
+            options: vec![option(accept.to_string(), true), option(decline.to_string(), false)],
+            context: serde_json::to_value(opportunity).unwrap_or_default(),
+        }
+    }
+
+    /// The opportunity a decision request was built from
+    pub fn opportunity_from_request(&self, request: &UserDecisionRequest) -> Option<CareerOpportunity> {
+        serde_json::from_value(request.context.clone()).ok()
+    }
+
+    /// A one-line description of the opportunity, for the decision screen
+    pub fn describe(&self, opportunity: &CareerOpportunity) -> String {
+        match &opportunity.kind {
+            OpportunityKind::Captaincy => "The manager wants you to captain the side.".to_string(),
+            OpportunityKind::RolePromotion { role, .. } => format!("Your form has earned you a {:?} role.", role),
+            OpportunityKind::PositionChange { position, role } => {
+                format!("The manager wants to retrain you as a {:?}, where you would be {:?}.", position, role)
+            }
+            OpportunityKind::NationalTeam { .. } => "The national team coach invites you to a training camp.".to_string(),
+            OpportunityKind::Mentorship { .. } => "The coaches ask you to mentor one of the club's young players.".to_string(),
+        }
+    }
+
+    /// Applies the player's answer and records it
+    pub fn resolve(&self, player: &mut Player, opportunity: &CareerOpportunity, accepted: bool, history: &mut Vec<OpportunityRecord>) {
+        let effects = self.effects(player, opportunity, accepted);
+        player.morale = (player.morale + effects.morale).clamp(0.0, 100.0);
+        player.fatigue = (player.fatigue + effects.fatigue).clamp(0.0, 100.0);
+        player.local_reputation = (player.local_reputation + effects.local_reputation).clamp(0.0, 100.0);
+        player.international_reputation = (player.international_reputation + effects.international_reputation).clamp(0.0, 100.0);
+        if let Some(role) = effects.squad_role {
+            player.contract.squad_role = role;
+        }
+        if let Some(position) = effects.position {
+            let old = player.primary_position;
+            player.secondary_positions.retain(|secondary| PositionGroup::for_position(*secondary) != PositionGroup::for_position(position));
+            player.secondary_positions.push(old);
+            player.primary_position = position;
+        }
+
+        let factors = PersonalityFactors::new(player.hidden.loyalty, player.hidden.ego, player.mental.teamwork, 50, 50);
+        for (other, change) in effects.relationships {
+            SocialEngine::new().update_relationship(&mut player.relationships, other, change, &factors);
+        }
+
+        history.push(OpportunityRecord {
+            opportunity: opportunity.clone(),
+            accepted,
+        });
+    }
+
+    /// Offered to established starters who get on with the club and lead by example
+    fn captaincy(&self, player: &Player, club: &Team) -> Option<OpportunityKind> {
+        let leadership = (player.mental.teamwork as f32 + player.mental.composure as f32) / 2.0;
+        let relationship = player.relationships.get(&club.id).copied().unwrap_or(50.0);
+        let starter = matches!(player.contract.squad_role, SquadRole::KeyPlayer | SquadRole::FirstTeam);
+        (player.age >= 24 && starter && relationship >= CAPTAINCY_RELATIONSHIP && leadership >= CAPTAINCY_LEADERSHIP)
+            .then_some(OpportunityKind::Captaincy)
+    }
+
+    /// A player in form outside the starting line-up is offered the next role up
+    ///
+    /// The weakest teammate in the same position group already holding that role loses out.
+    fn role_promotion(&self, player: &Player, club: &Team, teammates: &[Player]) -> Option<OpportunityKind> {
+        let role = match player.contract.squad_role {
+            SquadRole::Prospect | SquadRole::Backup => SquadRole::Rotation,
+            SquadRole::Rotation => SquadRole::FirstTeam,
+            SquadRole::FirstTeam | SquadRole::KeyPlayer => return None,
+        };
+        if player.form < PROMOTION_FORM {
+            return None;
+        }
+        let group = PositionGroup::for_position(player.primary_position);
+        let displaced = teammates
+            .iter()
+            .filter(|teammate| teammate.id != player.id && teammate.contract.club_id == club.id)
+            .filter(|teammate| PositionGroup::for_position(teammate.primary_position) == group)
+            .filter(|teammate| self.role_rank(teammate.contract.squad_role) == self.role_rank(role))
+            .min_by(|a, b| a.ability_score().total_cmp(&b.ability_score()))
+            .map(|teammate| teammate.id);
+        Some(OpportunityKind::RolePromotion { role, displaced })
+    }
+
+    /// A player short of minutes is offered a neighbouring position where they would start
+    fn position_change(&self, player: &Player) -> Option<OpportunityKind> {
+        if !matches!(player.contract.squad_role, SquadRole::Backup | SquadRole::Rotation) {
+            return None;
+        }
+        let position = match player.primary_position {
+            Position::RM | Position::RW => Position::RB,
+            Position::LM | Position::LW => Position::LB,
+            Position::CB => Position::FB,
+            Position::CM => Position::DM,
+            Position::DM => Position::CB,
+            Position::AM | Position::SS => Position::CF,
+            Position::CF => Position::RW,
+            Position::GK | Position::RB | Position::LB | Position::FB => return None,
+        };
+        Some(OpportunityKind::PositionChange {
+            position,
+            role: SquadRole::FirstTeam,
+        })
+    }
+
+    /// The player's national team invites an uncapped player who is making a name abroad
+    fn national_team(&self, player: &Player, national_team: &NationalTeam) -> Option<OpportunityKind> {
+        (national_team.nationality == player.nationality
+            && player.career_stats.first_call_up.is_none()
+            && player.international_reputation >= NATIONAL_TEAM_REPUTATION)
+            .then_some(OpportunityKind::NationalTeam {
+                national_team_id: national_team.id,
+            })
+    }
+
+    /// A senior player is asked to mentor a young teammate, preferably one in their position group
+    fn mentorship(&self, player: &Player, club: &Team, teammates: &[Player]) -> Option<OpportunityKind> {
+        if player.age < MENTOR_AGE {
+            return None;
+        }
+        let group = PositionGroup::for_position(player.primary_position);
+        teammates
+            .iter()
+            .filter(|teammate| teammate.id != player.id && teammate.contract.club_id == club.id && teammate.age <= MENTEE_AGE)
+            .max_by_key(|teammate| PositionGroup::for_position(teammate.primary_position) == group)
+            .map(|mentee| OpportunityKind::Mentorship { mentee_id: mentee.id })
+    }
+
+    /// What accepting or declining does to the player
+    fn effects(&self, player: &Player, opportunity: &CareerOpportunity, accepted: bool) -> OpportunityEffects {
+        let ego = player.hidden.ego as f32 / 100.0;
+        let club = opportunity.club_id;
+        let mut effects = OpportunityEffects::default();
+        match (&opportunity.kind, accepted) {
+            (OpportunityKind::Captaincy, true) => {
+                effects.morale = 5.0;
+                effects.local_reputation = 5.0;
+                effects.fatigue = 5.0;
+                effects.relationships.push((club, 10.0));
+            }
+            (OpportunityKind::Captaincy, false) => effects.relationships.push((club, -5.0)),
+            (OpportunityKind::RolePromotion { role, displaced }, true) => {
+                effects.morale = 3.0;
+                effects.fatigue = 10.0;
+                effects.squad_role = Some(*role);
+                if let Some(teammate) = displaced {
+                    effects.relationships.push((*teammate, -10.0));
+                }
+            }
+            (OpportunityKind::RolePromotion { .. }, false) => effects.relationships.push((club, -3.0)),
+            (OpportunityKind::PositionChange { position, role }, true) => {
+                // Bigger egos take being moved out of their best position harder
+                effects.morale = -(3.0 + 7.0 * ego);
+                effects.squad_role = Some(*role);
+                effects.position = Some(*position);
+                effects.relationships.push((club, 5.0));
+            }
+            (OpportunityKind::PositionChange { .. }, false) => effects.relationships.push((club, -2.0)),
+            (OpportunityKind::NationalTeam { national_team_id }, true) => {
+                effects.international_reputation = 3.0;
+                effects.fatigue = 10.0;
+                effects.relationships.push((*national_team_id, 15.0));
+                effects.relationships.push((club, -5.0));
+            }
+            (OpportunityKind::NationalTeam { national_team_id }, false) => {
+                effects.relationships.push((*national_team_id, -10.0));
+            }
+            (OpportunityKind::Mentorship { mentee_id }, true) => {
+                effects.local_reputation = 2.0;
+                effects.fatigue = 5.0;
+                effects.relationships.push((*mentee_id, 15.0));
+                effects.relationships.push((club, 5.0));
+            }
+            (OpportunityKind::Mentorship { mentee_id }, false) => effects.relationships.push((*mentee_id, -5.0)),
+        }
+        effects
+    }
+
+    /// The effects as decision consequences, for the options on screen
+    fn consequences(&self, effects: &OpportunityEffects) -> Vec<Consequence> {
+        let consequence = |consequence_type: ConsequenceType, value: f32| Consequence {
+            consequence_type,
+            value,
+            duration: None,
+        };
+        let mut consequences = Vec::new();
+        if effects.morale != 0.0 {
+            consequences.push(consequence(ConsequenceType::MoraleChange, effects.morale));
+        }
+        if effects.local_reputation + effects.international_reputation != 0.0 {
+            consequences.push(consequence(ConsequenceType::ReputationChange, effects.local_reputation + effects.international_reputation));
+        }
+        for (_, change) in &effects.relationships {
+            consequences.push(consequence(ConsequenceType::RelationshipChange, *change));
+        }
+        if let Some(role) = effects.squad_role {
+            consequences.push(consequence(ConsequenceType::PlayingTimeImpact, self.role_rank(role) as f32));
+        }
+        consequences
+    }
+
+    /// Orders squad roles from least to most playing time promised
+    fn role_rank(&self, role: SquadRole) -> u8 {
+        match role {
+            SquadRole::Prospect => 0,
+            SquadRole::Backup => 1,
+            SquadRole::Rotation => 2,
+            SquadRole::FirstTeam => 3,
+            SquadRole::KeyPlayer => 4,
+        }
+    }
+}
+
+/// Everything an answer to an opportunity changes
+#[derive(Debug, Clone, Default)]
+struct OpportunityEffects {
+    morale: f32,
+    fatigue: f32,
+    local_reputation: f32,
+    international_reputation: f32,
+    relationships: Vec<(Uuid, f32)>, // Club, teammate or national team, and the change
+    squad_role: Option<SquadRole>,
+    position: Option<Position>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{create_test_player, create_test_team, date};
+
+    #[test]
+    fn test_fringe_player_is_offered_a_bigger_role_or_a_new_position() {
+        let engine = OpportunityEngine::new();
+        let club = create_test_team();
+        let mut player = create_test_player();
+        player.contract.club_id = club.id;
+        player.contract.squad_role = SquadRole::Rotation;
+        let mut starter = create_test_player();
+        starter.contract.club_id = club.id;
+        starter.mental.vision = 40;
+        let mut history = Vec::new();
+
+        // In form, the player is offered the starter's place
+        let promotion = engine.generate_opportunity(&player, &club, &[starter.clone()], None, &history, date(2026, 1, 5)).unwrap();
+        assert!(matches!(promotion.kind, OpportunityKind::RolePromotion { role: SquadRole::FirstTeam, displaced: Some(id) } if id == starter.id));
+        engine.resolve(&mut player, &promotion, true, &mut history);
+        assert!(matches!(player.contract.squad_role, SquadRole::FirstTeam));
+        assert!(player.relationships[&starter.id] < 50.0);
+
+        // Out of form and back on the fringes, a move to defensive midfield guarantees minutes
+        player.contract.squad_role = SquadRole::Backup;
+        player.form = 6.0;
+        assert!(engine.generate_opportunity(&player, &club, &[], None, &history, date(2026, 1, 19)).is_none());
+        let retrain = engine.generate_opportunity(&player, &club, &[], None, &history, date(2026, 2, 2)).unwrap();
+        assert!(matches!(retrain.kind, OpportunityKind::PositionChange { position: Position::DM, role: SquadRole::FirstTeam }));
+
+        let morale = player.morale;
+        engine.resolve(&mut player, &retrain, true, &mut history);
+        assert!(matches!(player.primary_position, Position::DM));
+        assert!(matches!(player.secondary_positions[..], [Position::CM]));
+        assert!(matches!(player.contract.squad_role, SquadRole::FirstTeam));
+        assert!(player.morale < morale);
+        assert!(player.relationships[&club.id] > 50.0);
+    }
+
+    #[test]
+    fn test_captaincy_and_mentoring_are_put_to_the_player_as_decisions() {
+        let engine = OpportunityEngine::new();
+        let club = create_test_team();
+        let mut player = create_test_player();
+        player.age = 29;
+        player.contract.club_id = club.id;
+        player.relationships.insert(club.id, 70.0);
+        let mut youngster = create_test_player();
+        youngster.contract.club_id = club.id;
+        let teammates = vec![youngster.clone()];
+        let mut history = Vec::new();
+
+        let captaincy = engine.generate_opportunity(&player, &club, &teammates, None, &history, date(2026, 1, 5)).unwrap();
+        assert!(matches!(captaincy.kind, OpportunityKind::Captaincy));
+        let request = engine.decision_request(&player, &captaincy);
+        assert!(matches!(request.decision_type, DecisionType::CareerOpportunity));
+        assert_eq!(request.options.len(), 2);
+        assert!(request.options[0].consequences.iter().any(|c| matches!(c.consequence_type, ConsequenceType::RelationshipChange) && c.value > 0.0));
+        assert!(request.options[1].consequences.iter().any(|c| matches!(c.consequence_type, ConsequenceType::RelationshipChange) && c.value < 0.0));
+        assert_eq!(engine.opportunity_from_request(&request).unwrap().id, captaincy.id);
+
+        // Turning the armband down costs standing with the club and it isn't offered again this year
+        engine.resolve(&mut player, &captaincy, false, &mut history);
+        assert!(player.relationships[&club.id] < 70.0);
+        let mentoring = engine.generate_opportunity(&player, &club, &teammates, None, &history, date(2026, 2, 2)).unwrap();
+        assert!(matches!(mentoring.kind, OpportunityKind::Mentorship { mentee_id } if mentee_id == youngster.id));
+
+        engine.resolve(&mut player, &mentoring, true, &mut history);
+        assert!(player.relationships[&youngster.id] > 50.0);
+        assert!(history[1].accepted);
+    }
+}
@@ -0,0 +1,323 @@
+// src/systems/priority_system.rs
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::core::game_state::{CareerPriorities, CareerPriority};
+use crate::entities::{Player, SquadRole, Team};
+use crate::systems::transfer_system::NegotiationPreferences;
+
+/// Weight given to the player's top-ranked priority at the start of a career
+const TOP_PRIORITY_WEIGHTS: [f32; 3] = [90.0, 75.0, 60.0];
+/// Weight given to priorities the player didn't rank
+const UNRANKED_WEIGHT: f32 = 40.0;
+/// Score out of 100 an offer needs before the player will talk
+const ACCEPTABLE_SCORE: f32 = 55.0;
+/// Weight at which a priority becomes a demand in contract talks
+const DEMAND_WEIGHT: f32 = 60.0;
+/// Score points per point of morale gained or lost on a move
+const SCORE_PER_MORALE: f32 = 5.0;
+
+/// The PriorityEngine works with what the player wants from their career
+/// It scores offers against the player's priorities, shapes their demands and lets the priorities shift with age
+pub struct PriorityEngine;
+
+impl Default for PriorityEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PriorityEngine {
+    /// Creates a new PriorityEngine instance
+    pub fn new() -> Self {
+        PriorityEngine
+    }
+
+    /// The profile a player starts their career with, from the priorities they rank highest
+    ///
+    /// The top three picks are weighted 90, 75 and 60 and everything else 40.
+    /// Repeated picks only count the first time.
+    pub fn starting_priorities(&self, ranking: &[CareerPriority], home_country: &str) -> CareerPriorities {
+        let mut priorities = CareerPriorities::new();
+        for priority in CareerPriority::ALL {
+            priorities.set_weight(priority, UNRANKED_WEIGHT);
+        }
+
+        let mut ranked: Vec<CareerPriority> = Vec::new();
+        for priority in ranking {
+            if !ranked.contains(priority) {
+                ranked.push(*priority);
+            }
+        }
+        for (priority, weight) in ranked.iter().zip(TOP_PRIORITY_WEIGHTS) {
+            priorities.set_weight(*priority, weight);
+        }
+
+        priorities.home_country = home_country.to_string();
+        priorities
+    }
+
+    /// Lets the player's priorities drift on a birthday
+    ///
+    /// Young players want minutes, players in their prime want money and medals,
+    /// and veterans look for a club close to home that will keep them.
+    pub fn age_priorities(&self, priorities: &mut CareerPriorities, age: u8) {
+        let drift: &[(CareerPriority, f32)] = match age {
+            0..=21 => &[(CareerPriority::PlayingTime, 3.0), (CareerPriority::LeaguePrestige, 1.0)],
+            22..=27 => &[(CareerPriority::Money, 2.0), (CareerPriority::Trophies, 2.0), (CareerPriority::LeaguePrestige, 1.0)],
+            28..=31 => &[
+                (CareerPriority::Trophies, 2.0),
+                (CareerPriority::Money, 1.0),
+                (CareerPriority::ProximityToHome, 2.0),
+                (CareerPriority::LeaguePrestige, -1.0),
+            ],
+            _ => &[
+                (CareerPriority::ProximityToHome, 3.0),
+                (CareerPriority::Loyalty, 3.0),
+                (CareerPriority::PlayingTime, 1.0),
+                (CareerPriority::LeaguePrestige, -2.0),
+                (CareerPriority::Money, -1.0),
+            ],
+        };
+        for (priority, change) in drift {
+            priorities.set_weight(*priority, priorities.weight(*priority) + change);
+        }
+    }
+
+    /// Scores an offer out of 100 against the player's priorities
+    ///
+    /// Each priority is satisfied from 0 to 100 by the offer and counts in proportion to
+    /// its weight. An offer from the player's own club is a renewal and fully satisfies loyalty.
+    pub fn score_offer(
+        &self,
+        priorities: &CareerPriorities,
+        player: &Player,
+        club: &Team,
+        wage: f32,
+        squad_role: SquadRole,
+    ) -> OfferScore {
+        let factors: Vec<PriorityFactor> = CareerPriority::ALL
+            .iter()
+            .map(|priority| {
+                let (satisfaction, reason) = self.satisfaction(*priority, priorities, player, club, wage, squad_role);
+                PriorityFactor {
+                    priority: *priority,
+                    weight: priorities.weight(*priority),
+                    satisfaction,
+                    reason,
+                }
+            })
+            .collect();
+
+        let total_weight: f32 = factors.iter().map(|factor| factor.weight).sum();
+        let score = if total_weight > 0.0 {
+            factors.iter().map(|factor| factor.weight * factor.satisfaction).sum::<f32>() / total_weight
+        } else {
+            50.0
+        };
+
+        OfferScore {
+            club_id: club.id,
+            score,
+            factors,
+        }
+    }
+
+    /// Whether an offer scores well enough for the player to start talks
+    pub fn meets_expectations(&self, score: &OfferScore) -> bool {
+        score.score >= ACCEPTABLE_SCORE
+    }
+
+    /// Explains a score, the priorities that count most for or against the offer first
+    pub fn explain(&self, score: &OfferScore) -> Vec<String> {
+        let mut factors: Vec<&PriorityFactor> = score.factors.iter().collect();
+        factors.sort_by(|a, b| self.pull(b).abs().total_cmp(&self.pull(a).abs()));
+        factors
+            .iter()
+            .map(|factor| {
+                let verdict = if self.pull(factor) >= 0.0 { "+" } else { "-" };
+                format!("{} {:?} ({:.0}/100 to you): {}", verdict, factor.priority, factor.weight, factor.reason)
+            })
+            .collect()
+    }
+
+    /// The demands the player takes into contract talks, from the priorities they weight most
+    ///
+    /// Agents negotiate from these, so the profile decides what an agent fights for
+    /// and what a greedy agent has to override.
+    pub fn negotiation_preferences(&self, priorities: &CareerPriorities) -> NegotiationPreferences {
+        NegotiationPreferences {
+            prefer_longer_contract: priorities.loyalty >= DEMAND_WEIGHT,
+            prefer_higher_wage: priorities.money >= DEMAND_WEIGHT,
+            prefer_prestige_club: priorities.league_prestige >= DEMAND_WEIGHT || priorities.trophies >= DEMAND_WEIGHT,
+            prefer_playing_time: priorities.playing_time >= DEMAND_WEIGHT,
+        }
+    }
+
+    /// How the player settles after a move, from how well it fits their priorities
+    ///
+    /// Returns the morale change: a move that scores 50 leaves morale alone, a perfect
+    /// fit lifts it by 10 and a move against everything they want drops it by 10.
+    pub fn react_to_move(&self, player: &mut Player, score: &OfferScore) -> f32 {
+        let change = (score.score - 50.0) / SCORE_PER_MORALE;
+        player.morale = (player.morale + change).clamp(0.0, 100.0);
+        change
+    }
+
+    /// How prestigious a country's league is to play in, 0-100
+    pub fn league_prestige(&self, country: &str) -> f32 {
+        match country {
+            "England" => 95.0,
+            "Spain" => 90.0,
+            "Germany" | "Italy" => 85.0,
+            "France" => 80.0,
+            "Portugal" | "Netherlands" => 70.0,
+            "Scotland" => 55.0,
+            _ => 50.0,
+        }
+    }
+
+    /// How well an offer satisfies one priority, 0-100, and why
+    fn satisfaction(
+        &self,
+        priority: CareerPriority,
+        priorities: &CareerPriorities,
+        player: &Player,
+        club: &Team,
+        wage: f32,
+        squad_role: SquadRole,
+    ) -> (f32, String) {
+        match priority {
+            CareerPriority::Money => {
+                let change = if player.contract.wage > 0.0 { wage / player.contract.wage - 1.0 } else { 1.0 };
+                // Half as much again, or half as much, is as good or as bad as it gets
+                let satisfaction = (50.0 + change * 100.0).clamp(0.0, 100.0);
+                (satisfaction, format!("£{:.0}/week, {:+.0}% on your current wage", wage, change * 100.0))
+            }
+            CareerPriority::PlayingTime => {
+                let satisfaction = match squad_role {
+                    SquadRole::Prospect => 15.0,
+                    SquadRole::Backup => 25.0,
+                    SquadRole::Rotation => 50.0,
+                    SquadRole::FirstTeam => 80.0,
+                    SquadRole::KeyPlayer => 100.0,
+                };
+                (satisfaction, format!("a {:?} role", squad_role))
+            }
+            CareerPriority::Trophies => {
+                (club.reputation.clamp(0.0, 100.0), format!("{} have a reputation of {:.0}", club.name, club.reputation))
+            }
+            CareerPriority::LeaguePrestige => {
+                (self.league_prestige(&club.country), format!("the league in {}", club.country))
+            }
+            CareerPriority::ProximityToHome => {
+                if priorities.home_country.is_empty() {
+                    (50.0, "you have no strong ties to home".to_string())
+                } else if club.country == priorities.home_country {
+                    (100.0, format!("playing at home in {}", club.country))
+                } else {
+                    (10.0, format!("a long way from home in {}", club.country))
+                }
+            }
+            CareerPriority::Loyalty => {
+                if club.id == player.contract.club_id {
+                    (100.0, format!("staying at {}", club.name))
+                } else {
+                    let relationship = player.relationships.get(&player.contract.club_id).copied().unwrap_or(50.0);
+                    (100.0 - relationship, format!("leaving a club you rate {:.0}/100", relationship))
+                }
+            }
+        }
+    }
+
+    /// How far a factor pulls the score above or below neutral
+    fn pull(&self, factor: &PriorityFactor) -> f32 {
+        factor.weight * (factor.satisfaction - 50.0)
+    }
+}
+
+/// An offer judged against the player's career priorities
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OfferScore {
+    pub club_id: Uuid,
+    pub score: f32, // 0-100
+    pub factors: Vec<PriorityFactor>,
+}
+
+/// How one priority judged an offer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriorityFactor {
+    pub priority: CareerPriority,
+    pub weight: f32,       // How much the player cares, 0-100
+    pub satisfaction: f32, // How well the offer delivers, 0-100
+    pub reason: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{create_test_player, create_test_team};
+
+    #[test]
+    fn test_offers_are_scored_and_explained_against_priorities() {
+        let engine = PriorityEngine::new();
+        let mut player = create_test_player();
+        let mut home = create_test_team();
+        home.country = "Scotland".to_string();
+        home.reputation = 50.0;
+        player.contract.club_id = home.id;
+        player.relationships.insert(home.id, 80.0);
+        let mut abroad = create_test_team();
+        abroad.country = "Spain".to_string();
+        abroad.reputation = 90.0;
+
+        let mercenary = engine.starting_priorities(&[CareerPriority::Money, CareerPriority::Trophies], "Scotland");
+        assert_eq!(mercenary.money, 90.0);
+        assert_eq!(mercenary.trophies, 75.0);
+        assert_eq!(mercenary.loyalty, UNRANKED_WEIGHT);
+        let homebody = engine.starting_priorities(&[CareerPriority::Loyalty, CareerPriority::ProximityToHome, CareerPriority::Loyalty], "Scotland");
+        assert_eq!(homebody.proximity_to_home, 75.0);
+
+        // Double the wages abroad against a renewal at home on the same money
+        let big_move = engine.score_offer(&mercenary, &player, &abroad, 100000.0, SquadRole::FirstTeam);
+        let renewal = engine.score_offer(&mercenary, &player, &home, 50000.0, SquadRole::FirstTeam);
+        assert!(big_move.score > renewal.score);
+        assert!(engine.meets_expectations(&big_move));
+        assert!(engine.explain(&big_move)[0].contains("Money"));
+
+        let big_move = engine.score_offer(&homebody, &player, &abroad, 100000.0, SquadRole::FirstTeam);
+        let renewal = engine.score_offer(&homebody, &player, &home, 50000.0, SquadRole::FirstTeam);
+        assert!(renewal.score > big_move.score);
+        assert!(!engine.meets_expectations(&big_move));
+        assert!(engine.explain(&big_move)[0].starts_with("- "));
+
+        // What the player wants most is what their agent fights for
+        let demands = engine.negotiation_preferences(&mercenary);
+        assert!(demands.prefer_higher_wage && demands.prefer_prestige_club);
+        assert!(!demands.prefer_longer_contract && !demands.prefer_playing_time);
+        assert!(engine.negotiation_preferences(&homebody).prefer_longer_contract);
+    }
+
+    #[test]
+    fn test_priorities_shift_with_age_and_shape_morale_after_a_move() {
+        let engine = PriorityEngine::new();
+        let mut priorities = CareerPriorities::new();
+        for age in 18..=35 {
+            engine.age_priorities(&mut priorities, age);
+        }
+        assert!(priorities.proximity_to_home > priorities.league_prestige);
+        assert!(priorities.playing_time > 50.0);
+        assert!(priorities.trophies > 50.0);
+
+        let mut player = create_test_player();
+        let club = create_test_team();
+        let priorities = engine.starting_priorities(&[CareerPriority::PlayingTime], "");
+        let morale = player.morale;
+        let bench = engine.score_offer(&priorities, &player, &club, 50000.0, SquadRole::Backup);
+        assert!(engine.react_to_move(&mut player, &bench) < 0.0);
+        assert!(player.morale < morale);
+
+        let starting = engine.score_offer(&priorities, &player, &club, 60000.0, SquadRole::KeyPlayer);
+        assert!(engine.react_to_move(&mut player, &starting) > 0.0);
+    }
+}
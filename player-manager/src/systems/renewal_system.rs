@@ -0,0 +1,297 @@
+// src/systems/renewal_system.rs
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::core::game_state::{InterestLevel, RenewalTalks, TransferSystemState};
+use crate::entities::{Bonus, ContractTerms, Player, SquadRole, Team};
+use crate::systems::social_system::PersonalityFactors;
+use crate::systems::{ClubFinanceEngine, SocialEngine, TransferEngine, ValuationEngine};
+
+/// Months left on a contract when the club opens renewal talks
+const RENEWAL_MONTHS: u32 = 18;
+/// Days between the club's approaches while the player holds out
+const APPROACH_INTERVAL_DAYS: i64 = 28;
+/// Days between approaches once a rival is preparing an offer
+const RIVAL_APPROACH_INTERVAL_DAYS: i64 = 7;
+/// Highest escalation the club's offer reaches
+const MAX_ESCALATION: u32 = 4;
+/// Times the player can put the club off before the board break off talks
+const MAX_STALLS: u32 = 3;
+/// Relationship lost with the board per stall, growing with each one
+const STALL_RELATIONSHIP_PENALTY: f32 = 4.0;
+/// Relationship gained with the board when the player commits their future
+const RENEWAL_RELATIONSHIP_BOOST: f32 = 5.0;
+
+/// The RenewalEngine runs contract renewal talks opened by the player's club
+/// Valued players are approached with 18 months left, and the offer improves as the player holds out or rivals circle
+pub struct RenewalEngine;
+
+impl Default for RenewalEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RenewalEngine {
+    /// Creates a new RenewalEngine instance
+    pub fn new() -> Self {
+        RenewalEngine
+    }
+
+    /// The club's next approach about a new contract, if it makes one today
+    ///
+    /// Talks open once a valued player has 18 months or less left. While the player holds out
+    /// the club comes back every four weeks, or every week once a rival is preparing an offer.
+    /// Talks on an old contract are dropped when the player signs a new one or moves on.
+    pub fn renewal_approach(
+        &self,
+        player: &Player,
+        club: &Team,
+        state: &mut TransferSystemState,
+        today: NaiveDate,
+    ) -> Option<RenewalApproach> {
+        let contract = &player.contract;
+        if state
+            .renewal_talks
+            .as_ref()
+            .is_some_and(|talks| talks.club_id != contract.club_id || talks.contract_end_date != contract.contract_end_date)
+        {
+            state.renewal_talks = None;
+        }
+        if club.id != contract.club_id
+            || contract.loan.is_some()
+            || !self.is_valued(player)
+            || !TransferEngine::new().is_contract_expiring_soon(contract, RENEWAL_MONTHS, today)
+        {
+            return None;
+        }
+
+        let rival_interest = self.rival_interest(player, state);
+        let interval = if rival_interest.as_ref().is_some_and(|level| self.rival_pressure(level) >= 2) {
+            RIVAL_APPROACH_INTERVAL_DAYS
+        } else {
+            APPROACH_INTERVAL_DAYS
+        };
+        let talks = state.renewal_talks.get_or_insert(RenewalTalks {
+            club_id: club.id,
+            contract_end_date: contract.contract_end_date,
+            opened_on: today,
+            last_approach: today,
+            approaches: 0,
+            stalls: 0,
+            broken_off: false,
+        });
+        if talks.broken_off || (talks.approaches > 0 && (today - talks.last_approach).num_days() < interval) {
+            return None;
+        }
+        talks.approaches += 1;
+        talks.last_approach = today;
+
+        let pressure = rival_interest.as_ref().map_or(0, |level| self.rival_pressure(level));
+        let escalation = (pressure + talks.approaches - 1).min(MAX_ESCALATION);
+        Some(RenewalApproach {
+            club_id: club.id,
+            round: talks.approaches,
+            rival_interest,
+            terms: self.renewal_terms(player, club, escalation, today),
+        })
+    }
+
+    /// The player signs the club's new contract, which pleases the board
+    pub fn accept_renewal(
+        &self,
+        player: &mut Player,
+        club: &Team,
+        state: &mut TransferSystemState,
+        approach: &RenewalApproach,
+        today: NaiveDate,
+    ) {
+        TransferEngine::new().sign_contract(player, club, &approach.terms, today);
+        state.renewal_talks = None;
+        let factors = self.personality(player);
+        SocialEngine::new().update_relationship(&mut player.relationships, club.id, RENEWAL_RELATIONSHIP_BOOST, &factors);
+    }
+
+    /// The player puts the club off, costing goodwill with the board
+    ///
+    /// Each stall costs more than the last. Returns true if the board have had enough and
+    /// broken off talks, leaving the contract to run down.
+    pub fn stall(&self, player: &mut Player, state: &mut TransferSystemState) -> bool {
+        let Some(talks) = state.renewal_talks.as_mut() else {
+            return false;
+        };
+        talks.stalls += 1;
+        talks.broken_off = talks.stalls >= MAX_STALLS;
+
+        let factors = self.personality(player);
+        let penalty = STALL_RELATIONSHIP_PENALTY * talks.stalls as f32;
+        SocialEngine::new().update_relationship(&mut player.relationships, talks.club_id, -penalty, &factors);
+        talks.broken_off
+    }
+
+    /// The new contract on the table, improving with each step of escalation
+    ///
+    /// Each step adds 10% to the wage unless the club is in debt. From the second step the
+    /// club promises a bigger role, and it always asks for a raised release clause to keep
+    /// rivals away.
+    fn renewal_terms(&self, player: &Player, club: &Team, escalation: u32, today: NaiveDate) -> ContractTerms {
+        let renewal = TransferEngine::new().generate_contract_renewal(player, club, &player.contract, today);
+        let raise = if ClubFinanceEngine::new().in_debt(club) {
+            0.0
+        } else {
+            0.1 * escalation as f32
+        };
+        let wage = renewal.offered_wage * (1.0 + raise);
+
+        let squad_role = if escalation >= 2 {
+            self.promise_role(player.contract.squad_role)
+        } else {
+            player.contract.squad_role
+        };
+        let value = ValuationEngine::new().market_value(player, today);
+        let current_clause = player.contract.release_clause.unwrap_or(0.0);
+        let release_clause = (value * (2.0 + 0.5 * escalation as f32)).max(current_clause * 1.25);
+
+        ContractTerms {
+            wage,
+            length_years: renewal.contract_length_years.max(2),
+            squad_role,
+            release_clause: Some(release_clause),
+            signing_on_fee: wage * 2.0 * (1 + escalation) as f32,
+            performance_bonuses: player.contract.performance_bonuses.iter()
+                .map(|bonus| Bonus {
+                    achieved: false,
+                    ..bonus.clone()
+                })
+                .collect(),
+        }
+    }
+
+    /// Whether the club sees the player as worth keeping: a regular, or a prospect with a high ceiling
+    fn is_valued(&self, player: &Player) -> bool {
+        TransferEngine::new().squad_importance(player) >= 0.4
+            || (matches!(player.contract.squad_role, SquadRole::Prospect) && player.hidden.potential_ceiling >= 75)
+    }
+
+    /// The most serious interest from any other club
+    fn rival_interest(&self, player: &Player, state: &TransferSystemState) -> Option<InterestLevel> {
+        state
+            .interested_clubs
+            .values()
+            .filter(|interest| interest.club_id != player.contract.club_id)
+            .map(|interest| interest.interest_level.clone())
+            .max_by_key(|level| self.rival_pressure(level))
+    }
+
+    /// How much a rival's interest pushes the club, 0 for idle monitoring up to 3 for an official offer
+    fn rival_pressure(&self, level: &InterestLevel) -> u32 {
+        match level {
+            InterestLevel::Monitoring | InterestLevel::Scouting => 0,
+            InterestLevel::Shortlisted => 1,
+            InterestLevel::PreparingOffer => 2,
+            InterestLevel::OfficialOffer => 3,
+        }
+    }
+
+    /// One step up the pecking order
+    fn promise_role(&self, role: SquadRole) -> SquadRole {
+        match role {
+            SquadRole::Prospect | SquadRole::Backup => SquadRole::Rotation,
+            SquadRole::Rotation => SquadRole::FirstTeam,
+            SquadRole::FirstTeam | SquadRole::KeyPlayer => SquadRole::KeyPlayer,
+        }
+    }
+
+    /// The player's personality as it colours their relationship with the board
+    fn personality(&self, player: &Player) -> PersonalityFactors {
+        PersonalityFactors::new(player.hidden.loyalty, player.hidden.ego, player.mental.teamwork, 50, 50)
+    }
+}
+
+/// A renewal offer from the player's club
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RenewalApproach {
+    pub club_id: Uuid,
+    pub round: u32, // 1 for the opening approach
+    pub rival_interest: Option<InterestLevel>,
+    pub terms: ContractTerms,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::game_state::TransferInterest;
+    use crate::test_support::{create_test_player, create_test_team, date};
+
+    #[test]
+    fn test_club_opens_talks_at_eighteen_months_and_escalates_for_rivals() {
+        let engine = RenewalEngine::new();
+        let club = create_test_team();
+        let mut player = create_test_player();
+        player.contract.club_id = club.id;
+        player.contract.release_clause = Some(10000.0);
+        player.contract.contract_end_date = date(2027, 6, 30);
+        let mut state = TransferSystemState::new();
+
+        // Too early at twenty months, and no talks for a fringe player
+        assert!(engine.renewal_approach(&player, &club, &mut state, date(2025, 10, 30)).is_none());
+        let mut backup = player.clone();
+        backup.contract.squad_role = SquadRole::Backup;
+        assert!(engine.renewal_approach(&backup, &club, &mut state, date(2026, 1, 1)).is_none());
+
+        let opening = engine.renewal_approach(&player, &club, &mut state, date(2026, 1, 1)).unwrap();
+        assert_eq!(opening.round, 1);
+        assert!(matches!(opening.terms.squad_role, SquadRole::FirstTeam));
+        assert!(opening.terms.release_clause.unwrap() > 10000.0);
+        assert!(engine.renewal_approach(&player, &club, &mut state, date(2026, 1, 8)).is_none());
+
+        // A rival preparing an offer brings the club back within the week with more
+        let rival = Uuid::new_v4();
+        state.interested_clubs.insert(rival, TransferInterest {
+            club_id: rival,
+            interest_level: InterestLevel::PreparingOffer,
+            last_evaluation_date: date(2026, 1, 5).and_time(chrono::NaiveTime::MIN).and_utc(),
+            evaluation_score: 80.0,
+            scout_visits: 3,
+        });
+        let escalated = engine.renewal_approach(&player, &club, &mut state, date(2026, 1, 8)).unwrap();
+        assert_eq!(escalated.round, 2);
+        assert_eq!(escalated.rival_interest, Some(InterestLevel::PreparingOffer));
+        assert!(escalated.terms.wage > opening.terms.wage);
+        assert!(matches!(escalated.terms.squad_role, SquadRole::KeyPlayer));
+        assert!(escalated.terms.release_clause > opening.terms.release_clause);
+
+        engine.accept_renewal(&mut player, &club, &mut state, &escalated, date(2026, 1, 8));
+        assert!(matches!(player.contract.squad_role, SquadRole::KeyPlayer));
+        assert_eq!(player.contract.release_clause, escalated.terms.release_clause);
+        assert!(player.contract.contract_end_date > date(2027, 6, 30));
+        assert!(state.renewal_talks.is_none());
+        assert!(player.relationships[&club.id] > 50.0);
+    }
+
+    #[test]
+    fn test_stalling_sours_the_board_until_talks_break_down() {
+        let engine = RenewalEngine::new();
+        let club = create_test_team();
+        let mut player = create_test_player();
+        player.contract.club_id = club.id;
+        player.contract.contract_end_date = date(2027, 6, 30);
+        let mut state = TransferSystemState::new();
+        let mut day = date(2026, 1, 1);
+        let mut relationship = 50.0;
+
+        for stall in 1..=MAX_STALLS {
+            assert!(engine.renewal_approach(&player, &club, &mut state, day).is_some());
+            let broken_off = engine.stall(&mut player, &mut state);
+            assert_eq!(broken_off, stall == MAX_STALLS);
+            assert!(player.relationships[&club.id] < relationship);
+            relationship = player.relationships[&club.id];
+            day += chrono::Duration::days(APPROACH_INTERVAL_DAYS);
+        }
+
+        // The board won't come back to the table on this contract
+        assert!(engine.renewal_approach(&player, &club, &mut state, day).is_none());
+        assert!(state.renewal_talks.as_ref().unwrap().broken_off);
+    }
+}
@@ -9,6 +9,12 @@ use crate::entities::Player;
 /// from local to international reputation over time
 pub struct ReputationEngine;
 
+impl Default for ReputationEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ReputationEngine {
     /// Creates a new ReputationEngine instance
     pub fn new() -> Self {
@@ -67,11 +73,13 @@ impl ReputationEngine {
 
         // Importance multiplier
         let importance_multiplier = match importance {
+            MatchImportance::Youth => 0.3,
             MatchImportance::Friendly => 0.5,
             MatchImportance::League => 1.0,
             MatchImportance::Cup => 1.5,
             MatchImportance::Final => 2.0,
             MatchImportance::Continental => 2.5,
+            MatchImportance::International => 3.0,
         };
 
         // Big moment bonus
@@ -84,7 +92,10 @@ impl ReputationEngine {
             TeamPerformance::Loss => -0.3,
         };
 
-        (base_change * importance_multiplier) + big_moment_bonus + team_modifier
+        // Outside the senior game, goals and results barely register either
+        let bonus_scale = if matches!(importance, MatchImportance::Youth) { 0.3 } else { 1.0 };
+
+        (base_change * importance_multiplier) + (big_moment_bonus + team_modifier) * bonus_scale
     }
 
     /// Converts local reputation to international reputation
@@ -97,10 +108,12 @@ impl ReputationEngine {
         // Higher league strength converts local buzz to international fame faster
         let league_factor = league_strength / 100.0;
         
-        // Continental matches convert reputation faster
+        // Tournament and continental matches convert reputation faster
         let importance_factor = match match_importance {
+            MatchImportance::International => 1.8,
             MatchImportance::Continental => 1.5,
             MatchImportance::Final => 1.3,
+            MatchImportance::Youth => 0.3, // Few people outside the club watch youth football
             _ => 1.0,
         };
         
@@ -129,7 +142,7 @@ impl ReputationEngine {
         positional_needs: f32,       // 0-100 scale (how much club needs this position)
     ) -> f32 {
         // Base score from player attributes
-        let ability_score = self.calculate_player_ability_score(player);
+        let ability_score = player.ability_score();
         let potential_score = (player.hidden.potential_ceiling as f32) / 2.0;  // 0-50 scale
         let form_score = player.form * 0.5;  // 0-50 scale
         let reputation_score = player.international_reputation * 0.7;  // 0-70 scale (international matters more for transfers)
@@ -148,22 +161,6 @@ impl ReputationEngine {
         interest_score
     }
 
-    /// Calculates a player's overall ability score for transfer purposes
-    fn calculate_player_ability_score(&self, player: &Player) -> f32 {
-        // Weighted average of attributes
-        let technical_weight = 0.35;
-        let physical_weight = 0.25;
-        let mental_weight = 0.40;
-        
-        let technical_avg = player.technical.average();
-        let physical_avg = player.physical.average();
-        let mental_avg = player.mental.average();
-        
-        (technical_avg * technical_weight) + 
-        (physical_avg * physical_weight) + 
-        (mental_avg * mental_weight)
-    }
-
     /// Calculates age factor for transfer interest
     fn calculate_age_factor(&self, age: u8) -> f32 {
         match age {
@@ -222,8 +219,7 @@ impl ReputationEngine {
         
         // Awards give significant reputation boosts
         for award in awards {
-            let award_boost = self.get_award_reputation_boost(award);
-            player.international_reputation = (player.international_reputation + award_boost).clamp(0.0, 100.0);
+            self.apply_award(player, award);
         }
         
         // Team success affects international reputation
@@ -231,6 +227,12 @@ impl ReputationEngine {
         player.international_reputation = (player.international_reputation + team_success_boost).clamp(0.0, 100.0);
     }
 
+    /// Applies the international reputation boost for winning an award
+    pub fn apply_award(&self, player: &mut Player, award: &str) {
+        let award_boost = self.get_award_reputation_boost(award);
+        player.international_reputation = (player.international_reputation + award_boost).clamp(0.0, 100.0);
+    }
+
     /// Calculates season performance score
     fn calculate_season_performance_score(&self, stats: &SeasonStats, team_position: u8) -> f32 {
         // Calculate based on key stats
@@ -256,7 +258,8 @@ impl ReputationEngine {
         match award.to_lowercase().as_str() {
             "ballon d'or" | "world player of the year" => 25.0,
             "league best player" => 15.0,
-            "top scorer" => 10.0,
+            "top scorer" | "golden boot" => 10.0,
+            "international tournament winner" => 12.0,
             "best young player" => 8.0,
             "team of the season" => 5.0,
             _ => 2.0,  // Other awards
@@ -289,11 +292,13 @@ impl ReputationEngine {
 /// Match importance levels
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum MatchImportance {
+    Youth, // Youth and reserve leagues
     Friendly,
     League,
     Cup,
     Final,
     Continental,
+    International, // Finals of a World Cup or continental championship
 }
 
 /// How the team performed in the match
@@ -351,8 +356,6 @@ impl PositionExt for crate::entities::Position {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::entities::{Player, Position, Foot, CareerStats, Contract, SquadRole, HiddenAttributes};
-    use chrono::NaiveDate;
 
     #[test]
     fn test_local_reputation_change() {
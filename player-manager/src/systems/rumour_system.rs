@@ -0,0 +1,493 @@
+// src/systems/rumour_system.rs
+use chrono::NaiveDate;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::core::game_state::{
+    InterestLevel, RumourReliability, TransferInterest, TransferRumour, TransferSystemState,
+};
+use crate::entities::{Player, Team};
+use crate::systems::transfer_system::InterestLevel as EvaluatedInterest;
+use crate::systems::TransferEngine;
+
+/// Scouting awareness a club needs before it starts monitoring a player
+const AWARENESS_TO_MONITOR: f32 = 20.0;
+/// Further awareness needed for each stage beyond monitoring
+const AWARENESS_PER_STAGE: f32 = 20.0;
+/// Awareness gained each time a scout watches the player
+const SCOUT_VISIT_AWARENESS: f32 = 10.0;
+/// Awareness a club gains when the player's agent talks them up
+const AGENT_INTRODUCTION_AWARENESS: f32 = 15.0;
+/// Club reputation from which a club counts as a major side
+const BIG_CLUB_REPUTATION: f32 = 80.0;
+/// Chance a week's gossip includes a baseless tabloid link
+const FALSE_RUMOUR_CHANCE: f64 = 0.15;
+/// Days before a contract runs out when the press starts asking about it
+const CONTRACT_RUMOUR_DAYS: i64 = 365;
+/// Days between repeated stories about the player's contract
+const CONTRACT_RUMOUR_INTERVAL_DAYS: i64 = 28;
+
+/// The RumourEngine moves club interest along week by week and turns it into gossip
+/// Scouts watch matches, interest climbs one stage at a time and the player reacts to what they read
+pub struct RumourEngine;
+
+impl Default for RumourEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RumourEngine {
+    /// Creates a new RumourEngine instance
+    pub fn new() -> Self {
+        RumourEngine
+    }
+
+    /// Advances every club's interest in the player by one week and publishes the week's rumours
+    ///
+    /// Clubs first need to notice the player, which depends on reputation, club size
+    /// and matches played. Interest then moves at most one stage a week towards what
+    /// the club's squad analysis supports. `matches_played` is the number of matches
+    /// the player's club played this week, which scouts can attend.
+    pub fn advance_week(
+        &self,
+        state: &mut TransferSystemState,
+        player: &mut Player,
+        all_teams: &[Team],
+        all_players: &[Player],
+        matches_played: u32,
+        today: NaiveDate,
+    ) -> WeeklyTransferReport {
+        let current_club_id = player.contract.club_id;
+        let transfer_engine = TransferEngine::new();
+        let mut report = WeeklyTransferReport::default();
+        let evaluation_date = today.and_hms_opt(12, 0, 0).unwrap().and_utc();
+
+        for team in all_teams.iter().filter(|team| team.id != current_club_id) {
+            let evaluation = transfer_engine.evaluate_club_interest(player, team, all_players, 1.0, today);
+
+            // Scouts only go to watch once a club is actively evaluating
+            let current_rank = state.interested_clubs.get(&team.id).map(|interest| self.level_rank(&interest.interest_level)).unwrap_or(0);
+            let scouting = current_rank >= 2 && matches_played > 0;
+
+            let awareness = state.scouting_awareness.entry(team.id).or_insert(0.0);
+            *awareness = (*awareness + self.weekly_discovery(player, team, matches_played)).min(100.0);
+            if scouting {
+                *awareness = (*awareness + SCOUT_VISIT_AWARENESS).min(100.0);
+                report.scout_visits.push(team.id);
+            }
+            let awareness = *awareness;
+
+            // How far interest can go: what the club wants, capped by how well it knows the player
+            let wanted = evaluation.as_ref().map(|interest| self.evaluated_rank(&interest.interest_level)).unwrap_or(0);
+            let known = if awareness < AWARENESS_TO_MONITOR {
+                0
+            } else {
+                1 + ((awareness - AWARENESS_TO_MONITOR) / AWARENESS_PER_STAGE) as u8
+            };
+            let target = wanted.min(known);
+
+            let new_rank = match current_rank.cmp(&target) {
+                std::cmp::Ordering::Less => current_rank + 1,
+                std::cmp::Ordering::Greater => current_rank - 1,
+                std::cmp::Ordering::Equal => current_rank,
+            };
+
+            match self.level_for_rank(new_rank) {
+                Some(level) => {
+                    let entry = state.interested_clubs.entry(team.id).or_insert(TransferInterest {
+                        club_id: team.id,
+                        interest_level: level.clone(),
+                        last_evaluation_date: evaluation_date,
+                        evaluation_score: 0.0,
+                        scout_visits: 0,
+                    });
+                    entry.interest_level = level;
+                    entry.last_evaluation_date = evaluation_date;
+                    entry.evaluation_score = evaluation.as_ref().map(|interest| interest.evaluation_score).unwrap_or(0.0);
+                    if scouting {
+                        entry.scout_visits += 1;
+                    }
+                }
+                None => {
+                    state.interested_clubs.remove(&team.id);
+                }
+            }
+
+            if new_rank != current_rank {
+                report.stage_changes.push(StageChange {
+                    club_id: team.id,
+                    from: self.level_for_rank(current_rank),
+                    to: self.level_for_rank(new_rank),
+                });
+            }
+        }
+
+        report.rumours = self.write_rumours(state, player, all_teams, current_club_id, &report, today);
+        state.rumours.extend(report.rumours.iter().cloned());
+
+        let current_reputation = all_teams.iter()
+            .find(|team| team.id == current_club_id)
+            .map(|team| team.reputation)
+            .unwrap_or(50.0);
+        let (morale_change, focus_change) = self.react_to_rumours(player, &report.rumours, all_teams, current_reputation);
+        report.morale_change = morale_change;
+        report.focus_change = focus_change;
+
+        report
+    }
+
+    /// Puts the player on the radar of clubs the agent has pitched them to
+    ///
+    /// The pitch only raises awareness; interest still climbs through the weekly round.
+    pub fn agent_introductions(&self, state: &mut TransferSystemState, club_ids: &[Uuid]) {
+        for club_id in club_ids {
+            let awareness = state.scouting_awareness.entry(*club_id).or_insert(0.0);
+            *awareness = (*awareness + AGENT_INTRODUCTION_AWARENESS).min(100.0);
+        }
+    }
+
+    /// How much more a club learns about the player in a week without sending scouts
+    fn weekly_discovery(&self, player: &Player, team: &Team, matches_played: u32) -> f32 {
+        // Bigger clubs have wider scouting networks
+        let reach = 0.5 + team.reputation / 100.0;
+        let reputation = player.local_reputation * 0.05 + player.international_reputation * 0.1;
+        // Good performances get noticed; form is on the match rating scale
+        let performances = matches_played as f32 * 2.0 * (player.form / 7.0);
+
+        (reputation + performances) * reach
+    }
+
+    /// Turns the week's movements into news items
+    ///
+    /// The player only hears reliable stories once a club has shortlisted them;
+    /// earlier interest only surfaces as gossip.
+    fn write_rumours(
+        &self,
+        state: &TransferSystemState,
+        player: &Player,
+        all_teams: &[Team],
+        current_club_id: Uuid,
+        report: &WeeklyTransferReport,
+        today: NaiveDate,
+    ) -> Vec<TransferRumour> {
+        let mut rng = rand::thread_rng();
+        let mut rumours = Vec::new();
+        let club_name = |club_id: Uuid| all_teams.iter()
+            .find(|team| team.id == club_id)
+            .map(|team| team.name.clone())
+            .unwrap_or_else(|| "A club".to_string());
+
+        for change in &report.stage_changes {
+            let Some(level) = &change.to else {
+                continue;
+            };
+            let rising = change.from.as_ref().map(|from| self.level_rank(from)).unwrap_or(0) < self.level_rank(level);
+            if !rising {
+                continue;
+            }
+            let big_club = all_teams.iter().any(|team| team.id == change.club_id && team.reputation >= BIG_CLUB_REPUTATION);
+            let story = match level {
+                InterestLevel::Shortlisted => Some((
+                    format!("{} add {} to their shortlist", club_name(change.club_id), player.name),
+                    RumourReliability::Journalist,
+                )),
+                InterestLevel::PreparingOffer => Some((
+                    format!("{} preparing a bid for {}", club_name(change.club_id), player.name),
+                    RumourReliability::Insider,
+                )),
+                InterestLevel::OfficialOffer => Some((
+                    format!("{} submit an official offer for {}", club_name(change.club_id), player.name),
+                    RumourReliability::Insider,
+                )),
+                InterestLevel::Monitoring | InterestLevel::Scouting if big_club => Some((
+                    format!("{} linked with a move to {}", player.name, club_name(change.club_id)),
+                    RumourReliability::Tabloid,
+                )),
+                _ => None,
+            };
+            if let Some((headline, reliability)) = story {
+                rumours.push(self.rumour(today, Some(change.club_id), headline, reliability, true));
+            }
+        }
+
+        // Several clubs watching at once gets noticed
+        let watching = state.interested_clubs.values()
+            .filter(|interest| self.level_rank(&interest.interest_level) >= 2)
+            .count();
+        if watching >= 2 && !report.scout_visits.is_empty() {
+            rumours.push(self.rumour(
+                today,
+                None,
+                format!("Clubs keeping tabs on {}", player.name),
+                RumourReliability::Journalist,
+                true,
+            ));
+        }
+
+        // A contract running down invites questions about the future
+        let days_left = (player.contract.contract_end_date - today).num_days();
+        let recently_asked = state.rumours.iter().any(|rumour| {
+            rumour.club_id.is_none()
+                && rumour.headline.contains("future uncertain")
+                && (today - rumour.date).num_days() < CONTRACT_RUMOUR_INTERVAL_DAYS
+        });
+        if (0..=CONTRACT_RUMOUR_DAYS).contains(&days_left) && !recently_asked {
+            rumours.push(self.rumour(
+                today,
+                None,
+                format!("{}'s future uncertain as contract runs down", player.name),
+                RumourReliability::Journalist,
+                true,
+            ));
+        }
+
+        // And some stories are simply made up
+        if rng.gen_bool(FALSE_RUMOUR_CHANCE) {
+            let uninterested: Vec<&Team> = all_teams.iter()
+                .filter(|team| team.id != current_club_id && !state.interested_clubs.contains_key(&team.id))
+                .collect();
+            if let Some(team) = uninterested.choose(&mut rng) {
+                rumours.push(self.rumour(
+                    today,
+                    Some(team.id),
+                    format!("{} linked with a move to {}", player.name, team.name),
+                    RumourReliability::Tabloid,
+                    false,
+                ));
+            }
+        }
+
+        rumours
+    }
+
+    fn rumour(&self, date: NaiveDate, club_id: Option<Uuid>, headline: String, reliability: RumourReliability, truthful: bool) -> TransferRumour {
+        TransferRumour {
+            id: Uuid::new_v4(),
+            date,
+            club_id,
+            headline,
+            reliability,
+            truthful,
+        }
+    }
+
+    /// Applies the player's reaction to the week's rumours, returning (morale, focus) changes
+    ///
+    /// Ambitious players are thrilled by links to bigger clubs but get distracted.
+    /// Loyal players are unsettled by talk of leaving. Focus is reflected in sharpness.
+    pub fn react_to_rumours(
+        &self,
+        player: &mut Player,
+        rumours: &[TransferRumour],
+        all_teams: &[Team],
+        current_reputation: f32,
+    ) -> (f32, f32) {
+        let ambition = player.hidden.ambition as f32 / 100.0;
+        let loyalty = player.hidden.loyalty as f32 / 100.0;
+        let mut morale_change = 0.0;
+        let mut focus_change = 0.0;
+
+        for rumour in rumours {
+            let Some(club) = rumour.club_id.and_then(|id| all_teams.iter().find(|team| team.id == id)) else {
+                continue;
+            };
+            let weight = self.reliability_weight(rumour.reliability);
+
+            if club.reputation > current_reputation {
+                morale_change += ambition * 3.0 * weight;
+                focus_change -= ambition * 2.0 * weight;
+            }
+            morale_change -= loyalty * 2.0 * weight;
+        }
+
+        player.morale = (player.morale + morale_change).clamp(0.0, 100.0);
+        player.sharpness = (player.sharpness + focus_change).clamp(0.0, 100.0);
+
+        (morale_change, focus_change)
+    }
+
+    /// How much weight the player gives a story
+    pub fn reliability_weight(&self, reliability: RumourReliability) -> f32 {
+        match reliability {
+            RumourReliability::Tabloid => 0.3,
+            RumourReliability::Journalist => 0.6,
+            RumourReliability::Insider => 1.0,
+        }
+    }
+
+    /// Orders interest stages, 0 meaning no interest
+    fn level_rank(&self, level: &InterestLevel) -> u8 {
+        match level {
+            InterestLevel::Monitoring => 1,
+            InterestLevel::Scouting => 2,
+            InterestLevel::Shortlisted => 3,
+            InterestLevel::PreparingOffer => 4,
+            InterestLevel::OfficialOffer => 5,
+        }
+    }
+
+    fn evaluated_rank(&self, level: &EvaluatedInterest) -> u8 {
+        match level {
+            EvaluatedInterest::Monitoring => 1,
+            EvaluatedInterest::Scouting => 2,
+            EvaluatedInterest::Shortlisted => 3,
+            EvaluatedInterest::PreparingOffer => 4,
+            EvaluatedInterest::OfficialOffer => 5,
+        }
+    }
+
+    fn level_for_rank(&self, rank: u8) -> Option<InterestLevel> {
+        match rank {
+            0 => None,
+            1 => Some(InterestLevel::Monitoring),
+            2 => Some(InterestLevel::Scouting),
+            3 => Some(InterestLevel::Shortlisted),
+            4 => Some(InterestLevel::PreparingOffer),
+            _ => Some(InterestLevel::OfficialOffer),
+        }
+    }
+}
+
+/// What happened in the transfer market around the player this week
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WeeklyTransferReport {
+    pub stage_changes: Vec<StageChange>,
+    pub scout_visits: Vec<Uuid>, // Clubs whose scouts watched the player
+    pub rumours: Vec<TransferRumour>,
+    pub morale_change: f32,
+    pub focus_change: f32,
+}
+
+/// A club moving from one interest stage to another
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StageChange {
+    pub club_id: Uuid,
+    pub from: Option<InterestLevel>,
+    pub to: Option<InterestLevel>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::Position;
+    use crate::test_support::{create_test_player, create_test_team};
+
+    #[test]
+    fn test_interest_builds_week_by_week() {
+        let engine = RumourEngine::new();
+        let mut player = create_test_player();
+        player.primary_position = Position::CF;
+        let mut current = create_test_team();
+        current.id = player.contract.club_id;
+        let mut big_club = create_test_team();
+        big_club.name = "Big Club".to_string();
+        big_club.reputation = 90.0;
+        let mut stacked = create_test_team();
+        let elite: Vec<Player> = (0..3).map(|_| create_elite_striker()).collect();
+        stacked.squad = elite.iter().map(|p| p.id).collect();
+        let teams = vec![current, big_club.clone(), stacked.clone()];
+
+        let mut state = TransferSystemState::new();
+        let mut today = NaiveDate::from_ymd_opt(2025, 8, 4).unwrap();
+        let mut best_rank = 0;
+        for _ in 0..12 {
+            let report = engine.advance_week(&mut state, &mut player, &teams, &elite, 1, today);
+            for change in &report.stage_changes {
+                let from = change.from.as_ref().map(|l| engine.level_rank(l)).unwrap_or(0);
+                let to = change.to.as_ref().map(|l| engine.level_rank(l)).unwrap_or(0);
+                assert_eq!(from.abs_diff(to), 1);  // One stage at a time
+            }
+            if let Some(interest) = state.interested_clubs.get(&big_club.id) {
+                best_rank = best_rank.max(engine.level_rank(&interest.interest_level));
+            }
+            today += chrono::Duration::days(7);
+        }
+
+        assert!(best_rank >= 3);
+        assert!(state.interested_clubs[&big_club.id].scout_visits > 0);
+        assert!(!state.interested_clubs.contains_key(&stacked.id));
+        assert!(state.rumours.iter().any(|r| r.club_id == Some(big_club.id)
+            && r.reliability != RumourReliability::Tabloid
+            && r.truthful));
+    }
+
+    #[test]
+    fn test_agent_introductions_raise_awareness() {
+        let engine = RumourEngine::new();
+        let club = create_test_team();
+        let mut state = TransferSystemState::new();
+        state.scouting_awareness.insert(club.id, 95.0);
+
+        engine.agent_introductions(&mut state, &[club.id]);
+        engine.agent_introductions(&mut state, &[Uuid::nil()]);
+        assert_eq!(state.scouting_awareness[&club.id], 100.0);
+        assert_eq!(state.scouting_awareness[&Uuid::nil()], AGENT_INTRODUCTION_AWARENESS);
+    }
+
+    #[test]
+    fn test_contract_rumours_are_not_repeated_every_week() {
+        let engine = RumourEngine::new();
+        let mut player = create_test_player();
+        let mut current = create_test_team();
+        current.id = player.contract.club_id;
+        let today = NaiveDate::from_ymd_opt(2025, 8, 4).unwrap();
+        player.contract.contract_end_date = today + chrono::Duration::days(200);
+
+        let mut state = TransferSystemState::new();
+        engine.advance_week(&mut state, &mut player, &[current.clone()], &[], 0, today);
+        engine.advance_week(&mut state, &mut player, &[current], &[], 0, today + chrono::Duration::days(7));
+
+        let contract_stories = state.rumours.iter().filter(|r| r.headline.contains("future uncertain")).count();
+        assert_eq!(contract_stories, 1);
+    }
+
+    #[test]
+    fn test_reaction_depends_on_ambition_and_loyalty() {
+        let engine = RumourEngine::new();
+        let mut big_club = create_test_team();
+        big_club.reputation = 95.0;
+        let rumour = engine.rumour(
+            NaiveDate::from_ymd_opt(2025, 8, 4).unwrap(),
+            Some(big_club.id),
+            "Big move on the cards".to_string(),
+            RumourReliability::Insider,
+            true,
+        );
+        let teams = vec![big_club];
+
+        let mut ambitious = create_test_player();
+        ambitious.hidden.ambition = 90;
+        ambitious.hidden.loyalty = 10;
+        let (morale, focus) = engine.react_to_rumours(&mut ambitious, std::slice::from_ref(&rumour), &teams, 60.0);
+        assert!(morale > 0.0);
+        assert!(focus < 0.0);
+        assert_eq!(ambitious.morale, 75.0 + morale);
+
+        let mut loyal = create_test_player();
+        loyal.hidden.ambition = 10;
+        loyal.hidden.loyalty = 90;
+        let (morale, _) = engine.react_to_rumours(&mut loyal, &[rumour], &teams, 60.0);
+        assert!(morale < 0.0);
+    }
+
+    // Helper functions for tests
+    fn create_elite_striker() -> Player {
+        let mut player = create_test_player();
+        player.id = Uuid::new_v4();
+        player.primary_position = Position::CF;
+        player.technical = crate::entities::TechnicalAttributes {
+            dribbling: 95, passing: 95, shooting: 95, first_touch: 95, tackling: 95, crossing: 95,
+        };
+        player.physical = crate::entities::PhysicalAttributes {
+            pace: 95, stamina: 95, strength: 95, agility: 95, jumping: 95,
+        };
+        player.mental = crate::entities::MentalAttributes {
+            composure: 95, vision: 95, work_rate: 95, determination: 95, positioning: 95, teamwork: 95,
+        };
+        player.contract.contract_end_date = NaiveDate::from_ymd_opt(2035, 6, 30).unwrap();
+        player
+    }
+}
@@ -0,0 +1,530 @@
+// src/systems/season_system.rs
+use chrono::{DateTime, Datelike, Months, NaiveDate, Utc};
+use uuid::Uuid;
+
+use crate::core::game_state::{season_start_year, CompetitionRecord, GameState, SeasonArchive, TransferWindows};
+use crate::core::time_engine::{EventPriority, ScheduledEvent, ScheduledEventType, TimeEngine};
+use crate::entities::{Competition, CompetitionType, Player, SeasonStats, Trophy};
+use crate::systems::{AwardsEngine, BonusEngine, ClubFinanceEngine, CompetitionEngine, FeeEngine, FinanceEngine, FreeAgencyEngine, InternationalEngine, TournamentEngine};
+use crate::utils::helpers::{age_on, kick_off, season_label};
+
+/// The SeasonEngine moves the game world from one season to the next
+/// It archives the finished season, rolls competitions forward and schedules the new calendar
+pub struct SeasonEngine;
+
+impl Default for SeasonEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SeasonEngine {
+    /// Creates a new SeasonEngine instance
+    pub fn new() -> Self {
+        SeasonEngine
+    }
+
+    /// Closes the current season and starts the next one, returning the archived season
+    pub fn advance_season(&self, game_state: &mut GameState, time_engine: &mut TimeEngine) -> SeasonArchive {
+        let finished_label = self.current_label(game_state);
+        let awards = AwardsEngine::new().run_ceremony(game_state, &finished_label);
+        let today = game_state.current_date.date_naive();
+        BonusEngine::new().settle_season(game_state, today);
+        FinanceEngine::new().record_net_worth(&mut game_state.player_finances, &finished_label);
+        ClubFinanceEngine::new().award_prize_money(game_state);
+        FeeEngine::new().settle_season(game_state);
+        let mut archive = self.archive_season(game_state, &finished_label);
+        archive.awards = awards;
+
+        let finish_position = self.club_league_position(game_state, game_state.current_club_id);
+        self.archive_player_season(&mut game_state.player, &archive, game_state.current_club_id, finish_position);
+        // Everyone else in the world closes their season at their current club
+        let finishes: Vec<Option<u8>> = game_state.players.iter()
+            .map(|player| self.club_league_position(game_state, player.contract.club_id))
+            .collect();
+        for (player, finish_position) in game_state.players.iter_mut().zip(finishes) {
+            let club_id = player.contract.club_id;
+            self.archive_player_season(player, &archive, club_id, finish_position);
+        }
+        game_state.season_history.push(archive.clone());
+
+        // Roll the calendar forward a year
+        let season = &mut game_state.season;
+        season.start_date = add_year(season.start_date);
+        season.end_date = add_year(season.end_date);
+        season.transfer_windows.summer_start = add_year(season.transfer_windows.summer_start);
+        season.transfer_windows.summer_end = add_year(season.transfer_windows.summer_end);
+        season.transfer_windows.winter_start = season.transfer_windows.winter_start.map(add_year);
+        season.transfer_windows.winter_end = season.transfer_windows.winter_end.map(add_year);
+        // Each country with its own calendar gets next season's dates
+        let new_start_year = season_start_year(season.start_date);
+        for country in game_state.clubs.iter().map(|club| club.country.clone()) {
+            season.country_windows.insert(country.clone(), TransferWindows::for_country(&country, new_start_year));
+        }
+        season.year = season_label(season_start_year(season.start_date));
+        season.current_matchday = 1;
+        season.is_active = true;
+
+        let new_label = season.year.clone();
+        for player in std::iter::once(&mut game_state.player).chain(game_state.players.iter_mut()) {
+            player.career_stats.current_season = SeasonStats {
+                season_year: new_label.clone(),
+                ..SeasonStats::default()
+            };
+        }
+
+        for league in game_state.leagues.iter_mut() {
+            self.roll_competition(league, &new_label);
+        }
+        for competition in game_state.competitions.iter_mut() {
+            // Keep leagues listed in both collections identical, fixture ids included
+            match game_state.leagues.iter().find(|league| league.id == competition.id) {
+                Some(league) => *competition = league.clone(),
+                None => self.roll_competition(competition, &new_label),
+            }
+        }
+
+        self.schedule_season_events(game_state, time_engine);
+
+        archive
+    }
+
+    /// Records the final table and winner of every competition played this season
+    pub fn archive_season(&self, game_state: &GameState, label: &str) -> SeasonArchive {
+        let competition_engine = CompetitionEngine::new();
+        let mut records: Vec<CompetitionRecord> = Vec::new();
+
+        // Leagues are often listed in both collections, so keep one record per competition
+        for competition in game_state.leagues.iter().chain(game_state.competitions.iter()) {
+            if records.iter().any(|record| record.competition_id == competition.id) {
+                continue;
+            }
+
+            records.push(CompetitionRecord {
+                competition_id: competition.id,
+                name: competition.name.clone(),
+                winner: competition_engine.get_competition_winner(competition),
+                final_table: competition.standings.clone(),
+                player_stats: competition.player_stats.clone(),
+                youth: competition.competition_type.is_youth(),
+            });
+        }
+
+        SeasonArchive {
+            season: label.to_string(),
+            competitions: records,
+            awards: Vec::new(),
+        }
+    }
+
+    /// Moves the player's running season stats into their career history
+    pub fn archive_player_season(
+        &self,
+        player: &mut Player,
+        archive: &SeasonArchive,
+        club_id: Uuid,
+        finish_position: Option<u8>,
+    ) {
+        let career = &mut player.career_stats;
+
+        // Earlier spells at other clubs this season, e.g. before a loan, are kept per club
+        for mut spell in std::mem::take(&mut career.club_spells) {
+            if spell.season_year.is_empty() {
+                spell.season_year = archive.season.clone();
+            }
+            career.season_stats.push(spell);
+        }
+
+        let mut season_stats = std::mem::take(&mut career.current_season);
+        if season_stats.season_year.is_empty() {
+            season_stats.season_year = archive.season.clone();
+        }
+        season_stats.club_id.get_or_insert(club_id);
+        season_stats.team_finish_position = finish_position;
+        career.season_stats.push(season_stats);
+        career.seasons_played = career.seasons_played.saturating_add(1);
+
+        // Youth football is tracked separately from the senior career
+        if career.youth_season.appearances > 0 {
+            let mut youth_season = std::mem::take(&mut career.youth_season);
+            youth_season.season_year = archive.season.clone();
+            career.youth_history.push(youth_season);
+        }
+
+        // Any senior competition the club won counts as a trophy, youth ones only for those who played
+        let player_id = player.id;
+        let won = archive.competitions.iter()
+            .filter(|record| record.winner == Some(club_id))
+            .filter(|record| !record.youth || record.player_stats.iter().any(|stats| stats.player_id == player_id));
+        for record in won {
+            career.trophies.push(Trophy {
+                name: record.name.clone(),
+                season: archive.season.clone(),
+                competition: record.name.clone(),
+            });
+        }
+    }
+
+    /// Updates the player's age if a birthday has passed, returning true on a birthday
+    pub fn process_birthdays(&self, player: &mut Player, date: NaiveDate) -> bool {
+        let age = age_on(player.birth_date, date);
+        if age > player.age {
+            player.age = age;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Resets a competition for the next season with fresh standings and fixtures
+    pub fn roll_competition(&self, competition: &mut Competition, label: &str) {
+        competition.season_start = add_year_naive(competition.season_start);
+        competition.season_end = add_year_naive(competition.season_end);
+        competition.current_season.start_date = add_year_naive(competition.current_season.start_date);
+        competition.current_season.end_date = add_year_naive(competition.current_season.end_date);
+        competition.current_season.year = label.to_string();
+
+        CompetitionEngine::new().initialize_season(competition);
+    }
+
+    /// Queues the club's matches, transfer windows, the player's contract expiry and season end in the time engine
+    pub fn schedule_season_events(&self, game_state: &GameState, time_engine: &mut TimeEngine) {
+        let club_id = game_state.current_club_id;
+        let mut scheduled_fixtures: Vec<Uuid> = Vec::new();
+
+        // Youth and reserve fixtures are played day by day rather than as club match days
+        let senior = game_state.leagues.iter()
+            .chain(game_state.competitions.iter())
+            .filter(|competition| !competition.competition_type.is_youth());
+        for competition in senior {
+            for fixture in competition.fixtures.iter() {
+                if (fixture.home_team != club_id && fixture.away_team != club_id)
+                    || scheduled_fixtures.contains(&fixture.id)
+                {
+                    continue;
+                }
+
+                scheduled_fixtures.push(fixture.id);
+                time_engine.schedule_event(ScheduledEvent::new(
+                    kick_off(fixture.scheduled_date),
+                    ScheduledEventType::MatchDay(fixture.id),
+                    EventPriority::Medium,
+                    false,
+                ));
+            }
+        }
+
+        // The player trades in the windows of their club's country
+        let windows = game_state.clubs.iter()
+            .find(|club| club.id == club_id)
+            .map(|club| game_state.season.windows_for(&club.country))
+            .unwrap_or(&game_state.season.transfer_windows);
+        let mut window_events = vec![
+            (windows.summer_start, ScheduledEventType::TransferWindowOpen),
+            (windows.summer_end, ScheduledEventType::TransferWindowClose),
+        ];
+        if let (Some(start), Some(end)) = (windows.winter_start, windows.winter_end) {
+            window_events.push((start, ScheduledEventType::TransferWindowOpen));
+            window_events.push((end, ScheduledEventType::TransferWindowClose));
+        }
+        for (time, event_type) in window_events {
+            time_engine.schedule_event(ScheduledEvent::new(time, event_type, EventPriority::Low, false));
+        }
+
+        let break_dates = InternationalEngine::new().international_break_dates(
+            game_state.season.start_date.date_naive(),
+            game_state.season.end_date.date_naive(),
+        );
+        for date in break_dates {
+            time_engine.schedule_event(ScheduledEvent::new(
+                kick_off(date),
+                ScheduledEventType::InternationalBreak,
+                EventPriority::Medium,
+                false,
+            ));
+        }
+
+        // A tournament summer runs into next season's pre-season
+        let finals_dates = TournamentEngine::new().finals_dates(game_state.season.end_date.year());
+        for date in finals_dates {
+            time_engine.schedule_event(ScheduledEvent::new(
+                kick_off(date),
+                ScheduledEventType::InternationalTournament,
+                EventPriority::Medium,
+                false,
+            ));
+        }
+
+        if game_state.transfer_system.free_agent_since.is_none() {
+            FreeAgencyEngine::new().schedule_contract_expiry(&game_state.player, time_engine);
+        }
+
+        time_engine.schedule_event(ScheduledEvent::new(
+            game_state.season.end_date,
+            ScheduledEventType::SeasonEnd,
+            EventPriority::High,
+            false,
+        ));
+    }
+
+    /// Label of the season being played, derived from the start date if not yet set
+    fn current_label(&self, game_state: &GameState) -> String {
+        if game_state.season.year.is_empty() {
+            season_label(season_start_year(game_state.season.start_date))
+        } else {
+            game_state.season.year.clone()
+        }
+    }
+
+    /// The club's final position in the highest league it played in
+    fn club_league_position(&self, game_state: &GameState, club_id: Uuid) -> Option<u8> {
+        game_state.leagues.iter()
+            .chain(game_state.competitions.iter())
+            .filter(|competition| matches!(competition.competition_type, CompetitionType::League))
+            .filter(|competition| competition.teams.contains(&club_id))
+            .min_by_key(|competition| competition.level)
+            .and_then(|competition| {
+                competition.standings.iter()
+                    .find(|standing| standing.team_id == club_id)
+                    .map(|standing| standing.position)
+            })
+    }
+}
+
+fn add_year(date: DateTime<Utc>) -> DateTime<Utc> {
+    date.checked_add_months(Months::new(12)).unwrap_or(date)
+}
+
+fn add_year_naive(date: NaiveDate) -> NaiveDate {
+    date.checked_add_months(Months::new(12)).unwrap_or(date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::game_state::{AddOnCondition, FeeAddOn, FeeStructure, LedgerEntryKind, TransferRecord};
+    use crate::entities::{Bonus, BonusCondition, CurrentSeason, MatchStatus, Team, TiebreakRule};
+    use crate::test_support::{create_test_player, create_test_team};
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_process_birthdays() {
+        let engine = SeasonEngine::new();
+        let mut player = create_test_player();
+
+        assert!(!engine.process_birthdays(&mut player, NaiveDate::from_ymd_opt(2026, 3, 14).unwrap()));
+        assert_eq!(player.age, 17);
+
+        assert!(engine.process_birthdays(&mut player, NaiveDate::from_ymd_opt(2026, 3, 15).unwrap()));
+        assert_eq!(player.age, 18);
+
+        // Only one birthday a year
+        assert!(!engine.process_birthdays(&mut player, NaiveDate::from_ymd_opt(2026, 8, 1).unwrap()));
+    }
+
+    #[test]
+    fn test_season_labels() {
+        assert_eq!(season_label(2026), "2026-27");
+        assert_eq!(season_label(2099), "2099-00");
+        assert_eq!(season_start_year(Utc.with_ymd_and_hms(2027, 3, 1, 0, 0, 0).unwrap()), 2026);
+        assert_eq!(season_start_year(Utc.with_ymd_and_hms(2026, 8, 1, 0, 0, 0).unwrap()), 2026);
+    }
+
+    #[test]
+    fn test_season_end_archives_competitions() {
+        let (mut game_state, mut time_engine, club_id, _) = create_finished_season();
+        let archive = SeasonEngine::new().advance_season(&mut game_state, &mut time_engine);
+
+        // The finished season is archived once per competition
+        assert_eq!(archive.season, "2026-27");
+        assert_eq!(archive.competitions.len(), 1);
+        assert_eq!(archive.competitions[0].winner, Some(club_id));
+        assert_eq!(game_state.season_history.len(), 1);
+    }
+
+    #[test]
+    fn test_season_end_moves_player_season_into_career() {
+        let (mut game_state, mut time_engine, _, _) = create_finished_season();
+        SeasonEngine::new().advance_season(&mut game_state, &mut time_engine);
+
+        let career = &game_state.player.career_stats;
+        assert_eq!(career.seasons_played, 1);
+        assert_eq!(career.season_stats.len(), 1);
+        assert_eq!(career.season_stats[0].season_year, "2026-27");
+        assert_eq!(career.season_stats[0].goals, 12);
+        assert_eq!(career.season_stats[0].team_finish_position, Some(1));
+        assert_eq!(career.trophies.len(), 1);
+        assert_eq!(career.current_season.season_year, "2027-28");
+        assert_eq!(career.current_season.appearances, 0);
+    }
+
+    #[test]
+    fn test_season_end_archives_every_players_season() {
+        let (mut game_state, mut time_engine, club_id, _) = create_finished_season();
+        let mut teammate = create_test_player();
+        teammate.contract.club_id = club_id;
+        teammate.career_stats.current_season.goals = 5;
+        game_state.players.push(teammate);
+        SeasonEngine::new().advance_season(&mut game_state, &mut time_engine);
+
+        let career = &game_state.players[0].career_stats;
+        assert_eq!(career.season_stats.len(), 1);
+        assert_eq!(career.season_stats[0].goals, 5);
+        assert_eq!(career.season_stats[0].team_finish_position, Some(1));
+        assert_eq!(career.current_season.season_year, "2027-28");
+    }
+
+    #[test]
+    fn test_season_end_rolls_competitions() {
+        let (mut game_state, mut time_engine, _, _) = create_finished_season();
+        SeasonEngine::new().advance_season(&mut game_state, &mut time_engine);
+
+        // Next season's competitions start from scratch with the new label
+        assert_eq!(game_state.season.year, "2027-28");
+        for competition in game_state.leagues.iter().chain(game_state.competitions.iter()) {
+            assert_eq!(competition.current_season.year, "2027-28");
+            assert_eq!(competition.current_season.start_date, NaiveDate::from_ymd_opt(2027, 8, 1).unwrap());
+            assert!(competition.standings.iter().all(|standing| standing.points == 0));
+            assert!(competition.fixtures.iter().all(|fixture| fixture.status == MatchStatus::Scheduled));
+        }
+    }
+
+    #[test]
+    fn test_season_end_schedules_next_season() {
+        let (mut game_state, mut time_engine, _, _) = create_finished_season();
+        SeasonEngine::new().advance_season(&mut game_state, &mut time_engine);
+
+        let count = |wanted: fn(&ScheduledEventType) -> bool| {
+            time_engine.event_queue.iter().filter(|event| wanted(&event.event_type)).count()
+        };
+        assert_eq!(count(|event| matches!(event, ScheduledEventType::MatchDay(_))), 2);
+        assert_eq!(count(|event| matches!(event, ScheduledEventType::InternationalBreak)), 4);
+        assert_eq!(count(|event| matches!(event, ScheduledEventType::InternationalTournament)), 8);
+        assert_eq!(count(|event| matches!(event, ScheduledEventType::SeasonEnd)), 1);
+        let expiry = ScheduledEventType::ContractExpires(game_state.player.id);
+        assert!(time_engine.event_queue.iter().any(|event| event.event_type == expiry));
+    }
+
+    #[test]
+    fn test_season_end_settles_bonuses() {
+        let (mut game_state, mut time_engine, _, _) = create_finished_season();
+        game_state.player.contract.performance_bonuses.push(Bonus {
+            condition: BonusCondition::LeaguePosition(1),
+            amount: 25000.0,
+            achieved: false,
+        });
+        SeasonEngine::new().advance_season(&mut game_state, &mut time_engine);
+
+        // The title bonus is paid and the bonus starts over for the new season
+        assert!(game_state.player_finances.ledger.iter().any(|entry| entry.kind == LedgerEntryKind::Bonus && entry.amount == 25000.0));
+        assert!(!game_state.player.contract.performance_bonuses[0].achieved);
+    }
+
+    #[test]
+    fn test_season_end_records_net_worth() {
+        let (mut game_state, mut time_engine, _, _) = create_finished_season();
+        SeasonEngine::new().advance_season(&mut game_state, &mut time_engine);
+
+        assert_eq!(game_state.player_finances.net_worth_history.len(), 1);
+        assert_eq!(game_state.player_finances.net_worth_history[0].season, "2026-27");
+    }
+
+    #[test]
+    fn test_season_end_awards_prize_money() {
+        let (mut game_state, mut time_engine, club_id, rival_id) = create_finished_season();
+        let balance = |game_state: &GameState, id: Uuid| {
+            game_state.clubs.iter().find(|club| club.id == id).unwrap().finances.balance
+        };
+        let (club_before, rival_before) = (balance(&game_state, club_id), balance(&game_state, rival_id));
+        SeasonEngine::new().advance_season(&mut game_state, &mut time_engine);
+
+        let club_prize = balance(&game_state, club_id) - club_before;
+        let rival_prize = balance(&game_state, rival_id) - rival_before;
+        assert!(rival_prize > 0.0);
+        assert!(club_prize > rival_prize);
+    }
+
+    #[test]
+    fn test_season_end_pays_fee_add_ons() {
+        let (mut game_state, mut time_engine, club_id, rival_id) = create_finished_season();
+        game_state.transfer_system.transfer_records.push(TransferRecord {
+            player_id: game_state.player.id,
+            from_club_id: rival_id,
+            to_club_id: club_id,
+            date: NaiveDate::from_ymd_opt(2026, 7, 1).unwrap(),
+            fee: FeeStructure {
+                add_ons: vec![FeeAddOn { condition: AddOnCondition::Trophies(1), amount: 500000.0, paid: false }],
+                ..FeeStructure::upfront(2000000.0)
+            },
+            appearances: 0,
+            trophies: 0,
+        });
+        let rival_before = game_state.clubs.iter().find(|club| club.id == rival_id).unwrap().finances.balance;
+        SeasonEngine::new().advance_season(&mut game_state, &mut time_engine);
+
+        // Winning the league triggers the add-on, paid to the selling club on top of its prize money
+        let record = &game_state.transfer_system.transfer_records[0];
+        assert_eq!(record.trophies, 1);
+        assert!(record.fee.add_ons[0].paid);
+        let rival_after = game_state.clubs.iter().find(|club| club.id == rival_id).unwrap().finances.balance;
+        assert!(rival_after - rival_before > 500000.0);
+    }
+
+    /// A two-club league played to the end and won by the player's club
+    fn create_finished_season() -> (GameState, TimeEngine, Uuid, Uuid) {
+        let club_id = Uuid::new_v4();
+        let rival_id = Uuid::new_v4();
+
+        let mut player = create_test_player();
+        player.contract.club_id = club_id;
+        player.career_stats.current_season.appearances = 30;
+        player.career_stats.current_season.goals = 12;
+
+        let mut league = create_test_league(vec![club_id, rival_id]);
+        CompetitionEngine::new().initialize_season(&mut league);
+        for fixture in league.fixtures.iter_mut() {
+            fixture.status = MatchStatus::Finished;
+        }
+        league.standings[0].position = 1;
+        league.standings[0].points = 6;
+        league.standings[1].position = 2;
+
+        let mut game_state = GameState::new(player, club_id);
+        game_state.season.year = "2026-27".to_string();
+        game_state.season.start_date = Utc.with_ymd_and_hms(2026, 8, 1, 0, 0, 0).unwrap();
+        game_state.season.end_date = Utc.with_ymd_and_hms(2027, 5, 31, 0, 0, 0).unwrap();
+        game_state.current_date = game_state.season.end_date;
+        game_state.clubs = vec![Team { id: club_id, ..create_test_team() }, Team { id: rival_id, ..create_test_team() }];
+        game_state.leagues.push(league.clone());
+        game_state.competitions.push(league);
+
+        let time_engine = TimeEngine::new(game_state.season.end_date);
+        (game_state, time_engine, club_id, rival_id)
+    }
+
+    fn create_test_league(teams: Vec<Uuid>) -> Competition {
+        let start = NaiveDate::from_ymd_opt(2026, 8, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2027, 5, 31).unwrap();
+        Competition {
+            id: Uuid::new_v4(),
+            name: "Test League".to_string(),
+            country: "Test Country".to_string(),
+            level: 1,
+            teams,
+            fixtures: vec![],
+            standings: vec![],
+            competition_type: CompetitionType::League,
+            season_start: start,
+            season_end: end,
+            current_season: CurrentSeason {
+                year: "2026-27".to_string(),
+                is_active: true,
+                current_matchday: 1,
+                start_date: start,
+                end_date: end,
+            },
+            tiebreak_rules: TiebreakRule::goal_difference_first(),
+            player_stats: vec![],
+        }
+    }
+}
@@ -9,6 +9,12 @@ use uuid::Uuid;
 /// It influences morale, playing time, development, and career opportunities
 pub struct SocialEngine;
 
+impl Default for SocialEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl SocialEngine {
     /// Creates a new SocialEngine instance
     pub fn new() -> Self {
@@ -30,7 +36,7 @@ impl SocialEngine {
         let modified_change = self.apply_personality_modifiers(change, personality_factors);
         
         // Calculate new value with boundaries
-        let new_value = (current_value + modified_change).max(0.0).min(100.0);
+        let new_value = (current_value + modified_change).clamp(0.0, 100.0);
         
         // Update the relationship
         relationships.insert(other_entity_id, new_value);
@@ -0,0 +1,274 @@
+// src/systems/squad_system.rs
+use chrono::{Datelike, Months, NaiveDate};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::core::game_state::GameState;
+use crate::entities::{
+    CareerStats, Contract, Foot, HiddenAttributes, MentalAttributes, PhysicalAttributes, Player, Position, SeasonStats,
+    SquadRole, Team, TechnicalAttributes,
+};
+
+/// First names and surnames combined into generated players' names
+const PLAYER_FIRST_NAMES: [&str; 12] = ["James", "Luca", "Mateo", "Noah", "Kai", "Oscar", "Leon", "Hugo", "Ravi", "Tomás", "Finn", "Elias"];
+const PLAYER_SURNAMES: [&str; 12] = ["Walker", "Rossi", "García", "Müller", "Silva", "Dubois", "Jensen", "Kowalski", "Baker", "Santos", "Nowak", "Hughes"];
+/// The line-up every generated squad is built around; each position is filled twice
+const SQUAD_SHAPE: [Position; 11] = [
+    Position::GK,
+    Position::RB,
+    Position::CB,
+    Position::CB,
+    Position::LB,
+    Position::DM,
+    Position::CM,
+    Position::CM,
+    Position::RW,
+    Position::LW,
+    Position::CF,
+];
+/// Youngest and oldest players in a generated squad
+const MIN_SQUAD_AGE: u8 = 17;
+const MAX_SQUAD_AGE: u8 = 34;
+/// How far a starter's attributes sit below the club's reputation, and how much further the backups drop
+const STARTER_GAP: f32 = 5.0;
+const BACKUP_GAP: f32 = 12.0;
+/// Squad members ranked in each role, best first; the rest are backups or prospects
+const KEY_PLAYERS: usize = 4;
+const FIRST_TEAM_PLAYERS: usize = 7;
+const ROTATION_PLAYERS: usize = 5;
+/// Age up to which a player outside the first eleven counts as a prospect
+const PROSPECT_AGE: u8 = 20;
+
+/// The SquadEngine fills the game world with players
+/// Each club gets a squad pitched at its level, so the transfer market has players to trade
+pub struct SquadEngine;
+
+impl Default for SquadEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SquadEngine {
+    /// Creates a new SquadEngine instance
+    pub fn new() -> Self {
+        SquadEngine
+    }
+
+    /// Generates a squad for every club and adds the player character to their club's squad
+    ///
+    /// Any players already in the world are replaced.
+    pub fn populate_world(&self, game_state: &mut GameState, today: NaiveDate) {
+        let mut players = Vec::new();
+        for club in game_state.clubs.iter_mut() {
+            players.extend(self.generate_squad(club, today));
+        }
+        game_state.players = players;
+
+        let player = &game_state.player;
+        if let Some(club) = game_state.clubs.iter_mut().find(|club| club.id == player.contract.club_id) {
+            club.squad.push(player.id);
+        }
+    }
+
+    /// Generates two players for each position in the squad shape, pitched around the club's reputation
+    ///
+    /// Roles follow ability within the squad, and the club's squad list is replaced with the new players.
+    pub fn generate_squad(&self, club: &mut Team, today: NaiveDate) -> Vec<Player> {
+        let mut rng = rand::thread_rng();
+        let mut names: Vec<String> = PLAYER_FIRST_NAMES
+            .iter()
+            .flat_map(|first| PLAYER_SURNAMES.iter().map(move |surname| format!("{} {}", first, surname)))
+            .collect();
+        names.shuffle(&mut rng);
+
+        let mut squad: Vec<Player> = SQUAD_SHAPE
+            .iter()
+            .map(|position| (position, STARTER_GAP))
+            .chain(SQUAD_SHAPE.iter().map(|position| (position, BACKUP_GAP)))
+            .zip(names)
+            .map(|((position, gap), name)| {
+                let level = (club.reputation - gap + rng.gen_range(-6.0..=6.0)).clamp(30.0, 95.0);
+                let age = rng.gen_range(MIN_SQUAD_AGE..=MAX_SQUAD_AGE);
+                self.generate_player(name, *position, level, age, club, today)
+            })
+            .collect();
+
+        squad.sort_by(|a, b| b.ability_score().total_cmp(&a.ability_score()));
+        for (rank, player) in squad.iter_mut().enumerate() {
+            player.contract.squad_role = self.role_for_rank(rank, player.age);
+        }
+        club.squad = squad.iter().map(|player| player.id).collect();
+        squad
+    }
+
+    /// Where a player's ability ranks them in the squad
+    fn role_for_rank(&self, rank: usize, age: u8) -> SquadRole {
+        if rank < KEY_PLAYERS {
+            SquadRole::KeyPlayer
+        } else if rank < KEY_PLAYERS + FIRST_TEAM_PLAYERS {
+            SquadRole::FirstTeam
+        } else if age <= PROSPECT_AGE {
+            SquadRole::Prospect
+        } else if rank < KEY_PLAYERS + FIRST_TEAM_PLAYERS + ROTATION_PLAYERS {
+            SquadRole::Rotation
+        } else {
+            SquadRole::Backup
+        }
+    }
+
+    /// A player with attributes spread around `level` on a contract at the club
+    fn generate_player(&self, name: String, position: Position, level: f32, age: u8, club: &Team, today: NaiveDate) -> Player {
+        let mut rng = rand::thread_rng();
+        let mut attribute = || (level + rng.gen_range(-8.0..=8.0)).clamp(1.0, 99.0) as u8;
+        let technical = TechnicalAttributes {
+            dribbling: attribute(),
+            passing: attribute(),
+            shooting: attribute(),
+            first_touch: attribute(),
+            tackling: attribute(),
+            crossing: attribute(),
+        };
+        let physical = PhysicalAttributes {
+            pace: attribute(),
+            stamina: attribute(),
+            strength: attribute(),
+            agility: attribute(),
+            jumping: attribute(),
+        };
+        let mental = MentalAttributes {
+            composure: attribute(),
+            vision: attribute(),
+            work_rate: attribute(),
+            determination: attribute(),
+            positioning: attribute(),
+            teamwork: attribute(),
+        };
+
+        let length_years = rng.gen_range(1..=5);
+        let birth_date = NaiveDate::from_ymd_opt(today.year() - age as i32, rng.gen_range(1..=12), rng.gen_range(1..=28))
+            .unwrap_or(today);
+        Player {
+            id: Uuid::new_v4(),
+            name,
+            age,
+            birth_date,
+            nationality: self.nationality_for(&club.country).to_string(),
+            height: rng.gen_range(168..=195),
+            weight: rng.gen_range(62..=90),
+            preferred_foot: if rng.gen_bool(0.75) { Foot::Right } else { Foot::Left },
+            primary_position: position,
+            secondary_positions: vec![],
+            technical,
+            physical,
+            mental,
+            hidden: HiddenAttributes {
+                injury_proneness: rng.gen_range(5..=60),
+                consistency: rng.gen_range(30..=90),
+                big_match_temperament: rng.gen_range(30..=90),
+                professionalism: rng.gen_range(30..=95),
+                potential_ceiling: (level as u8).saturating_add(rng.gen_range(0..=25)).min(99),
+                versatility: rng.gen_range(20..=80),
+                ambition: rng.gen_range(20..=95),
+                loyalty: rng.gen_range(20..=95),
+                ego: rng.gen_range(10..=90),
+            },
+            fitness: 90.0,
+            fatigue: 10.0,
+            form: 6.5,
+            morale: 70.0,
+            sharpness: 75.0,
+            local_reputation: level * 0.6,
+            international_reputation: (level - 50.0).max(0.0),
+            contract: Contract {
+                club_id: club.id,
+                wage: level * level * 5.0,
+                length_years,
+                squad_role: SquadRole::Rotation,
+                release_clause: None,
+                performance_bonuses: vec![],
+                contract_end_date: today.checked_add_months(Months::new(12 * length_years as u32)).unwrap_or(today),
+                league_strength: club.reputation,
+                loan: None,
+            },
+            career_stats: CareerStats {
+                seasons_played: 0,
+                total_appearances: 0,
+                total_goals: 0,
+                total_assists: 0,
+                total_yellow_cards: 0,
+                total_red_cards: 0,
+                average_rating: 0.0,
+                highest_rating: 0.0,
+                season_stats: vec![],
+                awards: vec![],
+                trophies: vec![],
+                current_season: SeasonStats::default(),
+                international_caps: 0,
+                international_goals: 0,
+                first_call_up: None,
+                youth_season: SeasonStats::default(),
+                youth_history: vec![],
+                youth_form: vec![],
+                club_spells: vec![],
+            },
+            relationships: HashMap::new(),
+            injury_status: None,
+            form_history: vec![],
+            tutorial_state: HashMap::new(),
+        }
+    }
+
+    /// The nationality most of a country's players have
+    fn nationality_for(&self, country: &str) -> &'static str {
+        match country {
+            "England" => "English",
+            "Scotland" => "Scottish",
+            "Wales" => "Welsh",
+            "Spain" => "Spanish",
+            "Italy" => "Italian",
+            "Germany" => "German",
+            "France" => "French",
+            "Portugal" => "Portuguese",
+            "Netherlands" => "Dutch",
+            _ => "English",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{create_test_player, create_test_team, date};
+
+    #[test]
+    fn test_every_club_gets_a_squad_at_its_level() {
+        let engine = SquadEngine::new();
+        let player = create_test_player();
+        let mut home = create_test_team();
+        home.id = player.contract.club_id;
+        let mut minnow = create_test_team();
+        minnow.reputation = 50.0;
+        let mut game_state = GameState::new(player.clone(), home.id);
+        game_state.clubs = vec![home, minnow];
+
+        engine.populate_world(&mut game_state, date(2025, 7, 1));
+
+        assert_eq!(game_state.players.len(), 2 * SQUAD_SHAPE.len() * 2);
+        assert!(game_state.clubs[0].squad.contains(&player.id));
+        for club in &game_state.clubs {
+            let squad: Vec<&Player> = game_state.players.iter().filter(|other| other.contract.club_id == club.id).collect();
+            assert!(squad.iter().all(|other| club.squad.contains(&other.id)));
+            assert_eq!(squad.iter().filter(|other| matches!(other.primary_position, Position::GK)).count(), 2);
+            assert_eq!(squad.iter().filter(|other| matches!(other.contract.squad_role, SquadRole::KeyPlayer)).count(), KEY_PLAYERS);
+        }
+
+        let average = |club_id: Uuid| {
+            let squad: Vec<f32> = game_state.players.iter().filter(|other| other.contract.club_id == club_id).map(Player::ability_score).collect();
+            squad.iter().sum::<f32>() / squad.len() as f32
+        };
+        assert!(average(game_state.clubs[0].id) > average(game_state.clubs[1].id));
+    }
+}
@@ -0,0 +1,628 @@
+// src/systems/tournament_system.rs
+use chrono::{Datelike, NaiveDate};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::core::game_state::GameState;
+use crate::entities::{
+    Award, Competition, CompetitionType, CurrentSeason, InternationalFixture, InternationalMatchType,
+    InternationalTournament, MatchResult, NationalTeam, Player, PlayerCompetitionStats, TiebreakRule, TournamentKind,
+    Trophy
+};
+use crate::systems::international_system::CallUp;
+use crate::systems::{CompetitionEngine, InternationalEngine, ReputationEngine};
+
+/// Group matchdays at the summer finals as (month, day)
+const FINALS_GROUP_DAYS: [(u32, u32); 3] = [(6, 14), (6, 18), (6, 22)];
+/// Knockout rounds at the summer finals as (month, day), enough for 32 teams
+const FINALS_KNOCKOUT_DAYS: [(u32, u32); 5] = [(6, 27), (7, 1), (7, 5), (7, 9), (7, 13)];
+/// Teams in each finals group
+const FINALS_GROUP_SIZE: usize = 4;
+/// Largest field the finals can take
+const MAX_FINALISTS: usize = 32;
+/// Nations needed before a tournament is worth drawing, topped up with guests
+const MIN_TOURNAMENT_NATIONS: usize = 8;
+/// Pre-season training starts four weeks before the new season
+const PRE_SEASON_START: (u32, u32) = (7, 4);
+/// Rest players are given after their last tournament match
+const POST_TOURNAMENT_REST_DAYS: i64 = 14;
+/// Fatigue carried into the season for each day of pre-season missed
+const FATIGUE_PER_PRE_SEASON_DAY_LOST: f32 = 0.75;
+
+/// The TournamentEngine runs World Cups and continental championships
+/// Qualifying groups fill the international breaks before each summer finals of groups and knockouts
+pub struct TournamentEngine;
+
+impl Default for TournamentEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TournamentEngine {
+    /// Creates a new TournamentEngine instance
+    pub fn new() -> Self {
+        TournamentEngine
+    }
+
+    /// The tournament held in a summer, World Cups and continental championships alternate every two years
+    pub fn kind_for_year(&self, year: i32) -> Option<TournamentKind> {
+        match year.rem_euclid(4) {
+            2 => Some(TournamentKind::WorldCup),
+            0 => Some(TournamentKind::ContinentalChampionship),
+            _ => None,
+        }
+    }
+
+    /// Dates of every finals matchday in a tournament summer
+    pub fn finals_dates(&self, year: i32) -> Vec<NaiveDate> {
+        if self.kind_for_year(year).is_none() {
+            return Vec::new();
+        }
+
+        FINALS_GROUP_DAYS.iter()
+            .chain(FINALS_KNOCKOUT_DAYS.iter())
+            .filter_map(|(month, day)| NaiveDate::from_ymd_opt(year, *month, *day))
+            .collect()
+    }
+
+    /// Makes sure the next tournament has been drawn, fitting its qualifying groups into the remaining windows
+    pub fn ensure_tournaments(
+        &self,
+        tournaments: &mut Vec<InternationalTournament>,
+        national_teams: &mut Vec<NationalTeam>,
+        date: NaiveDate,
+    ) {
+        // Qualifying for a summer's finals runs until the end of May
+        let mut year = date.year() + date.year().rem_euclid(2);
+        if date > NaiveDate::from_ymd_opt(year, 5, 31).unwrap() {
+            year += 2;
+        }
+        if tournaments.iter().any(|tournament| tournament.year == year) {
+            return;
+        }
+        let Some(kind) = self.kind_for_year(year) else {
+            return;
+        };
+
+        InternationalEngine::new().invite_guest_nations(national_teams, MIN_TOURNAMENT_NATIONS);
+        let name = match kind {
+            TournamentKind::WorldCup => format!("World Cup {}", year),
+            TournamentKind::ContinentalChampionship => format!("Continental Championship {}", year),
+        };
+        let qualifying_start = date.max(NaiveDate::from_ymd_opt(year - 2, 8, 1).unwrap());
+        let qualifying_end = NaiveDate::from_ymd_opt(year, 5, 31).unwrap();
+        let teams: Vec<Uuid> = national_teams.iter().map(|team| team.id).collect();
+        let qualifying_groups = self.draw_qualifying_groups(&name, &teams, qualifying_start, qualifying_end);
+
+        // Without any windows left to play in, everyone goes through
+        let qualified = if qualifying_groups.is_empty() {
+            teams.into_iter().take(MAX_FINALISTS).collect()
+        } else {
+            Vec::new()
+        };
+
+        tournaments.push(InternationalTournament {
+            id: Uuid::new_v4(),
+            kind,
+            name,
+            year,
+            qualifying_groups,
+            qualified,
+            finals_groups: Vec::new(),
+            knockout: Vec::new(),
+            remaining: Vec::new(),
+            player_stats: Vec::new(),
+            winner: None,
+        });
+    }
+
+    /// Plays the tournament qualifiers scheduled on a date
+    pub fn play_qualifiers(
+        &self,
+        tournaments: &mut [InternationalTournament],
+        national_teams: &[NationalTeam],
+        players: &mut [&mut Player],
+        date: NaiveDate,
+    ) -> Vec<InternationalFixture> {
+        let mut results = Vec::new();
+        for tournament in tournaments.iter_mut().filter(|tournament| tournament.winner.is_none()) {
+            for group in tournament.qualifying_groups.iter_mut() {
+                results.extend(self.play_group_fixtures(group, national_teams, players, date, InternationalMatchType::Qualifier).0);
+            }
+        }
+
+        results
+    }
+
+    /// Plays a day of a summer finals, drawing the groups and knockout rounds as they come up
+    pub fn play_finals_day(
+        &self,
+        tournaments: &mut [InternationalTournament],
+        national_teams: &mut [NationalTeam],
+        players: &mut [&mut Player],
+        date: NaiveDate,
+    ) -> Option<TournamentDayReport> {
+        let dates = self.finals_dates(date.year());
+        let day = dates.iter().position(|finals_date| *finals_date == date)?;
+        let tournament = tournaments.iter_mut()
+            .find(|tournament| tournament.year == date.year() && tournament.winner.is_none())?;
+
+        let mut report = TournamentDayReport {
+            tournament: tournament.name.clone(),
+            ..TournamentDayReport::default()
+        };
+
+        if day < FINALS_GROUP_DAYS.len() {
+            if tournament.finals_groups.is_empty() {
+                report.call_ups = self.draw_finals(tournament, national_teams, players, &dates);
+            }
+
+            for group in tournament.finals_groups.iter_mut() {
+                let (fixtures, appearances) = self.play_group_fixtures(
+                    group,
+                    national_teams,
+                    players,
+                    date,
+                    InternationalMatchType::Tournament,
+                );
+                self.record_appearances(&mut tournament.player_stats, &appearances, players, national_teams);
+                report.fixtures.extend(fixtures);
+            }
+        } else {
+            if tournament.remaining.is_empty() && tournament.knockout.is_empty() {
+                tournament.remaining = self.group_qualifiers(&tournament.finals_groups);
+            }
+            report.fixtures = self.play_knockout_round(tournament, national_teams, players, date);
+
+            if tournament.remaining.len() == 1 {
+                tournament.winner = Some(tournament.remaining[0]);
+                report.champion = tournament.winner;
+                report.golden_boot = self.finish_tournament(tournament, national_teams, players);
+            }
+        }
+
+        Some(report)
+    }
+
+    /// Plays the finals day across the whole game world
+    pub fn play_finals_day_for_world(&self, game_state: &mut GameState, date: NaiveDate) -> Option<TournamentDayReport> {
+        let mut players: Vec<&mut Player> = std::iter::once(&mut game_state.player)
+            .chain(game_state.players.iter_mut())
+            .collect();
+        let report = self.play_finals_day(
+            &mut game_state.international_tournaments,
+            &mut game_state.national_teams,
+            &mut players,
+            date,
+        )?;
+        game_state.international_fixtures.extend(report.fixtures.iter().cloned());
+        Some(report)
+    }
+
+    /// Days of pre-season lost by a player whose last tournament match was on `last_match`
+    pub fn pre_season_days_lost(&self, last_match: NaiveDate) -> i64 {
+        let (month, day) = PRE_SEASON_START;
+        let pre_season = NaiveDate::from_ymd_opt(last_match.year(), month, day).unwrap();
+        let back_in_training = last_match + chrono::Duration::days(POST_TOURNAMENT_REST_DAYS);
+
+        (back_in_training - pre_season).num_days().max(0)
+    }
+
+    /// Splits the nations into qualifying groups whose fixtures fall on the qualifier dates
+    fn draw_qualifying_groups(
+        &self,
+        name: &str,
+        teams: &[Uuid],
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Vec<Competition> {
+        let international = InternationalEngine::new();
+        let dates: Vec<NaiveDate> = international.international_break_dates(start, end)
+            .into_iter()
+            .filter(|date| international.match_type_for(*date) == InternationalMatchType::Qualifier)
+            .flat_map(|date| [date, date + chrono::Duration::days(3)])
+            .filter(|date| *date >= start)
+            .collect();
+
+        // Bigger groups when there is time to play them, double round robin needs the extra matchdays
+        let Some(group_size) = [5, 4, 3, 2].into_iter().find(|size| self.matchdays(*size, true) <= dates.len()) else {
+            return Vec::new();
+        };
+
+        let mut shuffled = teams.to_vec();
+        shuffled.shuffle(&mut rand::thread_rng());
+        self.split_into_groups(&shuffled, group_size)
+            .into_iter()
+            .enumerate()
+            .map(|(i, group)| self.create_group(format!("{} Qualifying Group {}", name, group_letter(i)), group, &dates, true))
+            .collect()
+    }
+
+    /// Settles who qualified, calls up their squads and draws the finals groups
+    fn draw_finals(
+        &self,
+        tournament: &mut InternationalTournament,
+        national_teams: &mut [NationalTeam],
+        players: &mut [&mut Player],
+        dates: &[NaiveDate],
+    ) -> Vec<CallUp> {
+        if tournament.qualified.is_empty() {
+            tournament.qualified = self.group_qualifiers(&tournament.qualifying_groups);
+            tournament.qualified.truncate(MAX_FINALISTS);
+        }
+
+        let finalists: Vec<&mut NationalTeam> = national_teams.iter_mut()
+            .filter(|team| tournament.qualified.contains(&team.id))
+            .collect();
+        let call_ups = InternationalEngine::new().call_up_squads(finalists, players, dates[0]);
+
+        // Qualifiers are already in seeding order, so each group gets one from every pot
+        let group_dates = &dates[..FINALS_GROUP_DAYS.len()];
+        tournament.finals_groups = self.split_into_groups(&tournament.qualified, FINALS_GROUP_SIZE)
+            .into_iter()
+            .enumerate()
+            .map(|(i, group)| self.create_group(format!("{} Group {}", tournament.name, group_letter(i)), group, group_dates, false))
+            .collect();
+
+        call_ups
+    }
+
+    /// Plays the next knockout round, with the top seed given a bye when the numbers are odd
+    fn play_knockout_round(
+        &self,
+        tournament: &mut InternationalTournament,
+        national_teams: &[NationalTeam],
+        players: &mut [&mut Player],
+        date: NaiveDate,
+    ) -> Vec<InternationalFixture> {
+        let international = InternationalEngine::new();
+        let mut rng = rand::thread_rng();
+        let teams = std::mem::take(&mut tournament.remaining);
+        let byes = teams.len() % 2;
+        let mut next_round: Vec<Uuid> = teams[..byes].to_vec();
+        let mut fixtures = Vec::new();
+
+        let drawn = &teams[byes..];
+        for i in 0..drawn.len() / 2 {
+            let (Some(home), Some(away)) = (
+                national_teams.iter().find(|team| team.id == drawn[i]),
+                national_teams.iter().find(|team| team.id == drawn[drawn.len() - 1 - i]),
+            ) else {
+                continue;
+            };
+
+            let mut played = international.play_match(home, away, players, date, InternationalMatchType::Tournament);
+            self.record_appearances(&mut tournament.player_stats, &played.appearances, players, national_teams);
+
+            // Level after extra time goes to penalties, where the stronger side has the edge
+            if let Some(result) = played.fixture.result.as_mut() {
+                if result.winner.is_none() {
+                    let home_chance = played.home_strength / (played.home_strength + played.away_strength).max(1.0);
+                    result.winner = Some(if rng.gen::<f32>() < home_chance { home.id } else { away.id });
+                }
+                next_round.extend(result.winner);
+            }
+            fixtures.push(played.fixture);
+        }
+
+        tournament.knockout.extend(fixtures.iter().cloned());
+        tournament.remaining = next_round;
+        fixtures
+    }
+
+    /// Hands out the trophy and Golden Boot and docks pre-season from everyone who played
+    /// Returns the Golden Boot winner
+    fn finish_tournament(
+        &self,
+        tournament: &InternationalTournament,
+        national_teams: &[NationalTeam],
+        players: &mut [&mut Player],
+    ) -> Option<Uuid> {
+        let reputation = ReputationEngine::new();
+        let season = tournament.year.to_string();
+
+        if let Some(champions) = national_teams.iter().find(|team| Some(team.id) == tournament.winner) {
+            for player in players.iter_mut().filter(|player| champions.squad.contains(&player.id)) {
+                player.career_stats.trophies.push(Trophy {
+                    name: tournament.name.clone(),
+                    season: season.clone(),
+                    competition: tournament.name.clone(),
+                });
+                reputation.apply_award(player, "international tournament winner");
+            }
+        }
+
+        // Most goals wins, fewest games played breaks a tie
+        let golden_boot = tournament.player_stats.iter()
+            .filter(|stats| stats.goals > 0)
+            .max_by(|a, b| a.goals.cmp(&b.goals).then(b.appearances.cmp(&a.appearances)))
+            .map(|stats| stats.player_id);
+        if let Some(player) = players.iter_mut().find(|player| Some(player.id) == golden_boot) {
+            player.career_stats.awards.push(Award {
+                name: "Golden Boot".to_string(),
+                season: season.clone(),
+                competition: tournament.name.clone(),
+            });
+            reputation.apply_award(player, "golden boot");
+        }
+
+        for stats in tournament.player_stats.iter() {
+            let Some(last_match) = self.last_match_date(tournament, stats.team_id) else {
+                continue;
+            };
+            let days_lost = self.pre_season_days_lost(last_match);
+            if let Some(player) = players.iter_mut().find(|player| player.id == stats.player_id) {
+                player.fatigue = (player.fatigue + days_lost as f32 * FATIGUE_PER_PRE_SEASON_DAY_LOST).clamp(0.0, 100.0);
+            }
+        }
+
+        golden_boot
+    }
+
+    /// Plays every fixture in a group scheduled on a date and records the results in its table
+    fn play_group_fixtures(
+        &self,
+        group: &mut Competition,
+        national_teams: &[NationalTeam],
+        players: &mut [&mut Player],
+        date: NaiveDate,
+        match_type: InternationalMatchType,
+    ) -> (Vec<InternationalFixture>, Vec<(Uuid, u32)>) {
+        let international = InternationalEngine::new();
+        let competition = CompetitionEngine::new();
+        let mut results = Vec::new();
+        let mut appearances = Vec::new();
+
+        let due: Vec<(Uuid, Uuid, Uuid)> = group.fixtures.iter()
+            .filter(|fixture| fixture.scheduled_date == date && fixture.result.is_none())
+            .map(|fixture| (fixture.id, fixture.home_team, fixture.away_team))
+            .collect();
+        for (fixture_id, home_id, away_id) in due {
+            let (Some(home), Some(away)) = (
+                national_teams.iter().find(|team| team.id == home_id),
+                national_teams.iter().find(|team| team.id == away_id),
+            ) else {
+                continue;
+            };
+
+            let mut played = international.play_match(home, away, players, date, match_type);
+            played.fixture.id = fixture_id;
+            if let Some(MatchResult { home_score, away_score, .. }) = played.fixture.result {
+                competition.record_result(group, fixture_id, home_score, away_score);
+            }
+            appearances.extend(played.appearances);
+            results.push(played.fixture);
+        }
+
+        (results, appearances)
+    }
+
+    /// Adds starters' appearances and goals to the tournament's player totals
+    fn record_appearances(
+        &self,
+        player_stats: &mut Vec<PlayerCompetitionStats>,
+        appearances: &[(Uuid, u32)],
+        players: &[&mut Player],
+        national_teams: &[NationalTeam],
+    ) {
+        for (player_id, goals) in appearances {
+            let Some(team_id) = players.iter()
+                .find(|player| player.id == *player_id)
+                .and_then(|player| national_teams.iter().find(|team| team.nationality == player.nationality))
+                .map(|team| team.id)
+            else {
+                continue;
+            };
+
+            let index = match player_stats.iter().position(|stats| stats.player_id == *player_id) {
+                Some(index) => index,
+                None => {
+                    player_stats.push(PlayerCompetitionStats {
+                        player_id: *player_id,
+                        team_id,
+                        ..PlayerCompetitionStats::default()
+                    });
+                    player_stats.len() - 1
+                }
+            };
+            player_stats[index].appearances += 1;
+            player_stats[index].goals += goals;
+        }
+    }
+
+    /// Group winners in order of points, followed by the runners-up
+    fn group_qualifiers(&self, groups: &[Competition]) -> Vec<Uuid> {
+        let mut qualified = Vec::new();
+        for place in 0..2 {
+            let mut finishers: Vec<_> = groups.iter()
+                .filter_map(|group| group.standings.get(place))
+                .collect();
+            finishers.sort_by_key(|standing| std::cmp::Reverse((standing.points, standing.goal_difference)));
+            qualified.extend(finishers.iter().map(|standing| standing.team_id));
+        }
+
+        qualified
+    }
+
+    /// Date of a team's last match at the finals
+    pub fn last_match_date(&self, tournament: &InternationalTournament, team_id: Uuid) -> Option<NaiveDate> {
+        let group_matches = tournament.finals_groups.iter()
+            .flat_map(|group| group.fixtures.iter())
+            .filter(|fixture| fixture.result.is_some() && (fixture.home_team == team_id || fixture.away_team == team_id))
+            .map(|fixture| fixture.scheduled_date);
+        let knockout_matches = tournament.knockout.iter()
+            .filter(|fixture| fixture.home_team == team_id || fixture.away_team == team_id)
+            .map(|fixture| fixture.date);
+
+        group_matches.chain(knockout_matches).max()
+    }
+
+    /// Deals teams into groups of at most `size`, taking one team from each pot in turn
+    fn split_into_groups(&self, teams: &[Uuid], size: usize) -> Vec<Vec<Uuid>> {
+        let group_count = teams.len().div_ceil(size).max(1);
+        let mut groups = vec![Vec::new(); group_count];
+        for (i, team) in teams.iter().enumerate() {
+            groups[i % group_count].push(*team);
+        }
+
+        groups.retain(|group| group.len() >= 2);
+        groups
+    }
+
+    /// Creates a group table with its fixtures on the given dates, one matchday per date
+    fn create_group(&self, name: String, teams: Vec<Uuid>, dates: &[NaiveDate], double_round_robin: bool) -> Competition {
+        let matchdays = self.matchdays(teams.len(), double_round_robin);
+        let mut group = Competition {
+            id: Uuid::new_v4(),
+            name,
+            country: "International".to_string(),
+            level: 1,
+            teams,
+            fixtures: Vec::new(),
+            standings: Vec::new(),
+            competition_type: CompetitionType::League,
+            season_start: dates[0],
+            season_end: dates[matchdays - 1],
+            current_season: CurrentSeason {
+                year: String::new(),
+                is_active: false,
+                current_matchday: 1,
+                start_date: dates[0],
+                end_date: dates[matchdays - 1],
+            },
+            tiebreak_rules: TiebreakRule::goal_difference_first(),
+            player_stats: Vec::new(),
+        };
+
+        CompetitionEngine::new().initialize_season(&mut group);
+        group.fixtures.retain(|fixture| fixture.matchday as usize <= matchdays);
+        for fixture in group.fixtures.iter_mut() {
+            fixture.scheduled_date = dates[fixture.matchday as usize - 1];
+        }
+
+        group
+    }
+
+    /// Matchdays needed for a round robin, odd-sized groups include a bye round
+    fn matchdays(&self, teams: usize, double_round_robin: bool) -> usize {
+        let rounds = teams - 1 + teams % 2;
+        if double_round_robin { rounds * 2 } else { rounds }
+    }
+}
+
+/// Letter used to name the nth group
+fn group_letter(index: usize) -> char {
+    (b'A' + (index % 26) as u8) as char
+}
+
+/// Everything that happened on a day of a tournament finals
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TournamentDayReport {
+    pub tournament: String,
+    pub call_ups: Vec<CallUp>,
+    pub fixtures: Vec<InternationalFixture>,
+    pub champion: Option<Uuid>,
+    pub golden_boot: Option<Uuid>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::Position;
+    use crate::test_support::create_test_player;
+
+    #[test]
+    fn test_tournament_calendar() {
+        let engine = TournamentEngine::new();
+        assert_eq!(engine.kind_for_year(2026), Some(TournamentKind::WorldCup));
+        assert_eq!(engine.kind_for_year(2028), Some(TournamentKind::ContinentalChampionship));
+        assert_eq!(engine.kind_for_year(2027), None);
+        assert!(engine.finals_dates(2027).is_empty());
+        assert_eq!(engine.finals_dates(2030).len(), 8);
+
+        // Reaching the final eats well into pre-season, an early exit doesn't
+        assert_eq!(engine.pre_season_days_lost(NaiveDate::from_ymd_opt(2030, 7, 13).unwrap()), 23);
+        assert_eq!(engine.pre_season_days_lost(NaiveDate::from_ymd_opt(2030, 6, 18).unwrap()), 0);
+    }
+
+    #[test]
+    fn test_qualifying_and_finals() {
+        let engine = TournamentEngine::new();
+        let international = InternationalEngine::new();
+        let mut squad: Vec<Player> = ["A", "B", "C", "D", "E", "F", "G", "H"].iter()
+            .map(|nation| create_player(nation))
+            .collect();
+        let mut national_teams = Vec::new();
+        international.generate_national_teams(&mut national_teams, &squad.iter().collect::<Vec<_>>());
+
+        let mut tournaments = Vec::new();
+        engine.ensure_tournaments(&mut tournaments, &mut national_teams, NaiveDate::from_ymd_opt(2026, 9, 1).unwrap());
+        engine.ensure_tournaments(&mut tournaments, &mut national_teams, NaiveDate::from_ymd_opt(2027, 3, 20).unwrap());
+        assert_eq!(tournaments.len(), 1);
+        assert_eq!(tournaments[0].kind, TournamentKind::ContinentalChampionship);
+        assert_eq!(tournaments[0].name, "Continental Championship 2028");
+        assert_eq!(national_teams.len(), 8);
+
+        // Qualifiers are spread over the windows of the two seasons before the finals
+        let groups = &tournaments[0].qualifying_groups;
+        assert_eq!(groups.len(), 2);
+        let mut qualifier_dates: Vec<NaiveDate> = groups.iter()
+            .flat_map(|group| group.fixtures.iter().map(|fixture| fixture.scheduled_date))
+            .collect();
+        qualifier_dates.sort();
+        qualifier_dates.dedup();
+        assert!(qualifier_dates.iter().all(|date| {
+            international.match_type_for(*date) == InternationalMatchType::Qualifier && *date < NaiveDate::from_ymd_opt(2028, 6, 1).unwrap()
+        }));
+
+        let mut players: Vec<&mut Player> = squad.iter_mut().collect();
+        for date in qualifier_dates {
+            international.call_up_squads(national_teams.iter_mut().collect(), &mut players, date);
+            engine.play_qualifiers(&mut tournaments, &national_teams, &mut players, date);
+        }
+        assert!(tournaments[0].qualifying_groups.iter()
+            .all(|group| group.fixtures.iter().all(|fixture| fixture.result.is_some())));
+
+        let mut champion = None;
+        for date in engine.finals_dates(2028) {
+            if let Some(report) = engine.play_finals_day(&mut tournaments, &mut national_teams, &mut players, date) {
+                champion = champion.or(report.champion);
+            }
+        }
+
+        // The top two from each qualifying group make one finals group, whose top two contest the final
+        let tournament = &tournaments[0];
+        assert_eq!(tournament.qualified.len(), 4);
+        assert_eq!(tournament.finals_groups.len(), 1);
+        assert_eq!(tournament.knockout.len(), 1);
+        assert!(champion.is_some());
+        assert_eq!(tournament.winner, champion);
+        assert!(tournament.qualified.contains(&champion.unwrap()));
+
+        let finalist_players: Vec<&Player> = squad.iter()
+            .filter(|player| tournament.player_stats.iter().any(|stats| stats.player_id == player.id))
+            .collect();
+        assert_eq!(finalist_players.len(), 4);
+        for player in finalist_players {
+            assert!(player.fatigue > 10.0);
+            let won = national_teams.iter()
+                .any(|team| Some(team.id) == champion && team.nationality == player.nationality);
+            assert_eq!(player.career_stats.trophies.len(), if won { 1 } else { 0 });
+        }
+    }
+
+    fn create_player(nationality: &str) -> Player {
+        let mut player = create_test_player();
+        player.id = Uuid::new_v4();
+        player.nationality = nationality.to_string();
+        player.age = 25;
+        player.primary_position = Position::CF;
+        player.international_reputation = 70.0;
+        player.local_reputation = 70.0;
+        player.career_stats.trophies = vec![];
+        player
+    }
+}
@@ -9,6 +9,12 @@ use crate::entities::Player;
 /// and generates morale effects based on alignment
 pub struct TrainingSystem;
 
+impl Default for TrainingSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl TrainingSystem {
     /// Creates a new TrainingSystem instance
     pub fn new() -> Self {
@@ -78,13 +84,13 @@ impl TrainingSystem {
 
     /// Checks if two training focuses are complementary
     fn are_complementary_focuses(&self, focus1: TrainingFocus, focus2: TrainingFocus) -> bool {
-        match (focus1, focus2) {
-            (TrainingFocus::Technical, TrainingFocus::Tactical) => true,
-            (TrainingFocus::Tactical, TrainingFocus::Technical) => true,
-            (TrainingFocus::Physical, TrainingFocus::Mental) => true,
-            (TrainingFocus::Mental, TrainingFocus::Physical) => true,
-            _ => false,
-        }
+        matches!(
+            (focus1, focus2),
+            (TrainingFocus::Technical, TrainingFocus::Tactical)
+                | (TrainingFocus::Tactical, TrainingFocus::Technical)
+                | (TrainingFocus::Physical, TrainingFocus::Mental)
+                | (TrainingFocus::Mental, TrainingFocus::Physical)
+        )
     }
 
     /// Calculates overall training effectiveness
@@ -281,7 +287,7 @@ impl TrainingSystem {
 
     /// Caps an attribute value between 1 and 100
     fn cap_attribute(&self, value: f32) -> f32 {
-        value.max(1.0).min(100.0)
+        value.clamp(1.0, 100.0)
     }
 
     /// Gets technical attribute distribution based on position
@@ -507,8 +513,7 @@ struct PhysicalAttributeDistribution {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::entities::{Player, Position, Foot, CareerStats, Contract, SquadRole, HiddenAttributes};
-    use chrono::NaiveDate;
+    use crate::entities::HiddenAttributes;
 
     #[test]
     fn test_focus_alignment() {
@@ -3,14 +3,44 @@ use serde::{Deserialize, Serialize};
 // Transfer system cleanup
 // Removed unused HashMap
 use uuid::Uuid;
-use chrono::Datelike;
+use chrono::{Months, NaiveDate, NaiveTime};
 
-use crate::entities::{Player, Team, Contract};
+use crate::core::game_state::{
+    CareerPriorities, FeeStructure, NegotiatingParty, NegotiationResult, NegotiationRound, PlayerResponse as NegotiationResponse,
+    TransferNegotiation, TransferSystemState, TransferWindows,
+};
+use crate::entities::{Bonus, BonusCondition, Player, Position, SquadRole, Team, Contract, ContractTerms};
+use crate::systems::social_system::PersonalityFactors;
+use crate::systems::{ClubFinanceEngine, FeeEngine, PriorityEngine, SocialEngine, ValuationEngine};
+
+/// Minimum positional need before a club registers interest at all
+pub const MIN_POSITIONAL_NEED: f32 = 10.0;
+
+/// Age from which a squad member counts towards an ageing position group
+pub const VETERAN_AGE: u8 = 31;
+
+/// Days ahead within which an expiring contract creates a gap to fill
+pub const CONTRACT_EXPIRY_WINDOW_DAYS: i64 = 365;
+
+/// Counter-offers each side may make before talks break down
+pub const MAX_NEGOTIATION_ROUNDS: u8 = 5;
+
+/// Bids a buying club will make before giving up on a player
+pub const MAX_BID_ROUNDS: u8 = 3;
+
+/// Relationship damage with the club when a player pushes for a move
+const TRANSFER_REQUEST_RELATIONSHIP_PENALTY: f32 = 15.0;
 
 /// The TransferEngine manages transfer interest, offers, and negotiations
 /// It generates transfer interest based on player performance and club needs
 pub struct TransferEngine;
 
+impl Default for TransferEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl TransferEngine {
     /// Creates a new TransferEngine instance
     pub fn new() -> Self {
@@ -18,43 +48,74 @@ impl TransferEngine {
     }
 
     /// Evaluates all clubs to see if they have interest in a player
+    ///
+    /// Each club's squad is resolved from `all_players` so interest reflects real
+    /// gaps in that squad rather than a fixed value per position.
     pub fn evaluate_transfer_interest(
         &self,
         player: &Player,
         all_teams: &[Team],
+        all_players: &[Player],
         current_club_id: Uuid,
+        today: NaiveDate,
     ) -> Vec<TransferInterest> {
-        let mut interests = Vec::new();
+        all_teams
+            .iter()
+            .filter(|team| team.id != current_club_id)  // Skip current club
+            .filter_map(|team| self.evaluate_club_interest(player, team, all_players, 1.0, today))
+            .collect()
+    }
+
+    /// Evaluates one club's interest in a player
+    ///
+    /// `boost` scales the interest score, e.g. when an agent talks the player up.
+    /// Returns `None` when the club has no real positional gap or too little interest.
+    pub fn evaluate_club_interest(
+        &self,
+        player: &Player,
+        team: &Team,
+        all_players: &[Player],
+        boost: f32,
+        today: NaiveDate,
+    ) -> Option<TransferInterest> {
+        let squad = self.squad_members(team, all_players, player.id);
+        let need = self.analyze_positional_need(player, team, &squad, today);
         
-        for team in all_teams {
-            // Skip current club
-            if team.id == current_club_id {
-                continue;
-            }
-            
-            // Calculate interest score
-            let interest_score = self.calculate_transfer_interest_score(player, team);
-            
-            // Only add if interest is above threshold
-            if interest_score > 30.0 {
-                let interest_level = self.determine_interest_level(interest_score);
-                
-                interests.push(TransferInterest {
-                    club_id: team.id,
-                    interest_level,
-                    last_evaluation_date: chrono::Utc::now(),
-                    evaluation_score: interest_score,
-                });
-            }
+        // Clubs without a real gap in the player's position don't bid
+        if need.need < MIN_POSITIONAL_NEED {
+            return None;
         }
         
-        interests
+        // Calculate interest score
+        let interest_score = self.calculate_transfer_interest_score(player, team, &need) * boost;
+        
+        // Only add if interest is above threshold
+        if interest_score <= 30.0 {
+            return None;
+        }
+        
+        Some(TransferInterest {
+            club_id: team.id,
+            interest_level: self.determine_interest_level(interest_score),
+            last_evaluation_date: today.and_time(NaiveTime::MIN).and_utc(),
+            evaluation_score: interest_score,
+            positional_need: need.need,
+            reasons: need.reasons,
+        })
+    }
+
+    /// Resolves a team's squad list to players, leaving out the target player
+    pub fn squad_members<'a>(&self, team: &Team, all_players: &'a [Player], exclude: Uuid) -> Vec<&'a Player> {
+        all_players
+            .iter()
+            .filter(|p| p.id != exclude && team.squad.contains(&p.id))
+            .collect()
     }
 
     /// Calculates the transfer interest score for a club in a player
-    fn calculate_transfer_interest_score(&self, player: &Player, team: &Team) -> f32 {
+    fn calculate_transfer_interest_score(&self, player: &Player, team: &Team, need: &PositionalNeed) -> f32 {
         // Base score from player attributes
-        let ability_score = self.calculate_player_ability_score(player);
+        let ability_score = player.ability_score();
         let potential_score = (player.hidden.potential_ceiling as f32) / 2.0;  // 0-50 scale
         let form_score = player.form * 0.5;  // 0-50 scale
         let reputation_score = player.international_reputation * 0.7;  // 0-70 scale (international matters more)
@@ -62,9 +123,6 @@ impl TransferEngine {
         // Age factor (younger players more attractive)
         let age_factor = self.calculate_age_factor(player.age);
         
-        // Positional need factor
-        let positional_need = self.calculate_positional_need(player.primary_position, team);
-        
         // Financial capacity factor
         let financial_factor = self.calculate_financial_capacity(team);
         
@@ -74,28 +132,12 @@ impl TransferEngine {
         // Apply modifiers
         interest_score *= age_factor;
         interest_score *= 1.0 + (team.reputation / 200.0);  // Higher club reputation increases interest
-        interest_score *= 1.0 + (positional_need / 100.0);  // Positional need increases interest
+        interest_score *= 0.5 + (need.need / 100.0);  // No gap halves interest, a glaring one raises it
         interest_score *= financial_factor;
         
         interest_score
     }
 
-    /// Calculates a player's overall ability score for transfer purposes
-    fn calculate_player_ability_score(&self, player: &Player) -> f32 {
-        // Weighted average of attributes
-        let technical_weight = 0.35;
-        let physical_weight = 0.25;
-        let mental_weight = 0.40;
-        
-        let technical_avg = player.technical.average();
-        let physical_avg = player.physical.average();
-        let mental_avg = player.mental.average();
-        
-        (technical_avg * technical_weight) + 
-        (physical_avg * physical_weight) + 
-        (mental_avg * mental_weight)
-    }
-
     /// Calculates age factor for transfer interest
     fn calculate_age_factor(&self, age: u8) -> f32 {
         match age {
@@ -108,25 +150,105 @@ impl TransferEngine {
         }
     }
 
-    /// Calculates how much a team needs a specific position
-    fn calculate_positional_need(&self, position: crate::entities::Position, _team: &Team) -> f32 {
-        // This is a simplified version - in a real implementation, 
-        // this would analyze the team's current squad composition
-        // and determine gaps in positions
+    /// Analyzes a club's squad to work out how much it needs a player in their position
+    ///
+    /// Looks at depth, the age of the current options, how the starters compare with
+    /// the player, and contracts expiring within a year. Need is 0-100 and comes with
+    /// the reasons behind it.
+    pub fn analyze_positional_need(
+        &self,
+        player: &Player,
+        team: &Team,
+        squad: &[&Player],
+        today: NaiveDate,
+    ) -> PositionalNeed {
+        let group = PositionGroup::for_position(player.primary_position);
+        let ideal = group.ideal_depth() as f32;
+        let mut need = 0.0;
+        let mut reasons = Vec::new();
         
-        // For now, we'll return a base value based on position importance
-        match position {
-            crate::entities::Position::GK => 10.0,  // Goalkeepers are important
-            crate::entities::Position::CB => 15.0,  // Defense is important
-            crate::entities::Position::FB => 12.0,  // Fullbacks are important
-            crate::entities::Position::DM => 14.0,  // Defensive midfielders are important
-            crate::entities::Position::CM => 16.0,  // Central midfielders are very important
-            crate::entities::Position::RM | crate::entities::Position::LM => 13.0,  // Wide midfielders
-            crate::entities::Position::RW | crate::entities::Position::LW => 15.0,  // Wingers are important
-            crate::entities::Position::CF | crate::entities::Position::SS => 18.0,  // Forwards are very important
-            crate::entities::Position::RB => 12.0,  // Right back important
-            crate::entities::Position::LB => 12.0,  // Left back important
-            crate::entities::Position::AM => 16.0,  // Attacking midfielder very important
+        // Recognised options: natural players count fully, those who can cover count half
+        let naturals: Vec<&Player> = squad
+            .iter()
+            .copied()
+            .filter(|p| PositionGroup::for_position(p.primary_position) == group)
+            .collect();
+        let cover = squad
+            .iter()
+            .filter(|p| PositionGroup::for_position(p.primary_position) != group)
+            .filter(|p| p.secondary_positions.iter().any(|pos| PositionGroup::for_position(*pos) == group))
+            .count();
+        let depth = naturals.len() as f32 + cover as f32 * 0.5;
+        
+        // Depth
+        let shortfall = (ideal - depth).max(0.0);
+        if shortfall > 0.0 {
+            need += shortfall / ideal * 30.0;
+            reasons.push(format!(
+                "{} has only {:.1} of {} {} options",
+                team.name, depth, group.ideal_depth(), group.label()
+            ));
+        }
+        
+        // Starters' quality versus the player
+        let mut abilities: Vec<f32> = naturals.iter().map(|p| p.ability_score()).collect();
+        abilities.sort_by(|a, b| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal));
+        abilities.truncate(group.starters());
+        let starter_quality = if abilities.is_empty() {
+            None
+        } else {
+            Some(abilities.iter().sum::<f32>() / abilities.len() as f32)
+        };
+        let player_ability = player.ability_score();
+        match starter_quality {
+            None => {
+                need += 30.0;
+                reasons.push(format!("{} has no recognised {}", team.name, group.label()));
+            }
+            Some(quality) => {
+                let gap = player_ability - quality;
+                if gap > 0.0 {
+                    need += (gap * 2.0).min(30.0);
+                    reasons.push(format!(
+                        "Would improve on the current starters ({:.0} vs {:.0})",
+                        player_ability, quality
+                    ));
+                } else {
+                    // Better starters already in place make a signing a luxury
+                    need -= (-gap).min(20.0);
+                }
+            }
+        }
+        
+        // Age curve
+        let veterans = naturals.iter().filter(|p| p.age >= VETERAN_AGE).count();
+        if veterans > 0 {
+            need += (veterans as f32 / ideal * 15.0).min(15.0);
+            reasons.push(format!(
+                "{} of the {} are aged {} or over",
+                veterans, group.label(), VETERAN_AGE
+            ));
+        }
+        
+        // Upcoming contract expiries
+        let expiring = naturals
+            .iter()
+            .filter(|p| (p.contract.contract_end_date - today).num_days() <= CONTRACT_EXPIRY_WINDOW_DAYS)
+            .count();
+        if expiring > 0 {
+            need += (expiring as f32 / ideal * 15.0).min(15.0);
+            reasons.push(format!(
+                "{} of the {} have contracts expiring within a year",
+                expiring, group.label()
+            ));
+        }
+        
+        PositionalNeed {
+            group,
+            need: need.clamp(0.0, 100.0),
+            depth,
+            starter_quality,
+            reasons,
         }
     }
 
@@ -156,10 +278,11 @@ impl TransferEngine {
         &self,
         player: &Player,
         interested_club: &Team,
-        current_contract: &Contract,
+        _current_contract: &Contract,
+        today: NaiveDate,
     ) -> TransferOffer {
         // Calculate transfer fee based on player value
-        let transfer_fee = self.calculate_transfer_fee(player, interested_club, current_contract);
+        let transfer_fee = self.calculate_transfer_fee(player, interested_club, today);
         
         // Calculate wage offer based on player's ability and club's financial power
         let offered_wage = self.calculate_wage_offer(player, interested_club);
@@ -167,6 +290,9 @@ impl TransferEngine {
         // Calculate contract length based on age and club's youth focus
         let contract_length = self.calculate_contract_length(player.age, interested_club.youth_focus);
         
+        // Split the fee into upfront, instalments and clauses
+        let fee_structure = transfer_fee.map(|fee| FeeEngine::new().structure_fee(player, interested_club, fee, today));
+        
         TransferOffer {
             id: Uuid::new_v4(),
             buying_club_id: interested_club.id,
@@ -174,38 +300,27 @@ impl TransferEngine {
             offered_wage,
             contract_length_years: contract_length,
             transfer_fee,
-            offer_date: chrono::Utc::now(),
-            expiry_date: chrono::Utc::now() + chrono::Duration::days(14), // 2 weeks to respond
+            offer_date: today.and_time(NaiveTime::MIN).and_utc(),
+            expiry_date: (today + chrono::Duration::days(14)).and_time(NaiveTime::MIN).and_utc(), // 2 weeks to respond
+            fee_structure,
         }
     }
 
     /// Calculates transfer fee based on player value
-    fn calculate_transfer_fee(&self, player: &Player, interested_club: &Team, _current_contract: &Contract) -> Option<f32> {
-        // Base value from player attributes and performance
-        let base_value = self.calculate_player_market_value(player);
+    fn calculate_transfer_fee(&self, player: &Player, interested_club: &Team, today: NaiveDate) -> Option<f32> {
+        // Market value already prices in age, form, league and contract length
+        let market_value = ValuationEngine::new().market_value(player, today);
         
         // Apply club reputation multiplier
         let reputation_multiplier = interested_club.reputation / 50.0;  // Normalize to ~1.0 for average clubs
         
-        // Apply age factor (younger players cost more)
-        let age_factor = match player.age {
-            18..=24 => 1.2,
-            25..=28 => 1.0,
-            29..=31 => 0.8,
-            32..=34 => 0.6,
-            _ => 0.4,
-        };
-        
-        // Apply performance factor
-        let performance_factor = player.form / 50.0;  // Normalize form to 0-2 scale
-        
         // Calculate base fee
-        let base_fee = base_value * reputation_multiplier * age_factor * performance_factor;
+        let base_fee = market_value * reputation_multiplier;
         
         // Apply financial capacity constraint
-        if base_fee > interested_club.financial_power * 1000.0 {
+        if base_fee > self.transfer_budget(interested_club) {
             // Club can't afford, reduce to max they can pay
-            Some(interested_club.financial_power * 1000.0 * 0.8)  // 80% of capacity
+            Some(self.transfer_budget(interested_club) * 0.8)  // 80% of capacity
         } else {
             Some(base_fee)
         }
@@ -213,19 +328,13 @@ impl TransferEngine {
 
     /// Calculates player's market value
     fn calculate_player_market_value(&self, player: &Player) -> f32 {
-        // Combine various factors to determine market value
-        let ability_value = self.calculate_player_ability_score(player) * 100.0;
-        let reputation_value = player.international_reputation * 50.0;
-        let form_value = player.form * 30.0;
-        let potential_value = (player.hidden.potential_ceiling as f32) * 20.0;
-        
-        ability_value + reputation_value + form_value + potential_value
+        ValuationEngine::new().base_value(player)
     }
 
     /// Calculates wage offer based on player ability and club finances
     fn calculate_wage_offer(&self, player: &Player, interested_club: &Team) -> f32 {
         // Base wage from player ability
-        let base_wage = self.calculate_player_ability_score(player) * 1000.0;
+        let base_wage = player.ability_score() * 1000.0;
         
         // Apply club financial power multiplier
         let financial_multiplier = interested_club.financial_power / 50.0;
@@ -255,16 +364,21 @@ impl TransferEngine {
     }
 
     /// Processes a player's response to a transfer offer
+    ///
+    /// An interested player only opens talks when the offer scores well enough against
+    /// their career priorities; otherwise they ask for a better offer.
     pub fn process_player_response(
         &self,
         player: &Player,
         offer: &TransferOffer,
+        buying_club: &Team,
+        priorities: &CareerPriorities,
         response: PlayerResponse,
     ) -> TransferOutcome {
         match response {
             PlayerResponse::Interested => {
                 // Check if offer meets player's expectations
-                if self.offer_meets_expectations(player, offer) {
+                if self.offer_meets_expectations(player, offer, buying_club, priorities) {
                     TransferOutcome::NegotiationStarted
                 } else {
                     TransferOutcome::CounterOfferSuggested
@@ -275,77 +389,628 @@ impl TransferEngine {
         }
     }
 
-    /// Checks if an offer meets the player's expectations
-    fn offer_meets_expectations(&self, player: &Player, offer: &TransferOffer) -> bool {
-        // This would be more complex in a real implementation
-        // considering player's ambition, loyalty, relationships, etc.
-        
-        // Simple heuristic: check if offered wage is within 20% of player's perceived value
-        let player_perceived_value = self.calculate_player_perceived_value(player);
-        let wage_ratio = offer.offered_wage / player_perceived_value;
-        
-        wage_ratio >= 0.8 && wage_ratio <= 1.2
+    /// Checks if an offer meets the player's expectations, judged on their career priorities
+    fn offer_meets_expectations(&self, player: &Player, offer: &TransferOffer, buying_club: &Team, priorities: &CareerPriorities) -> bool {
+        let priority_engine = PriorityEngine::new();
+        let score = priority_engine.score_offer(priorities, player, buying_club, offer.offered_wage, player.contract.squad_role);
+        priority_engine.meets_expectations(&score)
     }
 
-    /// Calculates player's perceived value (what they think they're worth)
-    fn calculate_player_perceived_value(&self, player: &Player) -> f32 {
-        // Combine reputation, form, and ego to determine perceived value
-        let reputation_factor = player.international_reputation / 50.0;  // Normalize to 0-2 scale
-        let form_factor = player.form / 50.0;  // Normalize to 0-2 scale
-        let ego_factor = (player.hidden.ego as f32) / 50.0;  // Normalize to 0-2 scale
+    /// Runs contract talks between a club and a player over several rounds
+    ///
+    /// The club opens with the offer, the player counters towards their demands and
+    /// the club counters back within its limits. Talks end when one side accepts,
+    /// the club walks away, or the rounds run out. Keep the returned record with
+    /// `record_negotiation`.
+    pub fn negotiate_contract(
+        &self,
+        player: &Player,
+        club: &Team,
+        offer: &TransferOffer,
+        negotiation_preferences: &NegotiationPreferences,
+        positional_need: f32,
+    ) -> TransferNegotiation {
+        let limits = self.club_negotiation_limits(player, club, offer, positional_need);
+        let demands = self.player_demands(player, offer, negotiation_preferences, &limits);
+        let concession = self.player_concession_rate(player);
         
-        // Base value from attributes
-        let base_value = self.calculate_player_ability_score(player) * 1000.0;
+        let mut rounds = Vec::new();
+        let mut club_terms = self.opening_terms(offer, &limits);
+        rounds.push(NegotiationRound {
+            round: 1,
+            party: NegotiatingParty::Club,
+            terms: club_terms.clone(),
+            note: format!("{} open talks", club.name),
+        });
         
-        base_value * reputation_factor * form_factor * ego_factor
+        let mut final_result = NegotiationResult::Rejected;
+        let mut agreed_terms = None;
+        
+        for round in 1..=MAX_NEGOTIATION_ROUNDS {
+            // The player counters, conceding a little more ground each round
+            let ask = self.player_counter(&demands, &club_terms, concession * (round - 1) as f32);
+            if self.terms_satisfy(&club_terms, &ask) {
+                rounds.push(NegotiationRound {
+                    round,
+                    party: NegotiatingParty::Player,
+                    terms: club_terms.clone(),
+                    note: format!("{} accepts the terms", player.name),
+                });
+                final_result = NegotiationResult::Accepted;
+                agreed_terms = Some(club_terms.clone());
+                break;
+            }
+            rounds.push(NegotiationRound {
+                round,
+                party: NegotiatingParty::Player,
+                terms: ask.clone(),
+                note: format!("{} makes a counter-offer", player.name),
+            });
+            
+            // Demands beyond the walk-away threshold end the talks
+            if ask.wage > limits.walk_away_wage {
+                rounds.push(NegotiationRound {
+                    round,
+                    party: NegotiatingParty::Club,
+                    terms: club_terms.clone(),
+                    note: format!("{} walk away: wage demands too high", club.name),
+                });
+                final_result = NegotiationResult::Withdrawn;
+                break;
+            }
+            
+            if self.within_limits(&ask, &limits) {
+                rounds.push(NegotiationRound {
+                    round,
+                    party: NegotiatingParty::Club,
+                    terms: ask.clone(),
+                    note: format!("{} accept the counter-offer", club.name),
+                });
+                final_result = NegotiationResult::Accepted;
+                agreed_terms = Some(ask);
+                break;
+            }
+            
+            if round == MAX_NEGOTIATION_ROUNDS {
+                rounds.push(NegotiationRound {
+                    round,
+                    party: NegotiatingParty::Club,
+                    terms: club_terms.clone(),
+                    note: "Talks break down without agreement".to_string(),
+                });
+                break;
+            }
+            
+            club_terms = self.club_counter(&club_terms, &ask, &limits);
+            rounds.push(NegotiationRound {
+                round: round + 1,
+                party: NegotiatingParty::Club,
+                terms: club_terms.clone(),
+                note: format!("{} return with an improved offer", club.name),
+            });
+        }
+        
+        TransferNegotiation {
+            offer_id: offer.id,
+            player_response: Some(NegotiationResponse::Interested),
+            agent_response: None,
+            final_result: Some(final_result),
+            rounds,
+            agreed_terms,
+        }
     }
 
-    /// Processes contract negotiations
-    pub fn negotiate_contract(
+    /// Keeps finished talks in `TransferSystemState::negotiation_history`
+    pub fn record_negotiation(&self, state: &mut TransferSystemState, negotiation: &TransferNegotiation) {
+        state.negotiation_history.push(negotiation.clone());
+    }
+
+    /// Works out how far a club will go in talks
+    ///
+    /// Richer clubs and clubs with a bigger positional need stretch further.
+    pub fn club_negotiation_limits(
         &self,
-        _player: &Player,
+        player: &Player,
+        club: &Team,
         offer: &TransferOffer,
-        negotiation_preferences: &NegotiationPreferences,
-    ) -> ContractNegotiationResult {
-        // Simulate negotiation process
-        let mut final_offer = offer.clone();
+        positional_need: f32,
+    ) -> ClubNegotiationLimits {
+        let need = positional_need.clamp(0.0, 100.0) / 100.0;
+        let wealth = club.financial_power.clamp(0.0, 100.0) / 100.0;
+        
+        let wage_ceiling = offer.offered_wage * (1.0 + 0.15 * wealth + 0.25 * need);
+        let walk_away_wage = wage_ceiling * (1.1 + 0.2 * need);
+        let signing_fee_ceiling = wage_ceiling * (4.0 + 8.0 * wealth);
+        let bonus_ceiling = wage_ceiling * (2.0 + 2.0 * wealth);
+        let fee = offer.transfer_fee.unwrap_or_else(|| self.calculate_player_market_value(player));
+        let release_clause_floor = fee * (1.5 + need);
+        
+        let max_role = if positional_need >= 60.0 {
+            SquadRole::KeyPlayer
+        } else if positional_need >= 35.0 {
+            SquadRole::FirstTeam
+        } else if positional_need >= 15.0 {
+            SquadRole::Rotation
+        } else {
+            SquadRole::Backup
+        };
         
-        // Apply negotiation preferences
-        if negotiation_preferences.prefer_longer_contract {
-            final_offer.contract_length_years = final_offer.contract_length_years.min(6);
+        ClubNegotiationLimits {
+            wage_ceiling,
+            walk_away_wage,
+            signing_fee_ceiling,
+            bonus_ceiling,
+            release_clause_floor,
+            max_role,
+            max_length_years: (offer.contract_length_years + 1).min(5),
         }
+    }
+
+    /// The club's opening terms, built from the offer
+    fn opening_terms(&self, offer: &TransferOffer, limits: &ClubNegotiationLimits) -> ContractTerms {
+        // Hold back one step on the role promise
+        let role = match limits.max_role {
+            SquadRole::KeyPlayer => SquadRole::FirstTeam,
+            SquadRole::FirstTeam => SquadRole::Rotation,
+            _ => SquadRole::Backup,
+        };
         
-        if negotiation_preferences.prefer_higher_wage {
-            final_offer.offered_wage *= 1.05;  // 5% increase request
+        ContractTerms {
+            wage: offer.offered_wage,
+            length_years: offer.contract_length_years,
+            squad_role: role,
+            release_clause: None,
+            signing_on_fee: offer.offered_wage * 2.0,
+            performance_bonuses: vec![Bonus {
+                condition: BonusCondition::Appearances(20),
+                amount: offer.offered_wage,
+                achieved: false,
+            }],
         }
+    }
+
+    /// What the player wants from the talks, shaped by personality and preferences
+    fn player_demands(
+        &self,
+        player: &Player,
+        offer: &TransferOffer,
+        preferences: &NegotiationPreferences,
+        limits: &ClubNegotiationLimits,
+    ) -> ContractTerms {
+        let ego = player.hidden.ego as f32;
+        let greed = if preferences.prefer_higher_wage { 1.1 } else { 1.0 };
         
-        // Check if club accepts modified terms
-        let club_acceptance = self.club_acceptance_probability(&final_offer);
+        // Players always push above the opening wage, more so with a big ego
+        let value = player.ability_score() * 1000.0
+            * (1.0 + player.international_reputation / 200.0)
+            * (0.9 + ego / 250.0);
+        let wage = value.max(offer.offered_wage * (1.0 + ego / 400.0)) * greed;
         
-        if club_acceptance > 0.5 {
-            ContractNegotiationResult::Accepted(final_offer)
+        let length_years = if preferences.prefer_longer_contract {
+            (offer.contract_length_years + 1).min(5)
         } else {
-            ContractNegotiationResult::Rejected
+            offer.contract_length_years
+        };
+        
+        let squad_role = if player.hidden.ambition >= 80 {
+            SquadRole::KeyPlayer
+        } else if preferences.prefer_playing_time {
+            SquadRole::FirstTeam
+        } else {
+            self.opening_terms(offer, limits).squad_role
+        };
+        
+        // Ambitious players want a way out if a bigger club calls
+        let release_clause = if player.hidden.ambition >= 70 || preferences.prefer_prestige_club {
+            Some(limits.release_clause_floor * 0.8)
+        } else {
+            None
+        };
+        
+        let mut performance_bonuses = vec![Bonus {
+            condition: BonusCondition::Appearances(20),
+            amount: wage,
+            achieved: false,
+        }];
+        if matches!(
+            player.primary_position,
+            Position::CF | Position::SS | Position::RW | Position::LW | Position::AM
+        ) {
+            performance_bonuses.push(Bonus {
+                condition: BonusCondition::Goals(10),
+                amount: wage * 2.0,
+                achieved: false,
+            });
+        } else if matches!(player.primary_position, Position::GK) {
+            performance_bonuses.push(Bonus {
+                condition: BonusCondition::CleanSheets(12),
+                amount: wage * 2.0,
+                achieved: false,
+            });
+        }
+        
+        ContractTerms {
+            wage,
+            length_years,
+            squad_role,
+            release_clause,
+            signing_on_fee: wage * (3.0 + ego / 25.0) * greed,
+            performance_bonuses,
         }
     }
 
-    /// Calculates probability that club accepts modified terms
-    fn club_acceptance_probability(&self, _offer: &TransferOffer) -> f32 {
-        // Simplified acceptance probability
-        // In reality, this would consider club's budget, priorities, etc.
-        0.7  // 70% chance for now
+    /// Share of the gap to the club's terms a player concedes per round
+    fn player_concession_rate(&self, player: &Player) -> f32 {
+        (0.3 - player.hidden.ego as f32 / 500.0).max(0.05)
     }
 
-    /// Checks if a player's contract is expiring soon
-    pub fn is_contract_expiring_soon(&self, contract: &Contract, months_threshold: u32) -> bool {
-        let today = chrono::Utc::now().date_naive();
-        let expiry_date = contract.contract_end_date;
+    /// The player's counter-offer after conceding part of the gap to the club's terms
+    fn player_counter(&self, demands: &ContractTerms, club_terms: &ContractTerms, concession: f32) -> ContractTerms {
+        let concession = concession.min(1.0);
+        let towards = |ask: f32, offered: f32| ask - (ask - offered) * concession;
+        
+        ContractTerms {
+            wage: towards(demands.wage, club_terms.wage).max(club_terms.wage),
+            length_years: demands.length_years,
+            squad_role: demands.squad_role,
+            release_clause: demands.release_clause.map(|ask| match club_terms.release_clause {
+                Some(offered) => towards(ask, offered),
+                None => ask,
+            }),
+            signing_on_fee: towards(demands.signing_on_fee, club_terms.signing_on_fee).max(club_terms.signing_on_fee),
+            performance_bonuses: demands.performance_bonuses.iter()
+                .map(|bonus| {
+                    let offered = club_terms.performance_bonuses.iter()
+                        .find(|offered| offered.condition == bonus.condition)
+                        .map_or(0.0, |offered| offered.amount);
+                    Bonus {
+                        amount: towards(bonus.amount, offered).max(offered),
+                        ..bonus.clone()
+                    }
+                })
+                .collect(),
+        }
+    }
+
+    /// Total on offer across a set of terms' performance bonuses
+    fn bonus_total(&self, terms: &ContractTerms) -> f32 {
+        terms.performance_bonuses.iter().map(|bonus| bonus.amount).sum()
+    }
+
+    /// Whether the club's terms give the player everything they are currently asking for
+    fn terms_satisfy(&self, offered: &ContractTerms, ask: &ContractTerms) -> bool {
+        let release_clause_ok = match (ask.release_clause, offered.release_clause) {
+            (None, _) => true,
+            (Some(asked), Some(offered)) => offered <= asked * 1.25,
+            (Some(_), None) => false,
+        };
+        
+        offered.wage >= ask.wage * 0.98
+            && offered.signing_on_fee >= ask.signing_on_fee * 0.95
+            && offered.length_years >= ask.length_years
+            && self.role_rank(&offered.squad_role) >= self.role_rank(&ask.squad_role)
+            && offered.performance_bonuses.len() >= ask.performance_bonuses.len()
+            && self.bonus_total(offered) >= self.bonus_total(ask) * 0.95
+            && release_clause_ok
+    }
+
+    /// Whether the club can sign up to the player's terms as they stand
+    fn within_limits(&self, ask: &ContractTerms, limits: &ClubNegotiationLimits) -> bool {
+        ask.wage <= limits.wage_ceiling
+            && ask.signing_on_fee <= limits.signing_fee_ceiling
+            && self.bonus_total(ask) <= limits.bonus_ceiling
+            && ask.length_years <= limits.max_length_years
+            && self.role_rank(&ask.squad_role) <= self.role_rank(&limits.max_role)
+            && ask.release_clause.is_none_or(|clause| clause >= limits.release_clause_floor)
+    }
+
+    /// The club's counter: meet the player halfway on money, within its limits
+    fn club_counter(&self, club_terms: &ContractTerms, ask: &ContractTerms, limits: &ClubNegotiationLimits) -> ContractTerms {
+        let halfway = |offered: f32, asked: f32, ceiling: f32| (offered + (asked - offered) / 2.0).min(ceiling);
+        
+        let squad_role = if self.role_rank(&ask.squad_role) <= self.role_rank(&limits.max_role) {
+            ask.squad_role
+        } else {
+            limits.max_role
+        };
         
-        // Calculate difference in months
-        let month_diff = ((expiry_date.year() - today.year()) * 12) as i32 + 
-                         (expiry_date.month() as i32 - today.month() as i32);
+        // Bonuses are met halfway too, scaled down to fit the club's bonus pot
+        let bonus_target = halfway(self.bonus_total(club_terms), self.bonus_total(ask), limits.bonus_ceiling);
+        let bonus_scale = if self.bonus_total(ask) > 0.0 {
+            (bonus_target / self.bonus_total(ask)).min(1.0)
+        } else {
+            1.0
+        };
         
-        month_diff <= months_threshold as i32 && month_diff >= 0
+        ContractTerms {
+            wage: halfway(club_terms.wage, ask.wage, limits.wage_ceiling),
+            length_years: ask.length_years.min(limits.max_length_years),
+            squad_role,
+            release_clause: ask.release_clause.map(|clause| clause.max(limits.release_clause_floor)),
+            signing_on_fee: halfway(club_terms.signing_on_fee, ask.signing_on_fee, limits.signing_fee_ceiling),
+            performance_bonuses: ask.performance_bonuses.iter()
+                .map(|bonus| Bonus {
+                    amount: bonus.amount * bonus_scale,
+                    ..bonus.clone()
+                })
+                .collect(),
+        }
+    }
+
+    /// Orders squad roles from least to most playing time promised
+    fn role_rank(&self, role: &SquadRole) -> u8 {
+        match role {
+            SquadRole::Prospect => 0,
+            SquadRole::Backup => 1,
+            SquadRole::Rotation => 2,
+            SquadRole::FirstTeam => 3,
+            SquadRole::KeyPlayer => 4,
+        }
+    }
+
+    /// Most a club can spend on a single transfer fee
+    pub fn transfer_budget(&self, team: &Team) -> f32 {
+        team.financial_power * 1000.0
+    }
+
+    /// Whether a club can afford to trigger the player's release clause
+    pub fn can_trigger_release_clause(&self, buying_club: &Team, contract: &Contract) -> bool {
+        contract.release_clause.is_some_and(|clause| clause <= self.transfer_budget(buying_club))
+    }
+
+    /// Pays the player's release clause, bypassing the selling club entirely
+    ///
+    /// Only personal terms with the player remain, and those are negotiated straight away.
+    /// Returns `None` if there is no clause, the club can't afford it or the window is shut.
+    pub fn trigger_release_clause(
+        &self,
+        player: &Player,
+        buying_club: &Team,
+        negotiation_preferences: &NegotiationPreferences,
+        positional_need: f32,
+        windows: &TransferWindows,
+        today: NaiveDate,
+    ) -> Option<ClauseActivation> {
+        if !windows.is_open(today) || !self.can_trigger_release_clause(buying_club, &player.contract) {
+            return None;
+        }
+        let fee = player.contract.release_clause?;
+
+        let mut offer = self.generate_transfer_offer(player, buying_club, &player.contract, today);
+        offer.transfer_fee = Some(fee);
+        offer.fee_structure = Some(FeeStructure::upfront(fee)); // A release clause is paid in full
+        let negotiation = self.negotiate_contract(player, buying_club, &offer, negotiation_preferences, positional_need);
+
+        Some(ClauseActivation {
+            buying_club_id: buying_club.id,
+            fee,
+            negotiation,
+        })
+    }
+
+    /// Completes a move after a release clause has been paid and personal terms agreed
+    ///
+    /// The clause fee goes from the buying club to the selling club in full. Returns false,
+    /// changing nothing, if the talks failed, either club is missing or the window has shut.
+    pub fn complete_clause_move(
+        &self,
+        player: &mut Player,
+        state: &mut TransferSystemState,
+        clubs: &mut [Team],
+        activation: &ClauseActivation,
+        windows: &TransferWindows,
+        today: NaiveDate,
+    ) -> bool {
+        let Some(terms) = &activation.negotiation.agreed_terms else {
+            return false;
+        };
+        let Some(buying_club) = clubs.iter().find(|club| club.id == activation.buying_club_id).cloned() else {
+            return false;
+        };
+        let selling_club_id = player.contract.club_id;
+        if !self.complete_transfer(player, &buying_club, terms, windows, today) {
+            return false;
+        }
+
+        let fees = FeeEngine::new();
+        let payments = fees.record_transfer(&mut state.transfer_records, player.id, selling_club_id, buying_club.id, FeeStructure::upfront(activation.fee), today);
+        fees.apply_payments(clubs, &payments);
+        state.interested_clubs.remove(&buying_club.id);
+        state.active_offers.retain(|offer| offer.buying_club_id != buying_club.id);
+        state.transfer_request = None;
+        true
+    }
+
+    /// How important a player is to their club, 0-1
+    pub fn squad_importance(&self, player: &Player) -> f32 {
+        match player.contract.squad_role {
+            SquadRole::KeyPlayer => 1.0,
+            SquadRole::FirstTeam => 0.7,
+            SquadRole::Rotation => 0.4,
+            SquadRole::Prospect => 0.3,
+            SquadRole::Backup => 0.2,
+        }
+    }
+
+    /// The fee a selling club wants before it lets a player go
+    ///
+    /// Important players and long contracts raise the price; a release clause caps it.
+    pub fn selling_valuation(&self, player: &Player, today: NaiveDate) -> f32 {
+        // Out-of-contract players leave for nothing
+        if today >= player.contract.contract_end_date {
+            return 0.0;
+        }
+        let valuation = ValuationEngine::new().market_value(player, today) * (1.0 + self.squad_importance(player));
+
+        match player.contract.release_clause {
+            Some(clause) => valuation.min(clause),
+            None => valuation,
+        }
+    }
+
+    /// The selling club's answer to a bid
+    ///
+    /// Clubs short of money and players pushing for a move both lower the price.
+    pub fn evaluate_bid(
+        &self,
+        player: &Player,
+        selling_club: &Team,
+        fee: f32,
+        transfer_requested: bool,
+        today: NaiveDate,
+    ) -> BidResponse {
+        if player.contract.release_clause.is_some_and(|clause| fee >= clause) {
+            return BidResponse::ClauseTriggered;
+        }
+
+        let mut asking = self.selling_valuation(player, today);
+        if ClubFinanceEngine::new().in_debt(selling_club) {
+            asking *= 0.85;  // Needs the money
+        }
+        if transfer_requested {
+            asking *= 0.8;  // No point keeping an unhappy player
+        }
+
+        if fee >= asking {
+            BidResponse::Accepted
+        } else if fee >= asking * 0.75 {
+            BidResponse::Countered(asking * 1.05)
+        } else if self.squad_importance(player) >= 1.0 {
+            BidResponse::Rejected(format!("{} will not sell a key player for that", selling_club.name))
+        } else {
+            BidResponse::Rejected(format!("{} consider the bid too low", selling_club.name))
+        }
+    }
+
+    /// Runs a club's bids for a player until the clubs agree or the buyer gives up
+    ///
+    /// The buyer opens at `opening_fee` and goes no higher than `max_fee` or its budget.
+    /// A bid at the release clause can't be refused.
+    #[allow(clippy::too_many_arguments)]
+    pub fn bid_for_player(
+        &self,
+        player: &Player,
+        buying_club: &Team,
+        selling_club: &Team,
+        opening_fee: f32,
+        max_fee: f32,
+        transfer_requested: bool,
+        today: NaiveDate,
+    ) -> BidOutcome {
+        let limit = max_fee.min(self.transfer_budget(buying_club));
+        let mut fee = opening_fee.min(limit);
+        let mut bids = Vec::new();
+        let mut agreed_fee = None;
+
+        for _ in 0..MAX_BID_ROUNDS {
+            let response = self.evaluate_bid(player, selling_club, fee, transfer_requested, today);
+            bids.push(TransferBid {
+                buying_club_id: buying_club.id,
+                selling_club_id: selling_club.id,
+                player_id: player.id,
+                fee,
+                response: response.clone(),
+            });
+
+            match response {
+                BidResponse::Accepted | BidResponse::ClauseTriggered => {
+                    agreed_fee = Some(fee);
+                    break;
+                }
+                BidResponse::Countered(asking) if asking <= limit => fee = asking,
+                // Counter beyond the buyer's limit: one final offer at the limit
+                BidResponse::Countered(_) if fee < limit => fee = limit,
+                _ => break,
+            }
+        }
+
+        BidOutcome {
+            bids,
+            agreed_fee,
+        }
+    }
+
+    /// Moves the player to a club on the agreed terms, if the window is open
+    ///
+    /// Returns false when the window is shut and nothing changes.
+    pub fn complete_transfer(
+        &self,
+        player: &mut Player,
+        buying_club: &Team,
+        terms: &ContractTerms,
+        windows: &TransferWindows,
+        today: NaiveDate,
+    ) -> bool {
+        if !windows.is_open(today) {
+            return false;
+        }
+        self.sign_contract(player, buying_club, terms, today);
+        true
+    }
+
+    /// Replaces the player's contract with a new one at the club, starting today
+    ///
+    /// Used directly for moves that don't need a window, such as free agents signing.
+    pub fn sign_contract(&self, player: &mut Player, club: &Team, terms: &ContractTerms, today: NaiveDate) {
+        let months = Months::new(12 * terms.length_years as u32);
+        player.contract = Contract {
+            club_id: club.id,
+            wage: terms.wage,
+            length_years: terms.length_years,
+            squad_role: terms.squad_role,
+            release_clause: terms.release_clause,
+            performance_bonuses: terms.performance_bonuses.clone(),
+            contract_end_date: today.checked_add_months(months).unwrap_or(today),
+            league_strength: club.reputation,
+            loan: None,
+        };
+    }
+
+    /// The player pushes for a move, souring things with their club's manager
+    ///
+    /// Clubs sell for less once a player has asked to leave.
+    pub fn push_for_move(&self, player: &mut Player, club: &Team, state: &mut TransferSystemState, today: NaiveDate) {
+        if state.transfer_request.is_some() {
+            return;
+        }
+        state.transfer_request = Some(today);
+
+        let factors = PersonalityFactors::new(player.hidden.loyalty, player.hidden.ego, player.mental.teamwork, 50, 50);
+        SocialEngine::new().update_relationship(
+            &mut player.relationships,
+            club.manager_id(),
+            -TRANSFER_REQUEST_RELATIONSHIP_PENALTY,
+            &factors,
+        );
+    }
+
+    /// The player's reaction when their club turns down a bid they wanted to happen
+    ///
+    /// Returns the morale change. Ambitious players denied a step up take it hardest
+    /// and may push for a move.
+    pub fn react_to_blocked_move(&self, player: &mut Player, buying_club: &Team, selling_club: &Team) -> f32 {
+        if buying_club.reputation <= selling_club.reputation {
+            return 0.0;
+        }
+
+        let step_up = (buying_club.reputation - selling_club.reputation) / 100.0;
+        let ambition = player.hidden.ambition as f32 / 100.0;
+        let loyalty = player.hidden.loyalty as f32 / 100.0;
+        let change = -(20.0 * step_up * ambition * (1.0 - loyalty * 0.5));
+
+        player.morale = (player.morale + change).clamp(0.0, 100.0);
+        change
+    }
+
+    /// Whether an unhappy player will ask to leave
+    pub fn wants_to_push_for_move(&self, player: &Player) -> bool {
+        player.morale < 40.0 && player.hidden.ambition >= 60 && player.hidden.loyalty < 70
+    }
+
+    /// Checks if a player's contract runs out within the given number of months of the game date
+    pub fn is_contract_expiring_soon(&self, contract: &Contract, months_threshold: u32, today: NaiveDate) -> bool {
+        let threshold = today.checked_add_months(Months::new(months_threshold)).unwrap_or(today);
+        contract.contract_end_date >= today && contract.contract_end_date <= threshold
     }
 
     /// Generates contract renewal offer for current club
@@ -354,11 +1019,17 @@ impl TransferEngine {
         player: &Player,
         current_club: &Team,
         current_contract: &Contract,
+        today: NaiveDate,
     ) -> TransferOffer {
         // Calculate improved terms based on performance
         let performance_improvement = (player.form - 6.5).max(0.0) * 0.1;  // Positive form above average
         
-        let new_wage = current_contract.wage * (1.0 + performance_improvement);
+        // A club in debt can't afford a raise
+        let new_wage = if ClubFinanceEngine::new().in_debt(current_club) {
+            current_contract.wage
+        } else {
+            current_contract.wage * (1.0 + performance_improvement)
+        };
         let new_length = if player.age < 28 {
             current_contract.length_years.min(5)  // Extend for younger players
         } else {
@@ -372,12 +1043,47 @@ impl TransferEngine {
             offered_wage: new_wage,
             contract_length_years: new_length,
             transfer_fee: None,  // No fee for renewals
-            offer_date: chrono::Utc::now(),
-            expiry_date: chrono::Utc::now() + chrono::Duration::days(30), // More time for renewals
+            offer_date: today.and_time(NaiveTime::MIN).and_utc(),
+            expiry_date: (today + chrono::Duration::days(30)).and_time(NaiveTime::MIN).and_utc(), // More time for renewals
+            fee_structure: None,
         }
     }
 }
 
+/// A club's bid for a player and the selling club's answer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransferBid {
+    pub buying_club_id: Uuid,
+    pub selling_club_id: Uuid,
+    pub player_id: Uuid,
+    pub fee: f32,
+    pub response: BidResponse,
+}
+
+/// How a selling club answers a bid
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum BidResponse {
+    Accepted,
+    Rejected(String),
+    Countered(f32),  // The fee the selling club would accept
+    ClauseTriggered, // The bid met the release clause
+}
+
+/// Result of a round of bidding between two clubs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BidOutcome {
+    pub bids: Vec<TransferBid>,
+    pub agreed_fee: Option<f32>,
+}
+
+/// A release clause paid in full, leaving only personal terms
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClauseActivation {
+    pub buying_club_id: Uuid,
+    pub fee: f32,
+    pub negotiation: TransferNegotiation,
+}
+
 /// Transfer interest level
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum InterestLevel {
@@ -395,6 +1101,84 @@ pub struct TransferInterest {
     pub interest_level: InterestLevel,
     pub last_evaluation_date: chrono::DateTime<chrono::Utc>,
     pub evaluation_score: f32,
+    pub positional_need: f32,    // 0-100, from the club's squad analysis
+    pub reasons: Vec<String>,    // Why the club is interested
+}
+
+/// Broad positional groups used when analyzing squad depth
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum PositionGroup {
+    Goalkeeper,
+    CentreBack,
+    FullBack,
+    DefensiveMidfield,
+    CentralMidfield,
+    Wide,
+    Striker,
+}
+
+impl PositionGroup {
+    /// Maps a specific position to its group
+    pub fn for_position(position: Position) -> Self {
+        match position {
+            Position::GK => PositionGroup::Goalkeeper,
+            Position::CB => PositionGroup::CentreBack,
+            Position::FB | Position::RB | Position::LB => PositionGroup::FullBack,
+            Position::DM => PositionGroup::DefensiveMidfield,
+            Position::CM | Position::AM => PositionGroup::CentralMidfield,
+            Position::RM | Position::LM | Position::RW | Position::LW => PositionGroup::Wide,
+            Position::CF | Position::SS => PositionGroup::Striker,
+        }
+    }
+
+    /// Number of options a squad wants in this group
+    pub fn ideal_depth(&self) -> usize {
+        match self {
+            PositionGroup::Goalkeeper => 3,
+            PositionGroup::CentreBack => 4,
+            PositionGroup::FullBack => 4,
+            PositionGroup::DefensiveMidfield => 2,
+            PositionGroup::CentralMidfield => 4,
+            PositionGroup::Wide => 4,
+            PositionGroup::Striker => 3,
+        }
+    }
+
+    /// Number of starters in this group in a typical line-up
+    pub fn starters(&self) -> usize {
+        match self {
+            PositionGroup::Goalkeeper => 1,
+            PositionGroup::CentreBack => 2,
+            PositionGroup::FullBack => 2,
+            PositionGroup::DefensiveMidfield => 1,
+            PositionGroup::CentralMidfield => 2,
+            PositionGroup::Wide => 2,
+            PositionGroup::Striker => 1,
+        }
+    }
+
+    /// Plural label used in interest reasons
+    pub fn label(&self) -> &'static str {
+        match self {
+            PositionGroup::Goalkeeper => "goalkeepers",
+            PositionGroup::CentreBack => "centre-backs",
+            PositionGroup::FullBack => "full-backs",
+            PositionGroup::DefensiveMidfield => "defensive midfielders",
+            PositionGroup::CentralMidfield => "central midfielders",
+            PositionGroup::Wide => "wide players",
+            PositionGroup::Striker => "strikers",
+        }
+    }
+}
+
+/// Result of analyzing a club's squad for one position
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PositionalNeed {
+    pub group: PositionGroup,
+    pub need: f32,                    // 0-100
+    pub depth: f32,                   // Recognised options, cover counts half
+    pub starter_quality: Option<f32>, // Average ability of the current starters
+    pub reasons: Vec<String>,
 }
 
 /// Transfer offer
@@ -408,6 +1192,8 @@ pub struct TransferOffer {
     pub transfer_fee: Option<f32>,
     pub offer_date: chrono::DateTime<chrono::Utc>,
     pub expiry_date: chrono::DateTime<chrono::Utc>,
+    #[serde(default)]
+    pub fee_structure: Option<FeeStructure>, // How the fee is paid, when there is one
 }
 
 /// Player's response to transfer offer
@@ -436,27 +1222,30 @@ pub struct NegotiationPreferences {
     pub prefer_playing_time: bool,
 }
 
-/// Result of contract negotiation
+/// How far a club will go in contract talks
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum ContractNegotiationResult {
-    Accepted(TransferOffer),
-    Rejected,
+pub struct ClubNegotiationLimits {
+    pub wage_ceiling: f32,        // Most the club will pay in wages
+    pub walk_away_wage: f32,      // Wage demands above this end the talks
+    pub signing_fee_ceiling: f32,
+    pub bonus_ceiling: f32,       // Most the club will put into performance bonuses in total
+    pub release_clause_floor: f32, // Lowest release clause the club will accept
+    pub max_role: SquadRole,      // Biggest squad role the club will promise
+    pub max_length_years: u8,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::entities::{Position, Foot, CareerStats, SquadRole, HiddenAttributes};
-    use crate::systems::social_system::ManagerProfile;
     use chrono::NaiveDate;
+    use std::collections::HashMap;
 
     #[test]
-    fn test_calculate_player_ability_score() {
-        let engine = TransferEngine::new();
-        
+    fn test_player_ability_score() {
         let player = create_test_player();
         
-        let score = engine.calculate_player_ability_score(&player);
+        let score = player.ability_score();
         
         // The score should be based on the player's attributes
         assert!(score > 70.0 && score < 90.0);
@@ -476,12 +1265,12 @@ mod tests {
         let engine = TransferEngine::new();
         
         let mut contract = create_test_contract();
-        // Set contract to expire in 2 months
-        let today = chrono::Utc::now().date_naive();
-        contract.contract_end_date = today + chrono::Duration::days(60);
+        // Set contract to expire in 2 months of game time
+        contract.contract_end_date = today() + chrono::Duration::days(60);
         
-        assert!(engine.is_contract_expiring_soon(&contract, 3));  // Should be true within 3 months
-        assert!(!engine.is_contract_expiring_soon(&contract, 1)); // Should be false within 1 month
+        assert!(engine.is_contract_expiring_soon(&contract, 3, today()));  // Should be true within 3 months
+        assert!(!engine.is_contract_expiring_soon(&contract, 1, today())); // Should be false within 1 month
+        assert!(!engine.is_contract_expiring_soon(&contract, 3, today() + chrono::Duration::days(61))); // Already expired
     }
 
     #[test]
@@ -491,7 +1280,8 @@ mod tests {
         let player = create_test_player();
         let team = create_test_team();
         
-        let score = engine.calculate_transfer_interest_score(&player, &team);
+        let need = engine.analyze_positional_need(&player, &team, &[], today());
+        let score = engine.calculate_transfer_interest_score(&player, &team, &need);
         
         // The score should be reasonable
         assert!(score > 0.0);
@@ -508,7 +1298,290 @@ mod tests {
         assert_eq!(engine.determine_interest_level(45.0), InterestLevel::Monitoring);
     }
 
+    #[test]
+    fn test_positional_need_from_squad() {
+        let engine = TransferEngine::new();
+        let mut player = create_test_player();
+        player.primary_position = Position::CF;
+        let team = create_test_team();
+
+        // An empty squad has a glaring gap
+        let empty = engine.analyze_positional_need(&player, &team, &[], today());
+        assert!(empty.need >= 50.0);
+        assert!(empty.starter_quality.is_none());
+        assert!(empty.reasons.iter().any(|r| r.contains("no recognised strikers")));
+
+        // Three elite strikers in their prime leave no gap
+        let elite: Vec<Player> = (0..3).map(|_| create_striker(95, 26, 2030)).collect();
+        let squad: Vec<&Player> = elite.iter().collect();
+        let covered = engine.analyze_positional_need(&player, &team, &squad, today());
+        assert_eq!(covered.need, 0.0);
+        assert_eq!(covered.depth, 3.0);
+        assert!(covered.reasons.is_empty());
+
+        // Weaker, ageing strikers whose deals are running out
+        let weak: Vec<Player> = (0..2).map(|_| create_striker(50, 33, 2026)).collect();
+        let squad: Vec<&Player> = weak.iter().collect();
+        let ageing = engine.analyze_positional_need(&player, &team, &squad, today());
+        assert!(ageing.need >= 50.0);
+        assert!(ageing.reasons.iter().any(|r| r.contains("aged 31 or over")));
+        assert!(ageing.reasons.iter().any(|r| r.contains("expiring within a year")));
+        assert!(ageing.reasons.iter().any(|r| r.contains("improve on the current starters")));
+    }
+
+    #[test]
+    fn test_interest_follows_squad_gaps() {
+        let engine = TransferEngine::new();
+        let mut player = create_test_player();
+        player.primary_position = Position::CF;
+
+        let current = create_test_team();
+        let mut stacked = create_test_team();
+        let needy = create_test_team();
+        let elite: Vec<Player> = (0..3).map(|_| create_striker(95, 26, 2035)).collect();
+        stacked.squad = elite.iter().map(|p| p.id).collect();
+
+        let teams = vec![current.clone(), stacked.clone(), needy.clone()];
+        let interests = engine.evaluate_transfer_interest(&player, &teams, &elite, current.id, today());
+
+        assert!(interests.iter().all(|i| i.club_id != stacked.id));
+        let interest = interests.iter().find(|i| i.club_id == needy.id).unwrap();
+        assert!(interest.positional_need >= MIN_POSITIONAL_NEED);
+        assert!(!interest.reasons.is_empty());
+    }
+
+    #[test]
+    fn test_negotiation_reaches_agreement_over_rounds() {
+        let engine = TransferEngine::new();
+        let player = create_test_player();
+        let club = create_test_team();
+        let offer = engine.generate_transfer_offer(&player, &club, &player.contract, today());
+        let limits = engine.club_negotiation_limits(&player, &club, &offer, 70.0);
+
+        let negotiation = engine.negotiate_contract(&player, &club, &offer, &greedy_preferences(), 70.0);
+
+        assert!(matches!(negotiation.final_result, Some(NegotiationResult::Accepted)));
+        assert!(negotiation.rounds.len() > 2);
+        assert_eq!(negotiation.rounds[0].party, NegotiatingParty::Club);
+        assert_eq!(negotiation.rounds[1].party, NegotiatingParty::Player);
+        let terms = negotiation.agreed_terms.as_ref().unwrap();
+        assert!(terms.wage > offer.offered_wage);
+        assert!(terms.wage <= limits.wage_ceiling);
+        assert!(terms.release_clause.is_some());  // Ambitious player gets a way out
+
+        // The talks are kept in the transfer history
+        let mut state = TransferSystemState::new();
+        engine.record_negotiation(&mut state, &negotiation);
+        assert_eq!(state.negotiation_history[0].offer_id, offer.id);
+        assert_eq!(state.negotiation_history[0].rounds.len(), negotiation.rounds.len());
+    }
+
+    #[test]
+    fn test_bonuses_are_negotiated_within_the_clubs_pot() {
+        let engine = TransferEngine::new();
+        let mut player = create_test_player();
+        player.primary_position = Position::CF;
+        let club = create_test_team();
+        let offer = engine.generate_transfer_offer(&player, &club, &player.contract, today());
+        let limits = engine.club_negotiation_limits(&player, &club, &offer, 70.0);
+
+        let negotiation = engine.negotiate_contract(&player, &club, &offer, &greedy_preferences(), 70.0);
+
+        // The club opens with an appearance bonus only; the striker asks for a goals bonus on top
+        assert_eq!(negotiation.rounds[0].terms.performance_bonuses.len(), 1);
+        let terms = negotiation.agreed_terms.as_ref().unwrap();
+        assert!(terms.performance_bonuses.iter().any(|bonus| bonus.condition == BonusCondition::Goals(10)));
+        let total: f32 = terms.performance_bonuses.iter().map(|bonus| bonus.amount).sum();
+        assert!(total <= limits.bonus_ceiling);
+    }
+
+    #[test]
+    fn test_club_walks_away_from_excessive_demands() {
+        let engine = TransferEngine::new();
+        let player = create_test_player();
+        let mut club = create_test_team();
+        club.financial_power = 30.0;
+        let offer = engine.generate_transfer_offer(&player, &club, &player.contract, today());
+
+        let negotiation = engine.negotiate_contract(&player, &club, &offer, &greedy_preferences(), 0.0);
+
+        assert!(matches!(negotiation.final_result, Some(NegotiationResult::Withdrawn)));
+        assert!(negotiation.agreed_terms.is_none());
+        assert!(negotiation.rounds.last().unwrap().note.contains("walk away"));
+    }
+
+    #[test]
+    fn test_unmet_role_promise_breaks_down_talks() {
+        let engine = TransferEngine::new();
+        let player = create_test_player();  // Ambitious enough to demand a key role
+        let club = create_test_team();
+        let offer = engine.generate_transfer_offer(&player, &club, &player.contract, today());
+
+        let negotiation = engine.negotiate_contract(&player, &club, &offer, &greedy_preferences(), 20.0);
+
+        assert!(matches!(negotiation.final_result, Some(NegotiationResult::Rejected)));
+        assert_eq!(negotiation.rounds.last().unwrap().round, MAX_NEGOTIATION_ROUNDS);
+        assert!(negotiation
+            .rounds
+            .iter()
+            .filter(|r| r.party == NegotiatingParty::Club)
+            .all(|r| !matches!(r.terms.squad_role, SquadRole::KeyPlayer)));
+    }
+
+    #[test]
+    fn test_release_clause_bypasses_selling_club() {
+        let engine = TransferEngine::new();
+        let mut player = create_test_player();
+        player.contract.release_clause = Some(50_000.0);
+        let rich = create_test_team();
+        let mut poor = create_test_team();
+        poor.financial_power = 30.0;
+        let windows = TransferWindows::for_country("England", 2025);
+
+        assert!(engine.can_trigger_release_clause(&rich, &player.contract));
+        let shut = NaiveDate::from_ymd_opt(2025, 10, 1).unwrap();
+        assert!(engine.trigger_release_clause(&player, &rich, &greedy_preferences(), 70.0, &windows, shut).is_none());
+        let activation = engine.trigger_release_clause(&player, &rich, &greedy_preferences(), 70.0, &windows, today()).unwrap();
+        assert_eq!(activation.fee, 50_000.0);
+        assert_eq!(activation.buying_club_id, rich.id);
+        assert!(activation.negotiation.final_result.is_some());  // Only personal terms remain
+
+        // Once terms are agreed the clause fee is paid in full and the player moves
+        let mut seller = create_test_team();
+        seller.id = player.contract.club_id;
+        let mut clubs = vec![seller.clone(), rich.clone()];
+        let mut state = TransferSystemState::new();
+        let mut mover = player.clone();
+        assert!(engine.complete_clause_move(&mut mover, &mut state, &mut clubs, &activation, &windows, today()));
+        assert_eq!(mover.contract.club_id, rich.id);
+        assert_eq!(clubs[0].finances.balance, seller.finances.balance + 50_000.0);
+        assert_eq!(clubs[1].finances.balance, rich.finances.balance - 50_000.0);
+
+        assert!(engine.trigger_release_clause(&player, &poor, &greedy_preferences(), 70.0, &windows, today()).is_none());
+        player.contract.release_clause = None;
+        assert!(engine.trigger_release_clause(&player, &rich, &greedy_preferences(), 70.0, &windows, today()).is_none());
+    }
+
+    #[test]
+    fn test_selling_club_accepts_rejects_or_counters() {
+        let engine = TransferEngine::new();
+        let mut player = create_test_player();
+        player.contract.contract_end_date = NaiveDate::from_ymd_opt(2029, 6, 30).unwrap();
+        let buyer = create_test_team();
+        let seller = create_test_team();
+        let asking = engine.selling_valuation(&player, today());
+        assert!(asking < engine.transfer_budget(&buyer));
+
+        // Lowball bids are turned down
+        let outcome = engine.bid_for_player(&player, &buyer, &seller, asking * 0.5, asking * 0.6, false, today());
+        assert!(outcome.agreed_fee.is_none());
+        assert!(matches!(outcome.bids.last().unwrap().response, BidResponse::Rejected(_)));
+
+        // Close bids get a counter the buyer can meet
+        let outcome = engine.bid_for_player(&player, &buyer, &seller, asking * 0.9, asking * 1.2, false, today());
+        assert_eq!(outcome.bids.len(), 2);
+        assert!(matches!(outcome.bids[0].response, BidResponse::Countered(_)));
+        assert!((outcome.agreed_fee.unwrap() - asking * 1.05).abs() < 1.0);
+
+        // A club in debt, or a player pushing to leave, lowers the price
+        let mut broke = create_test_team();
+        broke.finances.balance = -50_000.0;
+        assert_eq!(engine.evaluate_bid(&player, &broke, asking * 0.9, false, today()), BidResponse::Accepted);
+        assert_eq!(engine.evaluate_bid(&player, &seller, asking * 0.85, true, today()), BidResponse::Accepted);
+
+        // Bids at the clause can't be refused
+        player.contract.release_clause = Some(asking * 0.9);
+        assert_eq!(engine.evaluate_bid(&player, &seller, asking * 0.9, false, today()), BidResponse::ClauseTriggered);
+
+        // Key players are not for sale on the cheap
+        player.contract.release_clause = None;
+        player.contract.squad_role = SquadRole::KeyPlayer;
+        let response = engine.evaluate_bid(&player, &seller, asking * 0.5, false, today());
+        assert!(matches!(response, BidResponse::Rejected(reason) if reason.contains("key player")));
+    }
+
+    #[test]
+    fn test_player_response_follows_career_priorities() {
+        let engine = TransferEngine::new();
+        let player = create_test_player();
+        let mut club = create_test_team();
+        club.reputation = 90.0;
+        let mut offer = engine.generate_transfer_offer(&player, &club, &player.contract, today());
+        offer.offered_wage = player.contract.wage * 1.5;
+
+        let money = PriorityEngine::new().starting_priorities(&[crate::core::game_state::CareerPriority::Money], "");
+        let outcome = engine.process_player_response(&player, &offer, &club, &money, PlayerResponse::Interested);
+        assert!(matches!(outcome, TransferOutcome::NegotiationStarted));
+
+        // A pay cut doesn't get a money-minded player to the table
+        offer.offered_wage = player.contract.wage * 0.5;
+        let outcome = engine.process_player_response(&player, &offer, &club, &money, PlayerResponse::Interested);
+        assert!(matches!(outcome, TransferOutcome::CounterOfferSuggested));
+    }
+
+    #[test]
+    fn test_unhappy_player_pushes_for_move() {
+        let engine = TransferEngine::new();
+        let mut player = create_test_player();
+        let mut big_club = create_test_team();
+        big_club.reputation = 95.0;
+        let mut current = create_test_team();
+        current.reputation = 60.0;
+
+        let change = engine.react_to_blocked_move(&mut player, &big_club, &current);
+        assert!(change < 0.0);
+        assert_eq!(engine.react_to_blocked_move(&mut player, &current, &big_club), 0.0);
+
+        player.morale = 30.0;
+        assert!(engine.wants_to_push_for_move(&player));
+        let mut state = crate::core::game_state::TransferSystemState::new();
+        current.staff = vec![Uuid::new_v4()];
+        engine.push_for_move(&mut player, &current, &mut state, today());
+        assert_eq!(state.transfer_request, Some(today()));
+        // The manager takes it personally, as they do when a loan goes wrong
+        let relationship = player.relationships[&current.manager_id()];
+        assert!(relationship < 50.0);
+        assert!(!player.relationships.contains_key(&current.id));
+
+        // Asking twice changes nothing
+        engine.push_for_move(&mut player, &current, &mut state, today());
+        assert_eq!(player.relationships[&current.manager_id()], relationship);
+    }
+
     // Helper functions for tests
+    fn greedy_preferences() -> NegotiationPreferences {
+        NegotiationPreferences {
+            prefer_longer_contract: true,
+            prefer_higher_wage: true,
+            prefer_prestige_club: false,
+            prefer_playing_time: true,
+        }
+    }
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 8, 1).unwrap()
+    }
+
+    fn create_striker(rating: u8, age: u8, contract_until: i32) -> Player {
+        let mut player = create_test_player();
+        player.id = Uuid::new_v4();
+        player.primary_position = Position::CF;
+        player.age = age;
+        player.technical = crate::entities::TechnicalAttributes {
+            dribbling: rating, passing: rating, shooting: rating,
+            first_touch: rating, tackling: rating, crossing: rating,
+        };
+        player.physical = crate::entities::PhysicalAttributes {
+            pace: rating, stamina: rating, strength: rating, agility: rating, jumping: rating,
+        };
+        player.mental = crate::entities::MentalAttributes {
+            composure: rating, vision: rating, work_rate: rating,
+            determination: rating, positioning: rating, teamwork: rating,
+        };
+        player.contract.contract_end_date = NaiveDate::from_ymd_opt(contract_until, 6, 30).unwrap();
+        player
+    }
+
     fn create_test_player() -> Player {
         Player {
             id: Uuid::new_v4(),
@@ -575,6 +1648,14 @@ mod tests {
                 season_stats: vec![],
                 awards: vec![],
                 trophies: vec![],
+                current_season: crate::entities::SeasonStats::default(),
+                international_caps: 0,
+                international_goals: 0,
+                first_call_up: None,
+                youth_season: crate::entities::SeasonStats::default(),
+                youth_history: vec![],
+                youth_form: vec![],
+                club_spells: vec![],
             },
             relationships: HashMap::new(),
             injury_status: None,
@@ -593,6 +1674,7 @@ mod tests {
             performance_bonuses: vec![],
             contract_end_date: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
             league_strength: 75.0,
+            loan: None,
         }
     }
 
@@ -0,0 +1,155 @@
+// src/systems/valuation_system.rs
+use chrono::NaiveDate;
+
+use crate::core::game_state::{GameState, MarketValuePoint};
+use crate::entities::Player;
+
+/// Market value weeks kept per player, about three seasons
+const MAX_VALUE_POINTS: usize = 156;
+
+/// The ValuationEngine puts a market value on every player in the world
+/// It is the one place values are worked out, and it keeps a weekly history of them
+pub struct ValuationEngine;
+
+impl Default for ValuationEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ValuationEngine {
+    /// Creates a new ValuationEngine instance
+    pub fn new() -> Self {
+        ValuationEngine
+    }
+
+    /// What the market would pay for the player, before their contract is taken into account
+    ///
+    /// Ability, reputation, form and potential set the base, which is scaled for age and the
+    /// strength of the league the player is proving themselves in.
+    pub fn base_value(&self, player: &Player) -> f32 {
+        let ability_value = player.ability_score() * 100.0;
+        let reputation_value = player.international_reputation * 50.0;
+        let form_value = player.form * 30.0;
+        let potential_value = (player.hidden.potential_ceiling as f32) * 20.0;
+
+        (ability_value + reputation_value + form_value + potential_value)
+            * self.age_factor(player.age)
+            * self.league_factor(player.contract.league_strength)
+    }
+
+    /// The player's market value today, allowing for how long their contract has left to run
+    pub fn market_value(&self, player: &Player, today: NaiveDate) -> f32 {
+        self.base_value(player) * self.contract_factor(player, today)
+    }
+
+    /// Adds this week's value to a player's history, dropping the oldest weeks beyond three seasons
+    pub fn record_value(&self, history: &mut Vec<MarketValuePoint>, player: &Player, today: NaiveDate) {
+        history.push(MarketValuePoint {
+            date: today,
+            value: self.market_value(player, today),
+        });
+        if history.len() > MAX_VALUE_POINTS {
+            history.remove(0);
+        }
+    }
+
+    /// Records this week's market value for every player in the world
+    pub fn record_weekly_values(&self, game_state: &mut GameState, today: NaiveDate) {
+        for player in std::iter::once(&game_state.player).chain(game_state.players.iter()) {
+            let history = game_state.market_values.entry(player.id).or_default();
+            self.record_value(history, player, today);
+        }
+    }
+
+    /// Change in value over the history, as a share of the first recorded value
+    pub fn value_trend(&self, history: &[MarketValuePoint]) -> Option<f32> {
+        let (first, last) = (history.first()?, history.last()?);
+        if first.value <= 0.0 {
+            return None;
+        }
+        Some((last.value - first.value) / first.value)
+    }
+
+    /// Young players carry a premium, and value falls away after the late twenties
+    fn age_factor(&self, age: u8) -> f32 {
+        match age {
+            0..=21 => 1.1,
+            22..=28 => 1.0,
+            29..=31 => 0.85,
+            32..=34 => 0.65,
+            _ => 0.45,
+        }
+    }
+
+    /// Proving it in a strong league is worth more, 1.0 for an average league
+    fn league_factor(&self, league_strength: f32) -> f32 {
+        0.75 + league_strength.clamp(0.0, 100.0) / 200.0
+    }
+
+    /// Longer contracts raise the price; a free agent or an expiring deal is worth far less
+    fn contract_factor(&self, player: &Player, today: NaiveDate) -> f32 {
+        if player.contract.club_id.is_nil() {
+            return 0.6;
+        }
+        let years_left = ((player.contract.contract_end_date - today).num_days() as f32 / 365.0).max(0.0);
+        (0.6 + 0.15 * years_left).min(1.35)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{create_test_player, date};
+
+    use uuid::Uuid;
+
+    #[test]
+    fn test_value_reflects_age_contract_and_league() {
+        let engine = ValuationEngine::new();
+        let today = date(2026, 8, 1);
+        let mut player = create_test_player();
+        player.contract.contract_end_date = date(2029, 8, 1);
+        let value = engine.market_value(&player, today);
+
+        let mut veteran = player.clone();
+        veteran.age = 33;
+        assert!(engine.market_value(&veteran, today) < value);
+
+        let mut expiring = player.clone();
+        expiring.contract.contract_end_date = date(2027, 1, 1);
+        assert!(engine.market_value(&expiring, today) < value);
+
+        let mut weaker_league = player.clone();
+        weaker_league.contract.league_strength = 40.0;
+        assert!(engine.market_value(&weaker_league, today) < value);
+
+        let mut free_agent = player.clone();
+        free_agent.contract.club_id = Uuid::nil();
+        assert!(engine.market_value(&free_agent, today) < value);
+    }
+
+    #[test]
+    fn test_weekly_values_are_kept_for_every_player() {
+        let engine = ValuationEngine::new();
+        let player = create_test_player();
+        let mut game_state = GameState::new(player.clone(), player.contract.club_id);
+        game_state.players = vec![create_test_player(), create_test_player()];
+
+        engine.record_weekly_values(&mut game_state, date(2026, 8, 3));
+        game_state.player.form = 90.0;
+        engine.record_weekly_values(&mut game_state, date(2026, 8, 10));
+
+        assert_eq!(game_state.market_values.len(), 3);
+        let history = &game_state.market_values[&player.id];
+        assert_eq!(history.len(), 2);
+        assert!(engine.value_trend(history).unwrap() > 0.0);
+
+        // Only the last three seasons of weeks are kept
+        let mut history = Vec::new();
+        for week in 0..200 {
+            engine.record_value(&mut history, &player, date(2026, 8, 3) + chrono::Duration::weeks(week));
+        }
+        assert_eq!(history.len(), MAX_VALUE_POINTS);
+    }
+}
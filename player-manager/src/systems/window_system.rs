@@ -0,0 +1,412 @@
+// src/systems/window_system.rs
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::core::game_state::{
+    InterestLevel, PreAgreement, TransferOffer as HeldOffer, TransferSystemState, TransferWindows,
+};
+use crate::core::time_engine::{EventPriority, ScheduledEvent, ScheduledEventType, TimeEngine};
+use crate::entities::{ContractTerms, Player, SquadRole, Team};
+use crate::systems::transfer_system::{BidResponse, TransferOffer};
+use crate::systems::{FeeEngine, TransferEngine};
+
+/// Final days of a window in which clubs scramble to get deals done
+pub const DEADLINE_DAYS: i64 = 3;
+/// Extra share of the player's valuation bid for each day closer to the deadline
+const DEADLINE_ESCALATION: f32 = 0.1;
+/// Hour of the day the first last-minute bid lands; the rest follow hourly
+const DEADLINE_BID_HOUR: u32 = 17;
+/// Days a released pre-agreement stays open once the window opens
+const RELEASED_OFFER_DAYS: i64 = 14;
+
+/// The TransferWindowEngine keeps transfers inside the calendar's windows
+/// It holds offers made while a window is shut and drives the deadline day scramble
+pub struct TransferWindowEngine;
+
+impl Default for TransferWindowEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TransferWindowEngine {
+    /// Creates a new TransferWindowEngine instance
+    pub fn new() -> Self {
+        TransferWindowEngine
+    }
+
+    /// Files a club's offer: live while the window is open, otherwise held as a pre-agreement
+    ///
+    /// A newer offer from the same club replaces its old one.
+    pub fn submit_offer(
+        &self,
+        state: &mut TransferSystemState,
+        offer: &TransferOffer,
+        windows: &TransferWindows,
+        today: NaiveDate,
+    ) -> OfferStatus {
+        state.active_offers.retain(|held| held.buying_club_id != offer.buying_club_id);
+        state.pre_agreements.retain(|held| held.offer.buying_club_id != offer.buying_club_id);
+
+        let held = HeldOffer {
+            id: offer.id,
+            buying_club_id: offer.buying_club_id,
+            target_player_id: offer.target_player_id,
+            offered_wage: offer.offered_wage,
+            contract_length_years: offer.contract_length_years,
+            transfer_fee: offer.transfer_fee,
+            offer_date: offer.offer_date,
+            expiry_date: offer.expiry_date,
+            fee_structure: offer.fee_structure.clone(),
+        };
+        if windows.is_open(today) {
+            state.active_offers.push(held);
+            return OfferStatus::Active;
+        }
+
+        let completes_on = windows.next_opening(today);
+        state.pre_agreements.push(PreAgreement {
+            offer: held,
+            agreed_on: today,
+            completes_on,
+        });
+        OfferStatus::PreAgreed(completes_on)
+    }
+
+    /// Turns pre-agreements into live offers once their window has opened
+    pub fn release_pre_agreements(
+        &self,
+        state: &mut TransferSystemState,
+        windows: &TransferWindows,
+        today: NaiveDate,
+    ) -> Vec<HeldOffer> {
+        if !windows.is_open(today) {
+            return Vec::new();
+        }
+
+        let (due, waiting): (Vec<PreAgreement>, Vec<PreAgreement>) = std::mem::take(&mut state.pre_agreements)
+            .into_iter()
+            .partition(|pre_agreement| pre_agreement.completes_on <= today);
+        state.pre_agreements = waiting;
+
+        let released: Vec<HeldOffer> = due
+            .into_iter()
+            .map(|pre_agreement| {
+                let mut offer = pre_agreement.offer;
+                offer.expiry_date = (today + chrono::Duration::days(RELEASED_OFFER_DAYS))
+                    .and_time(chrono::NaiveTime::MIN)
+                    .and_utc();
+                offer
+            })
+            .collect();
+        state.active_offers.extend(released.iter().cloned());
+        released
+    }
+
+    /// Days until the window shuts, if it is in its final days (0 on deadline day itself)
+    pub fn deadline_days_left(&self, windows: &TransferWindows, today: NaiveDate) -> Option<i64> {
+        windows
+            .closing_date(today)
+            .map(|closes| (closes - today).num_days())
+            .filter(|days_left| *days_left < DEADLINE_DAYS)
+    }
+
+    /// Last-minute bids from interested clubs, growing richer and more numerous as the deadline nears
+    pub fn deadline_day_bids(
+        &self,
+        player: &Player,
+        state: &TransferSystemState,
+        all_teams: &[Team],
+        windows: &TransferWindows,
+        today: NaiveDate,
+    ) -> Vec<DeadlineBid> {
+        let Some(days_left) = self.deadline_days_left(windows, today) else {
+            return Vec::new();
+        };
+        if state.free_agent_since.is_some() {
+            return Vec::new();
+        }
+
+        // Clubs further from a deal only join the scramble as time runs out
+        let min_rank = match days_left {
+            0 => self.level_rank(&InterestLevel::Scouting),
+            1 => self.level_rank(&InterestLevel::Shortlisted),
+            _ => self.level_rank(&InterestLevel::PreparingOffer),
+        };
+        let urgency = (DEADLINE_DAYS - days_left) as f32;
+        let transfer = TransferEngine::new();
+        let valuation = transfer.selling_valuation(player, today);
+
+        let mut bids: Vec<DeadlineBid> = state
+            .interested_clubs
+            .values()
+            .filter(|interest| self.level_rank(&interest.interest_level) >= min_rank)
+            .filter_map(|interest| {
+                all_teams
+                    .iter()
+                    .find(|team| team.id == interest.club_id && team.id != player.contract.club_id)
+            })
+            .map(|team| {
+                let offer = transfer.generate_transfer_offer(player, team, &player.contract, today);
+                DeadlineBid {
+                    club_id: team.id,
+                    fee: (valuation * (0.85 + DEADLINE_ESCALATION * urgency)).min(transfer.transfer_budget(team)),
+                    wage: offer.offered_wage * (1.0 + 0.05 * urgency),
+                    length_years: offer.contract_length_years,
+                    squad_role: player.contract.squad_role,
+                    days_left,
+                }
+            })
+            .collect();
+        bids.sort_by(|a, b| b.fee.total_cmp(&a.fee));
+        bids
+    }
+
+    /// Queues the day's bids so each one pauses the game for the player's decision
+    pub fn schedule_deadline_bids(&self, bids: &[DeadlineBid], time_engine: &mut TimeEngine, today: NaiveDate) {
+        for (i, bid) in bids.iter().enumerate() {
+            let hour = (DEADLINE_BID_HOUR + i as u32).min(23);
+            let mut event = ScheduledEvent::new(
+                today.and_hms_opt(hour, 0, 0).unwrap().and_utc(),
+                ScheduledEventType::DeadlineDayBid(bid.club_id),
+                EventPriority::High,
+                true,
+            );
+            event.data = serde_json::to_value(bid).ok();
+            time_engine.schedule_event(event);
+        }
+    }
+
+    /// The bid carried by a `DeadlineDayBid` event
+    pub fn bid_from_event(&self, event: &ScheduledEvent) -> Option<DeadlineBid> {
+        if !matches!(event.event_type, ScheduledEventType::DeadlineDayBid(_)) {
+            return None;
+        }
+        serde_json::from_value(event.data.clone()?).ok()
+    }
+
+    /// The player says yes to a last-minute bid; the selling club has the final say
+    ///
+    /// The move goes through on `terms`, the bid's own or those the player's agent
+    /// agreed, only if the club accepts and the window is still open. The up-front
+    /// fee moves between the clubs' balances.
+    /// A refusal hurts an ambitious player's morale.
+    #[allow(clippy::too_many_arguments)]
+    pub fn accept_deadline_bid(
+        &self,
+        player: &mut Player,
+        state: &mut TransferSystemState,
+        clubs: &mut [Team],
+        bid: &DeadlineBid,
+        terms: &ContractTerms,
+        windows: &TransferWindows,
+        today: NaiveDate,
+    ) -> BidResponse {
+        if !windows.is_open(today) {
+            return BidResponse::Rejected("The window has shut".to_string());
+        }
+        let (Some(buying_club), Some(selling_club)) = (
+            clubs.iter().find(|club| club.id == bid.club_id).cloned(),
+            clubs.iter().find(|club| club.id == player.contract.club_id).cloned(),
+        ) else {
+            return BidResponse::Rejected("The clubs involved can't be found".to_string());
+        };
+
+        let transfer = TransferEngine::new();
+        let response = transfer.evaluate_bid(player, &selling_club, bid.fee, state.transfer_request.is_some(), today);
+        match response {
+            BidResponse::Accepted | BidResponse::ClauseTriggered => {
+                let fees = FeeEngine::new();
+                let deal = fees.structure_fee(player, &buying_club, bid.fee, today);
+                transfer.complete_transfer(player, &buying_club, terms, windows, today);
+                let payments = fees.record_transfer(&mut state.transfer_records, player.id, selling_club.id, buying_club.id, deal, today);
+                fees.apply_payments(clubs, &payments);
+                state.interested_clubs.remove(&buying_club.id);
+                state.active_offers.retain(|offer| offer.buying_club_id != buying_club.id);
+                state.transfer_request = None;
+            }
+            BidResponse::Rejected(_) | BidResponse::Countered(_) => {
+                transfer.react_to_blocked_move(player, &buying_club, &selling_club);
+            }
+        }
+        response
+    }
+
+    fn level_rank(&self, level: &InterestLevel) -> u8 {
+        match level {
+            InterestLevel::Monitoring => 1,
+            InterestLevel::Scouting => 2,
+            InterestLevel::Shortlisted => 3,
+            InterestLevel::PreparingOffer => 4,
+            InterestLevel::OfficialOffer => 5,
+        }
+    }
+}
+
+/// Whether an offer is live or waiting for the window to open
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum OfferStatus {
+    Active,
+    PreAgreed(NaiveDate), // The day the window opens and the offer goes live
+}
+
+/// A club's last-minute bid in the final days of a window
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeadlineBid {
+    pub club_id: Uuid,
+    pub fee: f32,
+    pub wage: f32,
+    pub length_years: u8,
+    pub squad_role: SquadRole,
+    pub days_left: i64, // Days until the window shuts
+}
+
+impl DeadlineBid {
+    /// The personal terms the bid comes with, before any agent talks
+    pub fn terms(&self) -> ContractTerms {
+        ContractTerms {
+            wage: self.wage,
+            length_years: self.length_years,
+            squad_role: self.squad_role,
+            release_clause: None,
+            signing_on_fee: 0.0,
+            performance_bonuses: vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::game_state::TransferInterest;
+    use crate::core::time_engine::PauseReason;
+    use crate::test_support::{create_test_player, create_test_team, date};
+
+    #[test]
+    fn test_offers_outside_window_held_until_it_opens() {
+        let engine = TransferWindowEngine::new();
+        let player = create_test_player();
+        let club = create_test_team();
+        let mut state = TransferSystemState::new();
+        let windows = TransferWindows::for_country("England", 2025);
+        assert!(windows.is_open(date(2025, 9, 1)));
+        assert!(!TransferWindows::for_country("Sweden", 2025).is_open(date(2025, 9, 1)));
+
+        let offer = TransferEngine::new().generate_transfer_offer(&player, &club, &player.contract, date(2025, 10, 1));
+        assert_eq!(engine.submit_offer(&mut state, &offer, &windows, date(2025, 10, 1)), OfferStatus::PreAgreed(date(2026, 1, 1)));
+        // A second offer from the same club replaces the first
+        assert_eq!(engine.submit_offer(&mut state, &offer, &windows, date(2025, 10, 8)), OfferStatus::PreAgreed(date(2026, 1, 1)));
+        assert_eq!(state.pre_agreements.len(), 1);
+        assert!(state.active_offers.is_empty());
+
+        assert!(engine.release_pre_agreements(&mut state, &windows, date(2025, 12, 31)).is_empty());
+        let released = engine.release_pre_agreements(&mut state, &windows, date(2026, 1, 1));
+
+        assert_eq!(released.len(), 1);
+        assert_eq!(released[0].buying_club_id, club.id);
+        assert!(state.pre_agreements.is_empty());
+        assert_eq!(state.active_offers.len(), 1);
+        assert_eq!(engine.submit_offer(&mut state, &offer, &windows, date(2026, 1, 2)), OfferStatus::Active);
+        assert_eq!(state.active_offers.len(), 1);
+    }
+
+    #[test]
+    fn test_deadline_bids_escalate_and_pause_the_game() {
+        let engine = TransferWindowEngine::new();
+        let player = create_test_player();
+        let keen = create_test_team();
+        let shortlisting = create_test_team();
+        let scouting = create_test_team();
+        let teams = vec![keen.clone(), shortlisting.clone(), scouting.clone()];
+        let mut state = TransferSystemState::new();
+        for (club, level) in [
+            (keen.id, InterestLevel::PreparingOffer),
+            (shortlisting.id, InterestLevel::Shortlisted),
+            (scouting.id, InterestLevel::Scouting),
+        ] {
+            state.interested_clubs.insert(club, TransferInterest {
+                club_id: club,
+                interest_level: level,
+                last_evaluation_date: date(2025, 8, 1).and_time(chrono::NaiveTime::MIN).and_utc(),
+                evaluation_score: 60.0,
+                scout_visits: 0,
+            });
+        }
+        // The English summer window shuts on 1 September
+        let windows = TransferWindows::for_country("England", 2025);
+
+        assert!(engine.deadline_day_bids(&player, &state, &teams, &windows, date(2025, 8, 29)).is_empty());
+        let early = engine.deadline_day_bids(&player, &state, &teams, &windows, date(2025, 8, 30));
+        let late = engine.deadline_day_bids(&player, &state, &teams, &windows, date(2025, 8, 31));
+        let deadline = engine.deadline_day_bids(&player, &state, &teams, &windows, date(2025, 9, 1));
+
+        assert_eq!((early.len(), late.len(), deadline.len()), (1, 2, 3));
+        assert_eq!(early[0].club_id, keen.id);
+        assert!(late[0].fee > early[0].fee);
+        assert!(deadline[0].fee > late[0].fee);
+        assert_eq!(deadline[0].days_left, 0);
+
+        let mut time_engine = TimeEngine::new(date(2025, 9, 1).and_time(chrono::NaiveTime::MIN).and_utc());
+        engine.schedule_deadline_bids(&deadline, &mut time_engine, date(2025, 9, 1));
+        while !time_engine.is_paused {
+            time_engine.advance_time().unwrap();
+        }
+        let Some(PauseReason::HighPriorityEvent(event)) = time_engine.pause_reason.clone() else {
+            panic!("deadline bid should pause the game");
+        };
+        let bid = engine.bid_from_event(&event).unwrap();
+        assert_eq!(bid.club_id, deadline[0].club_id);
+        assert_eq!(bid.fee, deadline[0].fee);
+    }
+
+    #[test]
+    fn test_accepted_deadline_bid_completes_before_the_window_shuts() {
+        let engine = TransferWindowEngine::new();
+        let mut player = create_test_player();
+        let mut seller = create_test_team();
+        seller.id = player.contract.club_id;
+        let mut buyer = create_test_team();
+        buyer.reputation = 90.0;
+        let mut state = TransferSystemState::new();
+        let windows = TransferWindows::for_country("England", 2025);
+        let valuation = TransferEngine::new().selling_valuation(&player, date(2025, 9, 1));
+        let bid = DeadlineBid {
+            club_id: buyer.id,
+            fee: valuation * 1.15,
+            wage: 90_000.0,
+            length_years: 4,
+            squad_role: SquadRole::FirstTeam,
+            days_left: 0,
+        };
+        let mut clubs = vec![seller.clone(), buyer.clone()];
+
+        // Too late: the window has shut
+        let response = engine.accept_deadline_bid(&mut player, &mut state, &mut clubs, &bid, &bid.terms(), &windows, date(2025, 9, 2));
+        assert!(matches!(response, BidResponse::Rejected(_)));
+
+        // A lowball bid is knocked back and the player feels blocked
+        let lowball = DeadlineBid { fee: valuation * 0.5, ..bid.clone() };
+        let morale = player.morale;
+        let response = engine.accept_deadline_bid(&mut player, &mut state, &mut clubs, &lowball, &lowball.terms(), &windows, date(2025, 9, 1));
+        assert!(!matches!(response, BidResponse::Accepted));
+        assert!(player.morale < morale);
+        assert_eq!(player.contract.club_id, seller.id);
+
+        // The move goes through on the terms the agent agreed, clauses and all
+        let agreed = ContractTerms {
+            release_clause: Some(valuation * 3.0),
+            signing_on_fee: 200_000.0,
+            ..bid.terms()
+        };
+        let response = engine.accept_deadline_bid(&mut player, &mut state, &mut clubs, &bid, &agreed, &windows, date(2025, 9, 1));
+        assert!(matches!(response, BidResponse::Accepted));
+        assert_eq!(player.contract.club_id, buyer.id);
+        assert_eq!(player.contract.wage, 90_000.0);
+        assert_eq!(player.contract.release_clause, Some(valuation * 3.0));
+        assert_eq!(player.contract.contract_end_date, date(2029, 9, 1));
+        let upfront = state.transfer_records.last().unwrap().fee.upfront;
+        assert_eq!(clubs[0].finances.balance, seller.finances.balance + upfront);
+        assert_eq!(clubs[1].finances.balance, buyer.finances.balance - upfront);
+    }
+}
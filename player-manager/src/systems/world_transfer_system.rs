@@ -0,0 +1,714 @@
+// src/systems/world_transfer_system.rs
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::core::game_state::{FeeStructure, GameState};
+use crate::entities::{ContractTerms, Player, SquadRole, Team};
+use crate::systems::transfer_system::PositionGroup;
+use crate::systems::fee_system::FeePayment;
+use crate::systems::{ClubFinanceEngine, FeeEngine, LoanEngine, TransferEngine};
+
+/// Age from which a fringe player is let go rather than kept on
+const RELEASE_AGE: u8 = 33;
+/// How far below the club's level an ageing player must be before they are released
+const RELEASE_ABILITY_GAP: f32 = 10.0;
+/// Oldest player AI clubs will pay a fee for
+const MAX_SIGNING_AGE: u8 = 29;
+/// Opening bid as a share of the selling club's valuation
+const OPENING_BID_SHARE: f32 = 0.9;
+/// Share of the valuation a club in debt settles for when it has to sell
+const DISTRESSED_SALE_SHARE: f32 = 0.85;
+/// Ability edge a new signing needs over the player to push them down the pecking order
+const RIVAL_MARGIN: f32 = 5.0;
+/// Relationship at which a teammate counts as a close friend
+const CLOSE_FRIEND_RELATIONSHIP: f32 = 70.0;
+
+/// The WorldTransferEngine runs the transfer market between AI clubs
+/// It releases, loans and buys players so squads change around the player from window to window
+pub struct WorldTransferEngine;
+
+impl Default for WorldTransferEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WorldTransferEngine {
+    /// Creates a new WorldTransferEngine instance
+    pub fn new() -> Self {
+        WorldTransferEngine
+    }
+
+    /// Runs a day of AI transfer business for every club whose window is open
+    ///
+    /// Instalments that have fallen due are paid. Loans that have run their course end, ageing
+    /// fringe players and expired contracts are released, former clubs use buy-back clauses,
+    /// clubs loan out one prospect and make at most one signing where they have a gap.
+    /// Loans, sales and signings happen once per window for each club, with one more go on deadline day.
+    /// Moves into or out of the player's club change their pecking order.
+    pub fn run_window(&self, game_state: &mut GameState, today: NaiveDate) -> WorldTransferReport {
+        let open: Vec<Uuid> = game_state
+            .clubs
+            .iter()
+            .filter(|club| game_state.season.windows_for(&club.country).is_open(today))
+            .map(|club| club.id)
+            .collect();
+        let acting: Vec<Uuid> = game_state
+            .clubs
+            .iter()
+            .filter(|club| self.market_day(game_state, club, today))
+            .map(|club| club.id)
+            .collect();
+        for club_id in &acting {
+            game_state.transfer_system.market_days.insert(*club_id, today);
+        }
+
+        let payments = FeeEngine::new().pay_instalments(game_state, today);
+        let mut moves = self.end_loans(game_state, today);
+        moves.extend(self.release_players(game_state, &open, today));
+        moves.extend(self.exercise_buy_backs(game_state, &open, today));
+        moves.extend(self.loan_out_prospects(game_state, &open, &acting, today));
+        moves.extend(self.force_sales(game_state, &open, &acting, today));
+        let signings = self.make_signings(game_state, &acting, &moves, today);
+        moves.extend(signings);
+
+        let impacts = self.apply_to_player(game_state, &moves);
+        WorldTransferReport { moves, impacts, payments }
+    }
+
+    /// Whether the club has market business to do today: its first day in an open window, or deadline day
+    fn market_day(&self, game_state: &GameState, club: &Team, today: NaiveDate) -> bool {
+        let windows = game_state.season.windows_for(&club.country);
+        let (Some(opening), Some(closing)) = (windows.opening_date(today), windows.closing_date(today)) else {
+            return false;
+        };
+        match game_state.transfer_system.market_days.get(&club.id) {
+            Some(last) if *last >= opening => closing == today && *last < today,
+            _ => true,
+        }
+    }
+
+    /// Brings AI players back from loans that have reached their end date
+    ///
+    /// Players whose loan club takes up its purchase option decide whether to stay; the fee is
+    /// paid as the move goes through.
+    fn end_loans(&self, game_state: &mut GameState, today: NaiveDate) -> Vec<WorldTransfer> {
+        let loans = LoanEngine::new();
+        let mut moves = Vec::new();
+
+        for player in game_state.players.iter_mut() {
+            let Some(review) = loans.process_day(player, &game_state.competitions, today) else {
+                continue;
+            };
+            let records = &mut game_state.transfer_system.transfer_records;
+            if loans.wants_purchase(player, &review)
+                && loans.accept_purchase(player, &review, records, &mut game_state.clubs, today)
+            {
+                moves.push(WorldTransfer {
+                    player_id: player.id,
+                    from_club_id: review.parent_club_id,
+                    to_club_id: Some(review.loan_club_id),
+                    kind: WorldMoveKind::Transfer,
+                    fee: review.purchase_fee.unwrap_or(0.0),
+                });
+            } else {
+                moves.push(WorldTransfer {
+                    player_id: player.id,
+                    from_club_id: review.loan_club_id,
+                    to_club_id: Some(review.parent_club_id),
+                    kind: WorldMoveKind::LoanReturn,
+                    fee: 0.0,
+                });
+            }
+        }
+
+        for transfer in &moves {
+            self.move_in_squads(&mut game_state.clubs, transfer);
+        }
+        moves
+    }
+
+    /// Lets go of players whose contracts have run out and ageing players the club has outgrown
+    fn release_players(&self, game_state: &mut GameState, open: &[Uuid], today: NaiveDate) -> Vec<WorldTransfer> {
+        let mut moves = Vec::new();
+
+        for club in game_state.clubs.iter().filter(|club| open.contains(&club.id)) {
+            for player in game_state.players.iter_mut() {
+                if player.contract.club_id != club.id || player.contract.loan.is_some() {
+                    continue;
+                }
+                let expired = today >= player.contract.contract_end_date;
+                let outgrown = player.age >= RELEASE_AGE
+                    && player.ability_score() < club.reputation - RELEASE_ABILITY_GAP;
+                if !expired && !outgrown {
+                    continue;
+                }
+
+                player.contract.club_id = Uuid::nil();
+                player.contract.wage = 0.0;
+                player.contract.release_clause = None;
+                moves.push(WorldTransfer {
+                    player_id: player.id,
+                    from_club_id: club.id,
+                    to_club_id: None,
+                    kind: WorldMoveKind::Release,
+                    fee: 0.0,
+                });
+            }
+        }
+
+        for transfer in &moves {
+            self.move_in_squads(&mut game_state.clubs, transfer);
+        }
+        moves
+    }
+
+    /// Former clubs holding a buy-back clause bring players home when they want them and can pay
+    fn exercise_buy_backs(&self, game_state: &mut GameState, open: &[Uuid], today: NaiveDate) -> Vec<WorldTransfer> {
+        let fees = FeeEngine::new();
+        let transfer = TransferEngine::new();
+        let mut moves = Vec::new();
+
+        let returns: Vec<(Uuid, Uuid, Uuid)> = game_state
+            .players
+            .iter()
+            .filter_map(|player| {
+                let record = fees.buy_back_clause(&game_state.transfer_system.transfer_records, player, today)?;
+                let clause = record.fee.buy_back.as_ref()?;
+                let club = game_state.clubs.iter().find(|club| club.id == record.from_club_id)?;
+                (open.contains(&club.id) && fees.will_exercise(player, club, clause))
+                    .then_some((player.id, player.contract.club_id, club.id))
+            })
+            .collect();
+        for (player_id, from_club_id, club_id) in returns {
+            let Some(club) = game_state.clubs.iter().find(|club| club.id == club_id).cloned() else {
+                continue;
+            };
+            let windows = game_state.season.windows_for(&club.country).clone();
+            let Some(player) = game_state.players.iter_mut().find(|player| player.id == player_id) else {
+                continue;
+            };
+            let terms = self.signing_terms(player, &club, today);
+            if !transfer.complete_transfer(player, &club, &terms, &windows, today) {
+                continue;
+            }
+
+            let records = &mut game_state.transfer_system.transfer_records;
+            let payments = fees.exercise_buy_back(records, player_id, from_club_id, today);
+            fees.apply_payments(&mut game_state.clubs, &payments);
+            let homecoming = WorldTransfer {
+                player_id,
+                from_club_id,
+                to_club_id: Some(club_id),
+                kind: WorldMoveKind::Transfer,
+                fee: payments.first().map_or(0.0, |payment| payment.amount),
+            };
+            self.move_in_squads(&mut game_state.clubs, &homecoming);
+            moves.push(homecoming);
+        }
+        moves
+    }
+
+    /// Sends one loan candidate from each club to a lower-tier club that will play them
+    fn loan_out_prospects(&self, game_state: &mut GameState, open: &[Uuid], acting: &[Uuid], today: NaiveDate) -> Vec<WorldTransfer> {
+        let loans = LoanEngine::new();
+        let mut moves = Vec::new();
+
+        for club in game_state.clubs.iter().filter(|club| acting.contains(&club.id)) {
+            let offer = game_state
+                .players
+                .iter()
+                .filter(|player| player.contract.club_id == club.id)
+                .find_map(|player| {
+                    loans
+                        .generate_loan_offers(player, club, &game_state.clubs, today)
+                        .into_iter()
+                        .find(|offer| open.contains(&offer.loan_club_id))
+                        .map(|offer| (player.id, offer))
+                });
+            let Some((player_id, offer)) = offer else {
+                continue;
+            };
+            if let Some(player) = game_state.players.iter_mut().find(|player| player.id == player_id) {
+                loans.accept_loan(player, &offer, today);
+                moves.push(WorldTransfer {
+                    player_id,
+                    from_club_id: club.id,
+                    to_club_id: Some(offer.loan_club_id),
+                    kind: WorldMoveKind::Loan,
+                    fee: 0.0,
+                });
+            }
+        }
+
+        for transfer in &moves {
+            self.move_in_squads(&mut game_state.clubs, transfer);
+        }
+        moves
+    }
+
+    /// Each club in debt sells its most valuable player to the biggest club that can afford them
+    fn force_sales(&self, game_state: &mut GameState, open: &[Uuid], acting: &[Uuid], today: NaiveDate) -> Vec<WorldTransfer> {
+        let transfer = TransferEngine::new();
+        let finance = ClubFinanceEngine::new();
+        let mut moves = Vec::new();
+
+        let sellers: Vec<Uuid> = game_state.clubs.iter()
+            .filter(|club| acting.contains(&club.id) && finance.in_debt(club))
+            .map(|club| club.id)
+            .collect();
+        for seller_id in sellers {
+            let target = game_state.players.iter()
+                .filter(|player| player.contract.club_id == seller_id && player.contract.loan.is_none())
+                .map(|player| (player.id, transfer.selling_valuation(player, today)))
+                .max_by(|a, b| a.1.total_cmp(&b.1));
+            let Some((target_id, valuation)) = target else {
+                continue;
+            };
+            let asking = valuation * DISTRESSED_SALE_SHARE;
+            let buyer = game_state.clubs.iter()
+                .filter(|club| club.id != seller_id && open.contains(&club.id) && !finance.in_debt(club))
+                .filter(|club| self.budget(club) >= asking)
+                .max_by(|a, b| a.reputation.total_cmp(&b.reputation))
+                .map(|club| club.id);
+            let Some(buyer_id) = buyer else {
+                continue;
+            };
+            if let Some(sale) = self.sign_player(game_state, target_id, buyer_id, asking, today) {
+                moves.push(sale);
+            }
+        }
+        moves
+    }
+
+    /// Each club doing business today with money to spend tries to sign the player who best fills a gap
+    ///
+    /// Richer clubs shop first; clubs in debt can't buy. The selling club must agree a fee through the usual bidding.
+    fn make_signings(&self, game_state: &mut GameState, acting: &[Uuid], earlier: &[WorldTransfer], today: NaiveDate) -> Vec<WorldTransfer> {
+        let transfer = TransferEngine::new();
+        let finance = ClubFinanceEngine::new();
+        let mut moves: Vec<WorldTransfer> = Vec::new();
+
+        let mut buyers: Vec<Uuid> = acting.to_vec();
+        buyers.sort_by(|a, b| {
+            let reputation = |id: &Uuid| game_state.clubs.iter().find(|club| club.id == *id).map_or(0.0, |club| club.reputation);
+            reputation(b).total_cmp(&reputation(a))
+        });
+
+        for buyer_id in buyers {
+            // Squads are read fresh each time so earlier deals count
+            let everyone: Vec<Player> = std::iter::once(game_state.player.clone())
+                .chain(game_state.players.iter().cloned())
+                .collect();
+            let Some(buyer) = game_state.clubs.iter().find(|club| club.id == buyer_id) else {
+                continue;
+            };
+            if finance.in_debt(buyer) {
+                continue;
+            }
+            let budget = self.budget(buyer);
+
+            let target = game_state
+                .players
+                .iter()
+                .filter(|player| player.contract.club_id != buyer.id && !player.contract.club_id.is_nil())
+                .filter(|player| player.contract.loan.is_none() && player.age <= MAX_SIGNING_AGE)
+                .filter(|player| !moves.iter().chain(earlier).any(|done| done.player_id == player.id))
+                .filter(|player| transfer.selling_valuation(player, today) <= budget)
+                .filter_map(|player| {
+                    transfer
+                        .evaluate_club_interest(player, buyer, &everyone, 1.0, today)
+                        .map(|interest| (player, interest.evaluation_score))
+                })
+                .max_by(|a, b| a.1.total_cmp(&b.1))
+                .map(|(player, _)| player);
+            let Some(target) = target else {
+                continue;
+            };
+            let opening_bid = transfer.selling_valuation(target, today) * OPENING_BID_SHARE;
+            let target_id = target.id;
+            if let Some(signing) = self.sign_player(game_state, target_id, buyer_id, opening_bid, today) {
+                moves.push(signing);
+            }
+        }
+        moves
+    }
+
+    /// Bids for a player and, if the clubs agree a fee, moves them and settles the money
+    fn sign_player(&self, game_state: &mut GameState, player_id: Uuid, buyer_id: Uuid, opening_bid: f32, today: NaiveDate) -> Option<WorldTransfer> {
+        let transfer = TransferEngine::new();
+        let target = game_state.players.iter().find(|player| player.id == player_id)?;
+        let buyer = game_state.clubs.iter().find(|club| club.id == buyer_id)?;
+        let seller = game_state.clubs.iter().find(|club| club.id == target.contract.club_id)?;
+
+        let outcome = transfer.bid_for_player(target, buyer, seller, opening_bid, self.budget(buyer), false, today);
+        let fee = outcome.agreed_fee?;
+
+        let terms = self.signing_terms(target, buyer, today);
+        let deal = FeeEngine::new().structure_fee(target, buyer, fee, today);
+        let signing = WorldTransfer {
+            player_id,
+            from_club_id: seller.id,
+            to_club_id: Some(buyer_id),
+            kind: WorldMoveKind::Transfer,
+            fee,
+        };
+        let buyer = buyer.clone();
+        let windows = game_state.season.windows_for(&buyer.country).clone();
+        let player = game_state.players.iter_mut().find(|player| player.id == player_id)?;
+        if !transfer.complete_transfer(player, &buyer, &terms, &windows, today) {
+            return None;
+        }
+
+        self.move_in_squads(&mut game_state.clubs, &signing);
+        self.settle_fee(game_state, &signing, deal, today);
+        Some(signing)
+    }
+
+    /// The contract a club offers a player it is signing, keeping their place in the pecking order
+    fn signing_terms(&self, player: &Player, club: &Team, today: NaiveDate) -> ContractTerms {
+        let offer = TransferEngine::new().generate_transfer_offer(player, club, &player.contract, today);
+        ContractTerms {
+            wage: offer.offered_wage,
+            length_years: offer.contract_length_years,
+            squad_role: player.contract.squad_role,
+            release_clause: None,
+            signing_on_fee: 0.0,
+            performance_bonuses: vec![],
+        }
+    }
+
+    /// What a club can spend on a fee: its transfer budget, as far as its cash goes
+    fn budget(&self, club: &Team) -> f32 {
+        TransferEngine::new().transfer_budget(club).min(club.finances.balance.max(0.0))
+    }
+
+    /// How the window's business changes things for the player: rivals arriving or leaving, friends moving on
+    fn apply_to_player(&self, game_state: &mut GameState, moves: &[WorldTransfer]) -> Vec<SquadImpact> {
+        let club_id = game_state.player.contract.club_id;
+        let group = PositionGroup::for_position(game_state.player.primary_position);
+        let ability = game_state.player.ability_score();
+        let ego = game_state.player.hidden.ego as f32 / 100.0;
+        let mut impacts = Vec::new();
+
+        for transfer in moves {
+            let Some(other) = game_state.players.iter().find(|player| player.id == transfer.player_id) else {
+                continue;
+            };
+            let rival = PositionGroup::for_position(other.primary_position) == group
+                && other.ability_score() > ability + RIVAL_MARGIN;
+            let arrived = transfer.to_club_id == Some(club_id) && transfer.kind != WorldMoveKind::LoanReturn;
+            let left = transfer.from_club_id == club_id;
+
+            let player = &mut game_state.player;
+            if arrived && rival {
+                let role_before = player.contract.squad_role;
+                player.contract.squad_role = self.demote(role_before);
+                let morale_change = -5.0 * (0.5 + ego);
+                player.morale = (player.morale + morale_change).clamp(0.0, 100.0);
+                impacts.push(SquadImpact {
+                    player_id: transfer.player_id,
+                    kind: SquadImpactKind::RivalArrived,
+                    role_before,
+                    role_after: player.contract.squad_role,
+                    morale_change,
+                });
+            } else if left && rival {
+                let role_before = player.contract.squad_role;
+                player.contract.squad_role = self.promote(role_before);
+                let morale_change = 3.0;
+                player.morale = (player.morale + morale_change).clamp(0.0, 100.0);
+                impacts.push(SquadImpact {
+                    player_id: transfer.player_id,
+                    kind: SquadImpactKind::RivalDeparted,
+                    role_before,
+                    role_after: player.contract.squad_role,
+                    morale_change,
+                });
+            }
+
+            let friendship = player.relationships.get(&transfer.player_id).copied().unwrap_or(0.0);
+            if left && friendship >= CLOSE_FRIEND_RELATIONSHIP {
+                let morale_change = -5.0 * friendship / 100.0;
+                player.morale = (player.morale + morale_change).clamp(0.0, 100.0);
+                impacts.push(SquadImpact {
+                    player_id: transfer.player_id,
+                    kind: SquadImpactKind::FriendLeft,
+                    role_before: player.contract.squad_role,
+                    role_after: player.contract.squad_role,
+                    morale_change,
+                });
+            }
+        }
+        impacts
+    }
+
+    /// Updates both clubs' squad lists for a move
+    fn move_in_squads(&self, clubs: &mut [Team], transfer: &WorldTransfer) {
+        if let Some(club) = clubs.iter_mut().find(|club| club.id == transfer.from_club_id) {
+            club.squad.retain(|id| *id != transfer.player_id);
+        }
+        if let Some(club) = transfer.to_club_id.and_then(|to| clubs.iter_mut().find(|club| club.id == to)) {
+            if !club.squad.contains(&transfer.player_id) {
+                club.squad.push(transfer.player_id);
+            }
+        }
+    }
+
+    /// Records the fee deal and pays what is due on signing, including any sell-on owed
+    fn settle_fee(&self, game_state: &mut GameState, transfer: &WorldTransfer, deal: FeeStructure, today: NaiveDate) {
+        let Some(to_club_id) = transfer.to_club_id else {
+            return;
+        };
+        let fees = FeeEngine::new();
+        let records = &mut game_state.transfer_system.transfer_records;
+        let payments = fees.record_transfer(records, transfer.player_id, transfer.from_club_id, to_club_id, deal, today);
+        fees.apply_payments(&mut game_state.clubs, &payments);
+    }
+
+    /// One step down the pecking order
+    fn demote(&self, role: SquadRole) -> SquadRole {
+        match role {
+            SquadRole::KeyPlayer => SquadRole::FirstTeam,
+            SquadRole::FirstTeam => SquadRole::Rotation,
+            SquadRole::Rotation | SquadRole::Backup => SquadRole::Backup,
+            SquadRole::Prospect => SquadRole::Prospect,
+        }
+    }
+
+    /// One step up the pecking order
+    fn promote(&self, role: SquadRole) -> SquadRole {
+        match role {
+            SquadRole::KeyPlayer | SquadRole::FirstTeam => SquadRole::KeyPlayer,
+            SquadRole::Rotation => SquadRole::FirstTeam,
+            SquadRole::Backup => SquadRole::Rotation,
+            SquadRole::Prospect => SquadRole::Prospect,
+        }
+    }
+}
+
+/// One move in the AI transfer market
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorldTransfer {
+    pub player_id: Uuid,
+    pub from_club_id: Uuid,
+    pub to_club_id: Option<Uuid>, // None when the player is released
+    pub kind: WorldMoveKind,
+    pub fee: f32,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum WorldMoveKind {
+    Transfer,
+    Loan,
+    LoanReturn,
+    Release,
+}
+
+/// How a move elsewhere in the squad affected the player
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SquadImpact {
+    pub player_id: Uuid, // The teammate or rival who moved
+    pub kind: SquadImpactKind,
+    pub role_before: SquadRole,
+    pub role_after: SquadRole,
+    pub morale_change: f32,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum SquadImpactKind {
+    RivalArrived,
+    RivalDeparted,
+    FriendLeft,
+}
+
+/// Everything that happened in a day of AI transfer business
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorldTransferReport {
+    pub moves: Vec<WorldTransfer>,
+    pub impacts: Vec<SquadImpact>,
+    pub payments: Vec<FeePayment>, // Instalments that fell due today
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::Position;
+    use crate::systems::SquadEngine;
+    use crate::test_support::{create_player, create_test_team, create_world, date};
+
+    #[test]
+    fn test_club_with_a_gap_buys_from_a_smaller_club() {
+        let engine = WorldTransferEngine::new();
+        let mut buyer = create_test_team();
+        buyer.reputation = 80.0;
+        buyer.financial_power = 90.0;
+        let mut seller = create_test_team();
+        seller.reputation = 60.0;
+        let mut striker = create_player(78, 24, Position::CF);
+        striker.contract.club_id = seller.id;
+        seller.squad.push(striker.id);
+        let mut game_state = create_world(vec![buyer.clone(), seller.clone()], vec![striker.clone()]);
+
+        let report = engine.run_window(&mut game_state, date(2025, 8, 1));
+
+        let signing = report.moves.iter().find(|m| m.player_id == striker.id).unwrap();
+        assert_eq!(signing.kind, WorldMoveKind::Transfer);
+        assert_eq!(signing.to_club_id, Some(buyer.id));
+        assert!(signing.fee > 0.0);
+        // Only the upfront part changes hands now; the rest is owed in instalments
+        let deal = &game_state.transfer_system.transfer_records[0].fee;
+        assert!((deal.guaranteed() - signing.fee).abs() < 1.0);
+        assert!(deal.upfront < signing.fee);
+        let clubs = &game_state.clubs;
+        assert!(clubs[0].squad.contains(&striker.id));
+        assert!(!clubs[1].squad.contains(&striker.id));
+        assert_eq!(clubs[0].finances.balance, buyer.finances.balance - deal.upfront);
+        assert_eq!(clubs[1].finances.balance, seller.finances.balance + deal.upfront);
+        assert_eq!(game_state.players[0].contract.club_id, buyer.id);
+
+        // Nothing happens once the window has shut
+        let report = engine.run_window(&mut game_state, date(2025, 10, 1));
+        assert!(report.moves.is_empty());
+    }
+
+    #[test]
+    fn test_clubs_shop_once_a_window_and_again_on_deadline_day() {
+        let engine = WorldTransferEngine::new();
+        let mut buyer = create_test_team();
+        buyer.reputation = 80.0;
+        buyer.financial_power = 90.0;
+        buyer.finances.balance = 100000000.0;
+        let mut seller = create_test_team();
+        seller.reputation = 60.0;
+        let mut striker = create_player(78, 24, Position::CF);
+        striker.contract.club_id = seller.id;
+        seller.squad.push(striker.id);
+        let mut game_state = create_world(vec![buyer.clone(), seller.clone()], vec![striker.clone()]);
+
+        let report = engine.run_window(&mut game_state, date(2025, 8, 1));
+        assert!(report.moves.iter().any(|m| m.player_id == striker.id));
+
+        let mut defender = create_player(78, 24, Position::CB);
+        defender.contract.club_id = seller.id;
+        game_state.clubs[1].squad.push(defender.id);
+        game_state.players.push(defender.clone());
+
+        // The buyer has done its business for this window
+        let report = engine.run_window(&mut game_state, date(2025, 8, 2));
+        assert!(report.moves.is_empty());
+
+        let report = engine.run_window(&mut game_state, date(2025, 9, 1));
+        let signing = report.moves.iter().find(|m| m.player_id == defender.id).unwrap();
+        assert_eq!(signing.to_club_id, Some(buyer.id));
+    }
+
+    #[test]
+    fn test_a_populated_world_trades_on_its_first_window_day() {
+        let mut clubs: Vec<Team> = [85.0, 75.0, 65.0, 55.0]
+            .into_iter()
+            .map(|reputation| {
+                let mut club = create_test_team();
+                club.reputation = reputation;
+                club.financial_power = reputation;
+                club
+            })
+            .collect();
+        clubs[0].finances.balance = 100000000.0;
+        let mut game_state = create_world(clubs, vec![]);
+        let today = date(2025, 7, 1);
+        SquadEngine::new().populate_world(&mut game_state, today);
+
+        let report = WorldTransferEngine::new().run_window(&mut game_state, today);
+
+        assert!(!report.moves.is_empty());
+    }
+
+    #[test]
+    fn test_club_in_debt_is_forced_to_sell() {
+        let engine = WorldTransferEngine::new();
+        let mut buyer = create_test_team();
+        buyer.reputation = 80.0;
+        buyer.financial_power = 90.0;
+        let mut seller = create_test_team();
+        seller.finances.balance = 10000.0;
+        seller.finances.debt = 5000000.0;
+        // Too old for a normal signing, so only a forced sale moves them
+        let mut captain = create_player(80, 31, Position::CM);
+        captain.contract.club_id = seller.id;
+        seller.squad.push(captain.id);
+        let mut game_state = create_world(vec![buyer.clone(), seller.clone()], vec![captain.clone()]);
+
+        let report = engine.run_window(&mut game_state, date(2025, 8, 1));
+
+        let sale = report.moves.iter().find(|m| m.player_id == captain.id).unwrap();
+        assert_eq!(sale.to_club_id, Some(buyer.id));
+        let upfront = game_state.transfer_system.transfer_records[0].fee.upfront;
+        assert_eq!(game_state.clubs[1].finances.balance, seller.finances.balance + upfront);
+        assert!(game_state.clubs[0].squad.contains(&captain.id));
+    }
+
+    #[test]
+    fn test_boyhood_club_exercises_buy_back_years_later() {
+        let engine = WorldTransferEngine::new();
+        let fees = FeeEngine::new();
+        let mut boyhood = create_test_team();
+        boyhood.reputation = 70.0;
+        let mut buyer = create_test_team();
+        buyer.financial_power = 50.0;
+        let mut winger = create_player(75, 19, Position::RW);
+        winger.contract.club_id = buyer.id;
+        buyer.squad.push(winger.id);
+        let mut game_state = create_world(vec![boyhood.clone(), buyer.clone()], vec![winger.clone()]);
+        let deal = fees.structure_fee(&winger, &buyer, 30000.0, date(2023, 8, 1));
+        fees.record_transfer(&mut game_state.transfer_system.transfer_records, winger.id, boyhood.id, buyer.id, deal, date(2023, 8, 1));
+
+        let report = engine.run_window(&mut game_state, date(2025, 8, 1));
+
+        // Both instalments are long overdue and are paid before anything else
+        assert_eq!(report.payments.len(), 2);
+        let homecoming = report.moves.iter().find(|m| m.player_id == winger.id).unwrap();
+        assert_eq!(homecoming.to_club_id, Some(boyhood.id));
+        assert_eq!(homecoming.fee, 60000.0);
+        assert_eq!(game_state.players[0].contract.club_id, boyhood.id);
+        assert!(game_state.clubs[0].squad.contains(&winger.id));
+        assert!(game_state.transfer_system.transfer_records[0].fee.buy_back.is_none());
+    }
+
+    #[test]
+    fn test_rival_signing_and_departing_friend_affect_the_player() {
+        let engine = WorldTransferEngine::new();
+        let mut club = create_test_team();
+        club.reputation = 80.0;
+        let mut feeder = create_test_team();
+        feeder.reputation = 60.0;
+        let mut veteran = create_player(40, 34, Position::CB);
+        veteran.contract.club_id = club.id;
+        let mut rival = create_player(92, 24, Position::CM);
+        rival.contract.club_id = feeder.id;
+        club.squad.push(veteran.id);
+        feeder.squad.push(rival.id);
+        let mut game_state = create_world(vec![club.clone(), feeder], vec![veteran.clone(), rival.clone()]);
+        game_state.player.contract.club_id = club.id;
+        game_state.player.relationships.insert(veteran.id, 85.0);
+        game_state.clubs[0].squad.push(game_state.player.id);
+        let morale = game_state.player.morale;
+
+        let report = engine.run_window(&mut game_state, date(2025, 8, 1));
+
+        let release = report.moves.iter().find(|m| m.player_id == veteran.id).unwrap();
+        assert_eq!(release.kind, WorldMoveKind::Release);
+        assert!(game_state.players[0].contract.club_id.is_nil());
+        let signing = report.moves.iter().find(|m| m.player_id == rival.id).unwrap();
+        assert_eq!(signing.to_club_id, Some(club.id));
+
+        let kinds: Vec<SquadImpactKind> = report.impacts.iter().map(|impact| impact.kind).collect();
+        assert_eq!(kinds, vec![SquadImpactKind::FriendLeft, SquadImpactKind::RivalArrived]);
+        assert!(matches!(game_state.player.contract.squad_role, SquadRole::Rotation));
+        assert!(game_state.player.morale < morale);
+    }
+
+    // Helper functions for tests
+
+}
@@ -0,0 +1,510 @@
+// src/systems/youth_system.rs
+use chrono::NaiveDate;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::entities::{
+    Competition, CompetitionType, Player, PlayerCompetitionStats, Position, SeasonStats, SquadRole, Team
+};
+use crate::systems::reputation_system::{MatchImportance, TeamPerformance};
+use crate::systems::social_system::ManagerProfile;
+use crate::systems::{CompetitionEngine, ReputationEngine};
+use crate::utils::helpers::age_on;
+
+/// Youth matches the manager wants to see before considering a promotion
+const MIN_YOUTH_APPEARANCES_FOR_PROMOTION: usize = 6;
+/// Most recent youth ratings the manager keeps in mind
+const YOUTH_RATINGS_WATCHED: usize = 10;
+/// Quality of youth football when converting reputation, 0-100
+const YOUTH_LEAGUE_STRENGTH: f32 = 20.0;
+
+/// The YouthEngine runs the age-limited youth and reserve leagues
+/// Prospects play there until the manager has seen enough to promote them
+pub struct YouthEngine;
+
+impl Default for YouthEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl YouthEngine {
+    /// Creates a new YouthEngine instance
+    pub fn new() -> Self {
+        YouthEngine
+    }
+
+    /// Creates the under-18 and under-21 leagues for the clubs of a senior league
+    pub fn create_youth_leagues(&self, senior: &Competition) -> Vec<Competition> {
+        let competition_engine = CompetitionEngine::new();
+
+        [(CompetitionType::YouthLeague, "U18"), (CompetitionType::ReserveLeague, "U21")]
+            .into_iter()
+            .map(|(competition_type, suffix)| {
+                let mut league = Competition {
+                    id: Uuid::new_v4(),
+                    name: format!("{} {}", senior.name, suffix),
+                    competition_type,
+                    fixtures: Vec::new(),
+                    standings: Vec::new(),
+                    player_stats: Vec::new(),
+                    ..senior.clone()
+                };
+                competition_engine.initialize_season(&mut league);
+                league
+            })
+            .collect()
+    }
+
+    /// Whether a player is young enough for a competition this season
+    pub fn is_eligible(&self, player: &Player, competition: &Competition) -> bool {
+        competition.competition_type.age_limit()
+            .is_some_and(|limit| age_on(player.birth_date, competition.current_season.start_date) <= limit)
+    }
+
+    /// The youngest age group a prospect can play in for their club
+    pub fn youth_competition_for<'a>(&self, player: &Player, competitions: &'a [Competition]) -> Option<&'a Competition> {
+        if !matches!(player.contract.squad_role, SquadRole::Prospect) {
+            return None;
+        }
+
+        competitions.iter()
+            .filter(|competition| competition.teams.contains(&player.contract.club_id))
+            .filter(|competition| self.is_eligible(player, competition))
+            .min_by_key(|competition| competition.competition_type.age_limit())
+    }
+
+    /// Plays the youth and reserve fixtures scheduled on a date, with the prospect turning out for their club
+    pub fn play_youth_day(
+        &self,
+        competitions: &mut [Competition],
+        teams: &[Team],
+        player: &mut Player,
+        date: NaiveDate,
+    ) -> Vec<YouthMatchReport> {
+        let competition_engine = CompetitionEngine::new();
+        let player_competition = self.youth_competition_for(player, competitions).map(|competition| competition.id);
+        let mut reports = Vec::new();
+
+        for competition in competitions.iter_mut().filter(|competition| competition.competition_type.is_youth()) {
+            let due: Vec<(Uuid, Uuid, Uuid)> = competition.fixtures.iter()
+                .filter(|fixture| fixture.scheduled_date == date && fixture.result.is_none())
+                .map(|fixture| (fixture.id, fixture.home_team, fixture.away_team))
+                .collect();
+
+            for (fixture_id, home_team, away_team) in due {
+                let home_strength = self.academy_strength(teams, home_team);
+                let away_strength = self.academy_strength(teams, away_team);
+                let home_goals = self.simulate_goals(home_strength * 1.1, away_strength); // Home advantage
+                let away_goals = self.simulate_goals(away_strength, home_strength);
+                competition_engine.record_result(competition, fixture_id, home_goals, away_goals);
+
+                let club = player.contract.club_id;
+                let performance = if Some(competition.id) == player_competition
+                    && player.injury_status.is_none()
+                    && (home_team == club || away_team == club)
+                {
+                    let (scored, conceded) = if home_team == club { (home_goals, away_goals) } else { (away_goals, home_goals) };
+                    let performance = self.player_performance(player, scored, conceded);
+                    self.record_performance(competition, player, &performance, scored, conceded);
+                    Some(performance)
+                } else {
+                    None
+                };
+
+                reports.push(YouthMatchReport {
+                    competition_id: competition.id,
+                    fixture_id,
+                    home_team,
+                    away_team,
+                    home_goals,
+                    away_goals,
+                    performance,
+                });
+            }
+        }
+
+        reports
+    }
+
+    /// The manager's verdict on whether a prospect is ready for the first team
+    pub fn evaluate_promotion(&self, player: &Player, manager: &ManagerProfile) -> PromotionDecision {
+        let watched: Vec<f32> = player.career_stats.youth_form.iter().rev().take(YOUTH_RATINGS_WATCHED).copied().collect();
+        let average_rating = if watched.is_empty() {
+            0.0
+        } else {
+            watched.iter().sum::<f32>() / watched.len() as f32
+        };
+
+        // A 6.0 average shows nothing, an 8.0 average is as good as youth football gets
+        let performance_score = ((average_rating - 6.0) / 2.0 * 100.0).clamp(0.0, 100.0);
+        let ability_score = player.ability_score();
+        let score = performance_score * 0.5 + ability_score * 0.5;
+        // Managers who trust youth are quicker to give a prospect a chance
+        let threshold = 75.0 - manager.youth_trust * 0.2;
+
+        let mut reasons = Vec::new();
+        if watched.len() < MIN_YOUTH_APPEARANCES_FOR_PROMOTION {
+            reasons.push(format!(
+                "Needs more youth games ({} of {} watched)",
+                watched.len(),
+                MIN_YOUTH_APPEARANCES_FOR_PROMOTION
+            ));
+        }
+        if average_rating >= 7.0 {
+            reasons.push(format!("Averaging {:.1} in youth football", average_rating));
+        } else if !watched.is_empty() {
+            reasons.push(format!("Youth form of {:.1} isn't convincing yet", average_rating));
+        }
+        if ability_score >= threshold {
+            reasons.push("Has the ability to cope with senior football".to_string());
+        }
+
+        // Players too old for the reserves have to move up or be left without football
+        let too_old = player.age > CompetitionType::ReserveLeague.age_limit().unwrap_or(20);
+        if too_old {
+            reasons.push("Too old for the reserves".to_string());
+        }
+
+        PromotionDecision {
+            promote: too_old || (watched.len() >= MIN_YOUTH_APPEARANCES_FOR_PROMOTION && score >= threshold),
+            score,
+            threshold,
+            reasons,
+        }
+    }
+
+    /// Moves a prospect up to the first-team squad
+    pub fn promote(&self, player: &mut Player) {
+        if matches!(player.contract.squad_role, SquadRole::Prospect) {
+            player.contract.squad_role = SquadRole::Backup;
+        }
+    }
+
+    /// Rates a prospect's display in a youth match and shares out their goals and assists
+    fn player_performance(&self, player: &Player, team_goals: u8, goals_conceded: u8) -> YouthPerformance {
+        let mut rng = rand::thread_rng();
+        let (goal_share, assist_share) = match player.primary_position {
+            Position::CF | Position::SS => (0.35, 0.15),
+            Position::RW | Position::LW | Position::AM => (0.2, 0.25),
+            Position::CM | Position::RM | Position::LM => (0.1, 0.2),
+            Position::DM | Position::CB | Position::RB | Position::LB | Position::FB => (0.05, 0.08),
+            Position::GK => (0.0, 0.01),
+        };
+
+        let goals = (0..team_goals).filter(|_| rng.gen::<f32>() < goal_share).count() as u8;
+        let assists = (0..team_goals - goals).filter(|_| rng.gen::<f32>() < assist_share).count() as u8;
+        let result_bonus = match team_goals.cmp(&goals_conceded) {
+            std::cmp::Ordering::Greater => 0.3,
+            std::cmp::Ordering::Equal => 0.0,
+            std::cmp::Ordering::Less => -0.3,
+        };
+        // Good players stand out against their own age group
+        let ability_bonus = (player.ability_score() - 50.0) / 20.0;
+        let rating = 6.0 + ability_bonus + result_bonus + goals as f32 * 0.7 + assists as f32 * 0.4
+            + rng.gen_range(-0.8..0.8);
+
+        YouthPerformance { rating: rating.clamp(3.0, 10.0), goals, assists }
+    }
+
+    /// Adds a youth performance to the league, the player's youth stats and their reputation
+    fn record_performance(
+        &self,
+        competition: &mut Competition,
+        player: &mut Player,
+        performance: &YouthPerformance,
+        team_goals: u8,
+        goals_conceded: u8,
+    ) {
+        let index = match competition.player_stats.iter().position(|stats| stats.player_id == player.id) {
+            Some(index) => index,
+            None => {
+                competition.player_stats.push(PlayerCompetitionStats {
+                    player_id: player.id,
+                    team_id: player.contract.club_id,
+                    ..PlayerCompetitionStats::default()
+                });
+                competition.player_stats.len() - 1
+            }
+        };
+        let stats = &mut competition.player_stats[index];
+        stats.appearances += 1;
+        stats.goals += performance.goals as u32;
+        stats.assists += performance.assists as u32;
+        stats.rating_total += performance.rating;
+        if goals_conceded == 0 && matches!(player.primary_position, Position::GK) {
+            stats.clean_sheets += 1;
+        }
+
+        let career = &mut player.career_stats;
+        let season: &mut SeasonStats = &mut career.youth_season;
+        season.average_rating = (season.average_rating * season.appearances as f32 + performance.rating)
+            / (season.appearances + 1) as f32;
+        season.appearances += 1;
+        season.goals += performance.goals as u32;
+        season.assists += performance.assists as u32;
+        season.goals_conceded += goals_conceded as u32;
+        career.youth_form.push(performance.rating);
+        if career.youth_form.len() > YOUTH_RATINGS_WATCHED {
+            career.youth_form.remove(0);
+        }
+
+        let team_performance = match team_goals.cmp(&goals_conceded) {
+            std::cmp::Ordering::Greater => TeamPerformance::Win,
+            std::cmp::Ordering::Equal => TeamPerformance::Draw,
+            std::cmp::Ordering::Less => TeamPerformance::Loss,
+        };
+        ReputationEngine::new().update_reputation(
+            player,
+            performance.rating,
+            MatchImportance::Youth,
+            performance.goals > 0,
+            YOUTH_LEAGUE_STRENGTH,
+            team_performance,
+        );
+    }
+
+    /// Strength of a club's academy side, 0-100
+    fn academy_strength(&self, teams: &[Team], team_id: Uuid) -> f32 {
+        teams.iter()
+            .find(|team| team.id == team_id)
+            .map(|team| team.youth_academy_level as f32 * 10.0)
+            .unwrap_or(50.0)
+    }
+
+    /// Goals from five chances, converted more often by the stronger side
+    fn simulate_goals(&self, strength: f32, opponent: f32) -> u8 {
+        let mut rng = rand::thread_rng();
+        let conversion = (0.25 * strength / opponent.max(1.0)).clamp(0.05, 0.5);
+        (0..5).filter(|_| rng.gen::<f32>() < conversion).count() as u8
+    }
+}
+
+/// How a prospect played in a youth match
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct YouthPerformance {
+    pub rating: f32,
+    pub goals: u8,
+    pub assists: u8,
+}
+
+/// The result of a youth or reserve fixture
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct YouthMatchReport {
+    pub competition_id: Uuid,
+    pub fixture_id: Uuid,
+    pub home_team: Uuid,
+    pub away_team: Uuid,
+    pub home_goals: u8,
+    pub away_goals: u8,
+    pub performance: Option<YouthPerformance>, // Set when the prospect played
+}
+
+/// A manager's decision on promoting a prospect, with the reasons behind it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromotionDecision {
+    pub promote: bool,
+    pub score: f32,
+    pub threshold: f32,
+    pub reasons: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::game_state::{CompetitionRecord, SeasonArchive};
+    use crate::entities::{CurrentSeason, Standing, TiebreakRule};
+    use crate::systems::SeasonEngine;
+    use crate::test_support::create_test_player;
+
+    #[test]
+    fn test_youth_leagues_by_age() {
+        let engine = YouthEngine::new();
+        let club = Uuid::new_v4();
+        let senior = create_senior_league(vec![club, Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()]);
+        let leagues = engine.create_youth_leagues(&senior);
+
+        assert_eq!(leagues.len(), 2);
+        assert_eq!(leagues[0].name, "Test League U18");
+        assert_eq!(leagues[1].name, "Test League U21");
+        assert!(leagues.iter().all(|league| league.competition_type.is_youth() && league.fixtures.len() == 12));
+
+        // Prospects play in the youngest age group they qualify for, by age at the start of the season
+        let mut player = create_test_player();
+        player.contract.squad_role = SquadRole::Prospect;
+        player.contract.club_id = club;
+        player.birth_date = NaiveDate::from_ymd_opt(2009, 3, 15).unwrap();
+        assert_eq!(engine.youth_competition_for(&player, &leagues).map(|league| league.id), Some(leagues[0].id));
+
+        player.birth_date = NaiveDate::from_ymd_opt(2007, 3, 15).unwrap();
+        assert_eq!(engine.youth_competition_for(&player, &leagues).map(|league| league.id), Some(leagues[1].id));
+
+        player.birth_date = NaiveDate::from_ymd_opt(2005, 3, 15).unwrap();
+        assert!(engine.youth_competition_for(&player, &leagues).is_none());
+
+        // First-team players don't drop down
+        let mut first_teamer = create_test_player();
+        first_teamer.contract.club_id = club;
+        first_teamer.contract.squad_role = SquadRole::FirstTeam;
+        assert!(engine.youth_competition_for(&first_teamer, &leagues).is_none());
+    }
+
+    #[test]
+    fn test_youth_season_is_tracked_separately() {
+        let engine = YouthEngine::new();
+        let club = Uuid::new_v4();
+        let mut competitions = engine.create_youth_leagues(&create_senior_league(vec![club, Uuid::new_v4()]));
+        let mut player = create_test_player();
+        player.contract.squad_role = SquadRole::Prospect;
+        player.contract.club_id = club;
+        player.birth_date = NaiveDate::from_ymd_opt(2009, 3, 15).unwrap();
+        let reputation_before = player.local_reputation;
+
+        let mut dates: Vec<NaiveDate> = competitions.iter()
+            .flat_map(|competition| competition.fixtures.iter().map(|fixture| fixture.scheduled_date))
+            .collect();
+        dates.sort();
+        dates.dedup();
+        let mut performances = 0;
+        for date in dates {
+            let reports = engine.play_youth_day(&mut competitions, &[], &mut player, date);
+            performances += reports.iter().filter(|report| report.performance.is_some()).count();
+        }
+
+        // Both age groups are played out, but the prospect only turns out for the under-18s
+        assert!(competitions.iter().all(|competition| competition.fixtures.iter().all(|fixture| fixture.result.is_some())));
+        assert_eq!(performances, 2);
+        assert_eq!(competitions[0].player_stats[0].appearances, 2);
+        assert!(competitions[1].player_stats.is_empty());
+        assert_eq!(player.career_stats.youth_season.appearances, 2);
+        assert_eq!(player.career_stats.youth_form.len(), 2);
+        assert_eq!(player.career_stats.current_season.appearances, 0);
+        assert_eq!(player.career_stats.total_appearances, 30);
+        assert!((player.local_reputation - reputation_before).abs() < 3.0);
+
+        // Youth stats are archived apart from the senior season
+        let archive = SeasonArchive {
+            season: "2026-27".to_string(),
+            competitions: vec![CompetitionRecord {
+                competition_id: competitions[0].id,
+                name: competitions[0].name.clone(),
+                winner: Some(club),
+                final_table: vec![],
+                player_stats: competitions[0].player_stats.clone(),
+                youth: true,
+            }, CompetitionRecord {
+                competition_id: competitions[1].id,
+                name: competitions[1].name.clone(),
+                winner: Some(club),
+                final_table: vec![],
+                player_stats: vec![],
+                youth: true,
+            }],
+            awards: vec![],
+        };
+        SeasonEngine::new().archive_player_season(&mut player, &archive, club, None);
+        let career = &player.career_stats;
+        assert_eq!(career.youth_history.len(), 1);
+        assert_eq!(career.youth_history[0].appearances, 2);
+        assert_eq!(career.youth_season.appearances, 0);
+        assert_eq!(career.season_stats[0].appearances, 0);
+        // Only the youth title the player played for counts
+        assert_eq!(career.trophies.len(), 1);
+        assert_eq!(career.trophies[0].name, "Test League U18");
+    }
+
+    #[test]
+    fn test_youth_matches_carry_less_reputation() {
+        let reputation = ReputationEngine::new();
+        let mut youth_player = create_test_player();
+        let mut league_player = create_test_player();
+        let before = youth_player.local_reputation;
+
+        reputation.update_reputation(&mut youth_player, 8.5, MatchImportance::Youth, true, 50.0, TeamPerformance::Win);
+        reputation.update_reputation(&mut league_player, 8.5, MatchImportance::League, true, 50.0, TeamPerformance::Win);
+
+        assert!(youth_player.local_reputation > before);
+        assert!(youth_player.local_reputation < league_player.local_reputation);
+    }
+
+    #[test]
+    fn test_manager_watches_youth_form_before_promoting() {
+        let engine = YouthEngine::new();
+        let manager = ManagerProfile {
+            favoritism: 50.0,
+            youth_trust: 70.0,
+            discipline: 50.0,
+            communication_style: crate::systems::social_system::CommunicationStyle::Direct,
+        };
+
+        // Not enough games to judge yet
+        let mut player = create_test_player();
+        player.contract.squad_role = SquadRole::Prospect;
+        player.career_stats.youth_form = vec![8.0; 3];
+        let decision = engine.evaluate_promotion(&player, &manager);
+        assert!(!decision.promote);
+        assert!(decision.reasons.iter().any(|reason| reason.starts_with("Needs more youth games")));
+
+        // Poor youth form holds a prospect back
+        player.career_stats.youth_form = vec![6.0; 8];
+        assert!(!engine.evaluate_promotion(&player, &manager).promote);
+
+        // Standing out in the youth league earns a place in the first team
+        player.career_stats.youth_form = vec![8.0; 8];
+        let decision = engine.evaluate_promotion(&player, &manager);
+        assert!(decision.promote);
+        assert!(decision.score >= decision.threshold);
+        engine.promote(&mut player);
+        assert!(matches!(player.contract.squad_role, SquadRole::Backup));
+
+        // Outgrowing the reserves forces the decision
+        let mut veteran_prospect = create_test_player();
+        veteran_prospect.contract.squad_role = SquadRole::Prospect;
+        veteran_prospect.age = 21;
+        assert!(engine.evaluate_promotion(&veteran_prospect, &manager).promote);
+    }
+
+    fn create_senior_league(teams: Vec<Uuid>) -> Competition {
+        let start = NaiveDate::from_ymd_opt(2026, 8, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2027, 5, 31).unwrap();
+        Competition {
+            id: Uuid::new_v4(),
+            name: "Test League".to_string(),
+            country: "Test Country".to_string(),
+            level: 1,
+            teams: teams.clone(),
+            fixtures: vec![],
+            standings: teams.iter()
+                .map(|team_id| Standing {
+                    team_id: *team_id,
+                    position: 0,
+                    played: 0,
+                    won: 0,
+                    drawn: 0,
+                    lost: 0,
+                    goals_for: 0,
+                    goals_against: 0,
+                    points: 0,
+                    form: vec![],
+                    goal_difference: 0,
+                    away_goals_for: 0,
+                    fair_play_points: 0,
+                    lot: None,
+                })
+                .collect(),
+            competition_type: CompetitionType::League,
+            season_start: start,
+            season_end: end,
+            current_season: CurrentSeason {
+                year: "2026-27".to_string(),
+                is_active: true,
+                current_matchday: 1,
+                start_date: start,
+                end_date: end,
+            },
+            tiebreak_rules: TiebreakRule::goal_difference_first(),
+            player_stats: vec![],
+        }
+    }
+}
@@ -0,0 +1,257 @@
+// src/test_support.rs
+//! Factories shared by the unit tests
+//!
+//! Each returns a typical value; tests override only the fields they care about.
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::core::game_state::{GameState, TransferWindows};
+use crate::entities::{
+    CareerStats, Competition, CompetitionType, Contract, CurrentSeason, Facilities, Finances, Fixture, Foot,
+    HiddenAttributes, MatchStatus, MentalAttributes, PhysicalAttributes, Player, PlayerCompetitionStats, Position,
+    SeasonStats, SquadRole, Standing, Team, TechnicalAttributes, TiebreakRule
+};
+
+pub fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+/// A 17-year-old central midfielder with a first-team contract
+pub fn create_test_player() -> Player {
+    Player {
+        id: Uuid::new_v4(),
+        name: "Test Player".to_string(),
+        age: 17,
+        birth_date: date(2008, 3, 15),
+        nationality: "Country".to_string(),
+        height: 180,
+        weight: 75,
+        preferred_foot: Foot::Right,
+        primary_position: Position::CM,
+        secondary_positions: vec![],
+        technical: TechnicalAttributes {
+            dribbling: 75,
+            passing: 80,
+            shooting: 70,
+            first_touch: 78,
+            tackling: 72,
+            crossing: 65,
+        },
+        physical: PhysicalAttributes {
+            pace: 70,
+            stamina: 85,
+            strength: 75,
+            agility: 72,
+            jumping: 68,
+        },
+        mental: MentalAttributes {
+            composure: 80,
+            vision: 85,
+            work_rate: 75,
+            determination: 82,
+            positioning: 78,
+            teamwork: 80,
+        },
+        hidden: HiddenAttributes {
+            injury_proneness: 20,
+            consistency: 70,
+            big_match_temperament: 80,
+            professionalism: 90,
+            potential_ceiling: 85,
+            versatility: 75,
+            ambition: 80,
+            loyalty: 60,
+            ego: 70,
+        },
+        fitness: 90.0,
+        fatigue: 10.0,
+        form: 7.5,
+        morale: 75.0,
+        sharpness: 80.0,
+        local_reputation: 65.0,
+        international_reputation: 40.0,
+        contract: create_test_contract(),
+        career_stats: CareerStats {
+            seasons_played: 0,
+            total_appearances: 30,
+            total_goals: 12,
+            total_assists: 4,
+            total_yellow_cards: 3,
+            total_red_cards: 0,
+            average_rating: 7.0,
+            highest_rating: 8.5,
+            season_stats: vec![],
+            awards: vec![],
+            trophies: vec![],
+            current_season: SeasonStats::default(),
+            international_caps: 0,
+            international_goals: 0,
+            first_call_up: None,
+            youth_season: SeasonStats::default(),
+            youth_history: vec![],
+            youth_form: vec![],
+            club_spells: vec![],
+        },
+        relationships: HashMap::new(),
+        injury_status: None,
+        form_history: vec![7.0, 7.5, 8.0, 6.8, 7.2],
+        tutorial_state: HashMap::new(),
+    }
+}
+
+/// A three-year first-team contract at a new club
+pub fn create_test_contract() -> Contract {
+    Contract {
+        club_id: Uuid::new_v4(),
+        wage: 50000.0,
+        length_years: 3,
+        squad_role: SquadRole::FirstTeam,
+        release_clause: None,
+        performance_bonuses: vec![],
+        contract_end_date: date(2026, 1, 1),
+        league_strength: 75.0,
+        loan: None,
+    }
+}
+
+/// A mid-table club with a healthy balance
+pub fn create_test_team() -> Team {
+    Team {
+        id: Uuid::new_v4(),
+        name: "Test Team".to_string(),
+        country: "Test Country".to_string(),
+        city: "Test City".to_string(),
+        reputation: 75.0,
+        finances: Finances {
+            balance: 1000000.0,
+            weekly_wage_bill: 50000.0,
+            revenue_per_week: 100000.0,
+            debt: 0.0,
+        },
+        squad: vec![],
+        staff: vec![],
+        youth_academy_level: 5,
+        facilities: Facilities {
+            training_ground_quality: 7,
+            stadium_capacity: 20000,
+            stadium_quality: 6,
+            youth_facilities: 8,
+        },
+        financial_power: 75.0,
+        youth_focus: 60.0,
+        facilities_quality: 70.0,
+        medical_quality: 80.0,
+        tactical_identity: "Possession".to_string(),
+    }
+}
+
+/// A first-matchday fixture that hasn't been played yet
+pub fn create_fixture(home_team: Uuid, away_team: Uuid, scheduled_date: NaiveDate) -> Fixture {
+    Fixture {
+        id: Uuid::new_v4(),
+        competition_id: Uuid::new_v4(),
+        home_team,
+        away_team,
+        scheduled_date,
+        venue: home_team,
+        status: MatchStatus::Scheduled,
+        result: None,
+        matchday: 1,
+        postponed_from: None,
+    }
+}
+
+/// A 2026-27 competition holding the given fixtures, with no teams or standings
+pub fn create_competition(competition_type: CompetitionType, fixtures: Vec<Fixture>) -> Competition {
+    Competition {
+        id: Uuid::new_v4(),
+        name: "Test Competition".to_string(),
+        country: "Test Country".to_string(),
+        level: 1,
+        teams: vec![],
+        fixtures,
+        standings: vec![],
+        competition_type,
+        season_start: date(2026, 8, 1),
+        season_end: date(2027, 5, 31),
+        current_season: CurrentSeason {
+            year: "2026-27".to_string(),
+            is_active: true,
+            current_matchday: 1,
+            start_date: date(2026, 8, 1),
+            end_date: date(2027, 5, 31),
+        },
+        tiebreak_rules: TiebreakRule::goal_difference_first(),
+        player_stats: vec![],
+    }
+}
+
+/// A league with no fixtures, standing the teams in the given positions
+pub fn create_league(positions: Vec<(Uuid, u8)>, player_stats: Vec<PlayerCompetitionStats>) -> Competition {
+    Competition {
+        name: "Test League".to_string(),
+        teams: positions.iter().map(|(team, _)| *team).collect(),
+        standings: positions.iter()
+            .map(|(team_id, position)| Standing {
+                team_id: *team_id,
+                position: *position,
+                played: 0,
+                won: 0,
+                drawn: 0,
+                lost: 0,
+                goals_for: 0,
+                goals_against: 0,
+                points: 0,
+                form: vec![],
+                goal_difference: 0,
+                away_goals_for: 0,
+                fair_play_points: 0,
+                lot: None,
+            })
+            .collect(),
+        player_stats,
+        ..create_competition(CompetitionType::League, vec![])
+    }
+}
+
+/// A first-team player with every attribute at the same rating
+pub fn create_player(attribute: u8, age: u8, position: Position) -> Player {
+    let mut player = create_test_player();
+    player.age = age;
+    player.primary_position = position;
+    player.contract.squad_role = SquadRole::FirstTeam;
+    player.technical = TechnicalAttributes {
+        dribbling: attribute,
+        passing: attribute,
+        shooting: attribute,
+        first_touch: attribute,
+        tackling: attribute,
+        crossing: attribute,
+    };
+    player.physical = PhysicalAttributes {
+        pace: attribute,
+        stamina: attribute,
+        strength: attribute,
+        agility: attribute,
+        jumping: attribute,
+    };
+    player.mental = MentalAttributes {
+        composure: attribute,
+        vision: attribute,
+        work_rate: attribute,
+        determination: attribute,
+        positioning: attribute,
+        teamwork: attribute,
+    };
+    player
+}
+
+/// A game world with English transfer windows, made up of the given clubs and other players
+pub fn create_world(clubs: Vec<Team>, players: Vec<Player>) -> GameState {
+    let mut game_state = GameState::new(create_test_player(), Uuid::new_v4());
+    game_state.season.transfer_windows = TransferWindows::for_country("England", 2025);
+    game_state.clubs = clubs;
+    game_state.players = players;
+    game_state
+}
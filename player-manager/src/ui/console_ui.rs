@@ -1,12 +1,30 @@
 // src/ui/console_ui.rs
 use std::io::{self, Write};
 use std::collections::HashMap;
+use std::path::Path;
 use uuid::Uuid;
 
-use crate::entities::{Player, Team, Match, Competition};
-use crate::core::time_engine::TimeEngine;
+use crate::entities::{BonusCondition, ContractTerms, SquadRole, Player, Team, Match, Competition, MatchStatus, NationalTeam, InternationalFixture};
+use crate::core::time_engine::{PauseReason, ScheduledEventType, TimeEngine};
 use crate::core::event_engine::{EventEngine, UserDecisionRequest};
+use crate::core::game_state::{AwardResult, CareerPriorities, GameState, InterestLevel, LifestyleItem, MarketValuePoint, RumourReliability, SeasonInfo, TransferWindows};
+use crate::save::SaveManager;
 use crate::ui::tutorial::TutorialManager;
+use crate::systems::{AgentEngine, BonusEngine, CalendarEngine, ClubFinanceEngine, FeeEngine, FinanceEngine, ValuationEngine, FreeAgencyEngine, InternationalEngine, LeaderboardEngine, LoanEngine, MatchEngine, OpportunityEngine, PriorityEngine, RenewalEngine, RumourEngine, SeasonEngine, TournamentEngine, TransferEngine, TransferWindowEngine, WorldTransferEngine, YouthEngine};
+use crate::systems::social_system::{CommunicationStyle, ManagerProfile};
+use crate::systems::agent_system::AgentHireOutcome;
+use crate::systems::calendar_system::{CongestionLevel, Postponement, PostponementReason};
+use crate::systems::fee_system::FeePayment;
+use crate::systems::free_agency_system::{ExpiryOutcome, FreeTransferOffer, TrialOutcome};
+use crate::systems::loan_system::{LoanOutcome, LoanReview};
+use crate::systems::priority_system::OfferScore;
+use crate::systems::transfer_system::BidResponse;
+use crate::systems::window_system::OfferStatus;
+use crate::systems::world_transfer_system::{SquadImpactKind, WorldMoveKind, WorldTransfer};
+use chrono::{Datelike, NaiveDate, Weekday};
+
+/// Where the console keeps its saved careers
+const SAVE_DIRECTORY: &str = "saves";
 
 /// The ConsoleUI provides the text-based interface for the game
 /// It displays data, presents choices, and sends user decisions back to the system
@@ -27,28 +45,48 @@ impl ConsoleUI {
     }
 
     /// Main game loop for the console interface
-    pub fn run_main_loop(&mut self, mut player: Player, all_teams: Vec<Team>, _competitions: Vec<Competition>) {
+    pub fn run_main_loop(&mut self, state: &mut GameState) {
         println!("⚽ Welcome to From Boots to Ballon d'Or!");
-        println!("Playing as: {}", player.name);
-        println!("Age: {}, Position: {:?}", player.age, player.primary_position);
+        println!("Playing as: {}", state.player.name);
+        println!("Age: {}, Position: {:?}", state.player.age, state.player.primary_position);
         println!();
 
         // Show main menu tutorial if first time
-        self.show_tutorial_if_needed("main_menu", &mut player.tutorial_state);
+        self.show_tutorial_if_needed("main_menu", &mut state.player.tutorial_state);
+
+        // Make sure no club is asked to play a league game around a cup tie
+        let calendar = CalendarEngine::new();
+        let postponements = calendar.resolve_cup_clashes(&mut state.competitions);
+        self.report_postponements(&postponements, state.player.contract.club_id, &state.competitions, &state.clubs);
+        let mut last_day = self.time_engine.current_date.date_naive();
+        SeasonEngine::new().schedule_season_events(state, &mut self.time_engine);
 
         loop {
             // Show current status
-            self.display_weekly_status(&mut player, &all_teams);
+            self.display_weekly_status(state);
             
             // Check for any events requiring user input
-            if let Some(user_decision) = self.check_for_user_decisions() {
-                self.handle_user_decision(&mut player, user_decision);
+            if self.time_engine.is_paused {
+                self.resolve_pause(state);
+            } else if let Some(user_decision) = self.check_for_user_decisions(state) {
+                self.handle_user_decision(state, user_decision);
             } else {
                 // If no user decisions, advance time
                 match self.time_engine.advance_time() {
                     Ok(()) => {
                         // Time advanced successfully
                         println!("Time advanced. Checking for events...");
+                        state.current_date = self.time_engine.current_date;
+                        let today = state.current_date.date_naive();
+                        if SeasonEngine::new().process_birthdays(&mut state.player, today) {
+                            println!("🎂 Happy birthday! {} turns {} today.", state.player.name, state.player.age);
+                            PriorityEngine::new().age_priorities(&mut state.career_priorities, state.player.age);
+                        }
+                        self.handle_triggered_events(state, today);
+                        if today != last_day {
+                            last_day = today;
+                            self.process_new_day(state, today);
+                        }
                     },
                     Err(e) => {
                         eprintln!("Error advancing time: {}", e);
@@ -68,14 +106,1118 @@ impl ConsoleUI {
                 continue;
             }
             
+            if input_trim.eq_ignore_ascii_case("stats") || input_trim.eq_ignore_ascii_case("s") {
+                let player_names = self.player_names(state);
+                for competition in &state.competitions {
+                    self.display_leaderboards(competition, &player_names, &state.clubs);
+                }
+                continue;
+            }
+            
+            if input_trim.eq_ignore_ascii_case("transfer") || input_trim.eq_ignore_ascii_case("t") {
+                self.request_transfer(state);
+                continue;
+            }
+            
+            if input_trim.eq_ignore_ascii_case("money") || input_trim.eq_ignore_ascii_case("m") {
+                self.manage_finances(state);
+                continue;
+            }
+            
+            if input_trim.eq_ignore_ascii_case("value") || input_trim.eq_ignore_ascii_case("v") {
+                self.display_value_chart(state);
+                continue;
+            }
+            
+            if input_trim.eq_ignore_ascii_case("agent") || input_trim.eq_ignore_ascii_case("a") {
+                self.manage_agent(state);
+                continue;
+            }
+            
+            if input_trim.eq_ignore_ascii_case("save") {
+                match SaveManager::new().quick_save(state, Path::new(SAVE_DIRECTORY)) {
+                    Ok(()) => println!("💾 Career saved."),
+                    Err(e) => eprintln!("Error saving game: {}", e),
+                }
+                continue;
+            }
+            
             if input_trim.eq_ignore_ascii_case("quit") || input_trim.eq_ignore_ascii_case("q") {
                 break;
             }
         }
     }
 
+    /// Handles the calendar at the start of each day: weather and weekly congestion
+    fn process_new_day(&mut self, state: &mut GameState, today: NaiveDate) {
+        // Everyone else in the world gets older too
+        let season = SeasonEngine::new();
+        for player in state.players.iter_mut() {
+            season.process_birthdays(player, today);
+        }
+
+        let calendar = CalendarEngine::new();
+        let postponements = calendar.check_weather(&mut state.competitions, today);
+        calendar.sync_time_engine(&postponements, &mut self.time_engine);
+        self.report_postponements(&postponements, state.player.contract.club_id, &state.competitions, &state.clubs);
+
+        // A crowded week takes its toll up front
+        if today.weekday() == Weekday::Mon {
+            let matches = calendar.matches_in_week(&state.competitions, state.player.contract.club_id, today);
+            let added = calendar.apply_congestion_fatigue(&mut state.player, matches);
+            if added > 0.0 {
+                println!("📅 {} matches this week. Fatigue +{:.1}", matches, added);
+            }
+        }
+
+        self.play_youth_football(&mut state.player, &mut state.competitions, &state.clubs, today);
+
+        if today.weekday() == Weekday::Mon {
+            self.follow_transfer_rumours(state, today);
+            self.offer_career_opportunity(state, today);
+        }
+        self.manage_loans(state, today);
+        self.manage_free_agency(state, today);
+        self.run_transfer_window(state, today);
+        if state.clubs.iter().any(|club| state.season.windows_for(&club.country).is_open(today)) {
+            self.follow_world_transfers(state, today);
+        }
+        self.manage_buy_back(state, today);
+        self.manage_renewal(state, today);
+        state.current_club_id = state.player.contract.club_id;
+
+        for payout in BonusEngine::new().evaluate_bonuses(&mut state.player, &state.competitions, &mut state.player_finances, today) {
+            println!("💰 Bonus earned: {} (£{:.0})", BonusEngine::new().describe_condition(&payout.condition), payout.amount);
+        }
+        if today.weekday() == Weekday::Mon {
+            let player = &mut state.player;
+            let country = state.clubs.iter()
+                .find(|team| team.id == player.contract.club_id)
+                .map(|team| team.country.as_str())
+                .unwrap_or_default();
+            let agent = state.player_agent.and_then(|agent_id| state.agents.iter().find(|agent| agent.id == agent_id));
+            let statement = FinanceEngine::new().process_week(player, &mut state.player_finances, country, agent, today);
+            println!("🏦 Payday: £{:.0} wages and £{:.0} bonuses, £{:.0} tax, £{:.0} lifestyle. Net £{:.0}",
+                     statement.wage, statement.bonuses, statement.tax, statement.upkeep, statement.net);
+            if statement.agent_fee > 0.0 {
+                println!("   Your agent took their £{:.0} fee for your contract.", statement.agent_fee);
+            }
+
+            ValuationEngine::new().record_weekly_values(state, today);
+
+            let club_id = state.player.contract.club_id;
+            let payments = FeeEngine::new().pay_instalments(state, today);
+            self.report_instalments(&payments, club_id, &state.clubs);
+
+            let club_finances = ClubFinanceEngine::new();
+            let reports = club_finances.process_week(state, today);
+            if let Some(report) = reports.iter().find(|report| report.club_id == club_id) {
+                println!("🏟️ Club books: £{:.0} in, £{:.0} wages. Balance £{:.0}, debt £{:.0}",
+                         report.matchday_revenue + report.tv_revenue + report.prize_money, report.wages, report.balance, report.debt);
+            }
+            if state.clubs.iter().find(|club| club.id == club_id).is_some_and(|club| club_finances.in_debt(club)) {
+                println!("   The club is in debt. Expect sales and no raises.");
+            }
+        }
+    }
+
+    /// The transfer windows of the country the player's club plays in
+    fn current_windows<'a>(&self, season: &'a SeasonInfo, player: &Player, all_teams: &[Team]) -> &'a TransferWindows {
+        let country = all_teams.iter()
+            .find(|team| team.id == player.contract.club_id)
+            .map(|team| team.country.as_str())
+            .unwrap_or_default();
+        season.windows_for(country)
+    }
+
+    /// Opens held pre-agreements when a window opens and lines up deadline day bids
+    fn run_transfer_window(&mut self, state: &mut GameState, today: NaiveDate) {
+        let player = &state.player;
+        let all_teams = &state.clubs;
+        let windows_engine = TransferWindowEngine::new();
+        let windows = self.current_windows(&state.season, player, all_teams);
+
+        for offer in windows_engine.release_pre_agreements(&mut state.transfer_system, windows, today) {
+            println!("📨 The window is open: {}'s offer agreed earlier is now live.", self.get_team_name(all_teams, offer.buying_club_id));
+        }
+
+        let bids = windows_engine.deadline_day_bids(player, &state.transfer_system, all_teams, windows, today);
+        if bids.is_empty() {
+            return;
+        }
+        match windows_engine.deadline_days_left(windows, today) {
+            Some(0) => println!("⏰ DEADLINE DAY! The window shuts tonight and the phones are ringing."),
+            Some(days) => println!("⏰ {} days until the window shuts. Clubs are circling.", days),
+            None => {}
+        }
+        windows_engine.schedule_deadline_bids(&bids, &mut self.time_engine, today);
+    }
+
+    /// Runs a day of AI transfer business and reports what touches the player's club, plus the biggest deals
+    fn follow_world_transfers(&self, state: &mut GameState, today: NaiveDate) {
+        const HEADLINE_DEALS: usize = 3;
+
+        let report = WorldTransferEngine::new().run_window(state, today);
+        let club_id = state.player.contract.club_id;
+        let player_name = |id: Uuid| state.find_player(id).map(|player| player.name.clone()).unwrap_or_else(|| "Unknown".to_string());
+
+        let mut headlines: Vec<&WorldTransfer> = report.moves.iter()
+            .filter(|transfer| transfer.kind == WorldMoveKind::Transfer && transfer.to_club_id != Some(club_id) && transfer.from_club_id != club_id)
+            .collect();
+        headlines.sort_by(|a, b| b.fee.total_cmp(&a.fee));
+        for transfer in headlines.into_iter().take(HEADLINE_DEALS) {
+            println!("📰 {} joins {} from {} for £{:.0}.",
+                     player_name(transfer.player_id),
+                     self.get_team_name(&state.clubs, transfer.to_club_id.unwrap_or_default()),
+                     self.get_team_name(&state.clubs, transfer.from_club_id),
+                     transfer.fee);
+        }
+
+        for transfer in report.moves.iter().filter(|transfer| transfer.to_club_id == Some(club_id) || transfer.from_club_id == club_id) {
+            let name = player_name(transfer.player_id);
+            let other_club = |id: Option<Uuid>| id.map(|id| self.get_team_name(&state.clubs, id)).unwrap_or_default();
+            match transfer.kind {
+                WorldMoveKind::Transfer if transfer.to_club_id == Some(club_id) =>
+                    println!("🔄 New signing: {} arrives from {} for £{:.0}.", name, other_club(Some(transfer.from_club_id)), transfer.fee),
+                WorldMoveKind::Transfer => println!("🔄 {} leaves for {} for £{:.0}.", name, other_club(transfer.to_club_id), transfer.fee),
+                WorldMoveKind::Loan => println!("🔄 {} goes out on loan to {}.", name, other_club(transfer.to_club_id)),
+                WorldMoveKind::LoanReturn => println!("🔄 {} is back from loan.", name),
+                WorldMoveKind::Release => println!("🔄 {} has been released.", name),
+            }
+        }
+
+        for impact in &report.impacts {
+            let name = player_name(impact.player_id);
+            match impact.kind {
+                SquadImpactKind::RivalArrived => println!("😬 {} is competing for your place. You drop from {:?} to {:?}.", name, impact.role_before, impact.role_after),
+                SquadImpactKind::RivalDeparted => println!("💪 With {} gone you move up from {:?} to {:?}.", name, impact.role_before, impact.role_after),
+                SquadImpactKind::FriendLeft => println!("😢 Your friend {} has left the club.", name),
+            }
+            println!("   Morale {:+.1}", impact.morale_change);
+        }
+        self.report_instalments(&report.payments, club_id, &state.clubs);
+    }
+
+    /// Reports the transfer instalments the player's club paid or received
+    fn report_instalments(&self, payments: &[FeePayment], club_id: Uuid, all_teams: &[Team]) {
+        for payment in payments {
+            if payment.payer_club_id == club_id {
+                println!("💷 The club pays a £{:.0} transfer instalment to {}.", payment.amount, self.get_team_name(all_teams, payment.payee_club_id));
+            } else if payment.payee_club_id == club_id {
+                println!("💷 {} pay the club a £{:.0} transfer instalment.", self.get_team_name(all_teams, payment.payer_club_id), payment.amount);
+            }
+        }
+    }
+
+    /// Lets a former club with a buy-back clause bring the player home, if they want to go
+    fn manage_buy_back(&mut self, state: &mut GameState, today: NaiveDate) {
+        let player = &mut state.player;
+        let all_teams = &state.clubs;
+        let fees = FeeEngine::new();
+        let Some(record) = fees.buy_back_clause(&state.transfer_system.transfer_records, player, today) else {
+            return;
+        };
+        let (Some(clause), Some(club)) = (
+            record.fee.buy_back.clone(),
+            all_teams.iter().find(|team| team.id == record.from_club_id).cloned(),
+        ) else {
+            return;
+        };
+        let windows = state.season.windows_for(&club.country);
+        if !windows.is_open(today) || !fees.will_exercise(player, &club, &clause) {
+            return;
+        }
+
+        let transfer = TransferEngine::new();
+        let offer = transfer.generate_transfer_offer(player, &club, &player.contract, today);
+        println!("🏠 BUY-BACK");
+        println!("{} have triggered their £{:.0} buy-back clause and want you home, offering £{:.0}/week for {} years.",
+                 club.name, clause.fee, offer.offered_wage, offer.contract_length_years);
+        let score = self.report_offer_score(&state.career_priorities, player, &club, offer.offered_wage, player.contract.squad_role);
+        print!("Do you want to go back? (y/n): ");
+        io::stdout().flush().unwrap();
+
+        let mut input = String::new();
+        io::stdin().read_line(&mut input).expect("Failed to read line");
+        if !input.trim().eq_ignore_ascii_case("y") {
+            fees.waive_buy_back(&mut state.transfer_system.transfer_records, player);
+            println!("You turn down personal terms and the clause lapses.");
+            return;
+        }
+
+        let from_club_id = player.contract.club_id;
+        let terms = ContractTerms {
+            wage: offer.offered_wage,
+            length_years: offer.contract_length_years,
+            squad_role: player.contract.squad_role,
+            release_clause: None,
+            signing_on_fee: 0.0,
+            performance_bonuses: vec![],
+        };
+        if transfer.complete_transfer(player, &club, &terms, windows, today) {
+            let payments = fees.exercise_buy_back(&mut state.transfer_system.transfer_records, player.id, from_club_id, today);
+            fees.apply_payments(&mut state.clubs, &payments);
+            println!("✍️ You're going home! Welcome back to {}.", club.name);
+            self.settle_in(player, &score);
+            FreeAgencyEngine::new().schedule_contract_expiry(player, &mut self.time_engine);
+        }
+    }
+
+    /// Scores an offer against the player's priorities and explains the score
+    fn report_offer_score(&self, priorities: &CareerPriorities, player: &Player, club: &Team, wage: f32, squad_role: SquadRole) -> OfferScore {
+        let engine = PriorityEngine::new();
+        let score = engine.score_offer(priorities, player, club, wage, squad_role);
+        println!("🧭 Fits your priorities: {:.0}/100", score.score);
+        for line in engine.explain(&score).iter().take(3) {
+            println!("   {}", line);
+        }
+        score
+    }
+
+    /// Lifts or drops morale after a move, depending on how well it fits the player's priorities
+    fn settle_in(&self, player: &mut Player, score: &OfferScore) {
+        let change = PriorityEngine::new().react_to_move(player, score);
+        if change >= 1.0 {
+            println!("😊 The move is everything you wanted. Morale +{:.0}", change);
+        } else if change <= -1.0 {
+            println!("😟 The move isn't what you wanted from your career. Morale {:.0}", change);
+        }
+    }
+
+    /// Queues a career opportunity, if one comes up this week, as a decision for the player
+    fn offer_career_opportunity(&mut self, state: &mut GameState, today: NaiveDate) {
+        let player = &state.player;
+        let all_teams = &state.clubs;
+        let Some(club) = all_teams.iter().find(|team| team.id == player.contract.club_id) else {
+            return;
+        };
+        let national_team = state.national_teams.iter().find(|team| team.nationality == player.nationality);
+        let opportunities = OpportunityEngine::new();
+        if let Some(opportunity) = opportunities.generate_opportunity(player, club, &state.players, national_team, &state.career_opportunities, today) {
+            state.pending_decisions.push(opportunities.decision_request(player, &opportunity));
+        }
+    }
+
+    /// Puts the club's renewal offer to the player: sign now, or hold out for better at the board's expense
+    fn manage_renewal(&mut self, state: &mut GameState, today: NaiveDate) {
+        let player = &mut state.player;
+        let all_teams = &state.clubs;
+        let Some(club) = all_teams.iter().find(|team| team.id == player.contract.club_id) else {
+            return;
+        };
+        let renewals = RenewalEngine::new();
+        let Some(approach) = renewals.renewal_approach(player, club, &mut state.transfer_system, today) else {
+            return;
+        };
+
+        println!("📝 CONTRACT RENEWAL");
+        if approach.round == 1 {
+            println!("{} want to talk about a new contract before yours runs out on {}.",
+                     club.name, player.contract.contract_end_date.format("%d %b %Y"));
+        } else {
+            println!("{} come back with an improved offer.", club.name);
+        }
+        if let Some(level) = &approach.rival_interest {
+            println!("They know other clubs are interested ({:?}).", level);
+        }
+        let terms = &approach.terms;
+        println!("£{:.0}/week for {} years as a {:?}, £{:.0} signing-on fee, release clause £{:.0}.",
+                 terms.wage, terms.length_years, terms.squad_role, terms.signing_on_fee, terms.release_clause.unwrap_or(0.0));
+        self.report_offer_score(&state.career_priorities, player, club, terms.wage, terms.squad_role);
+        print!("Sign it? (y to sign, anything else to hold out): ");
+        io::stdout().flush().unwrap();
+
+        let mut input = String::new();
+        io::stdin().read_line(&mut input).expect("Failed to read line");
+        if input.trim().eq_ignore_ascii_case("y") {
+            renewals.accept_renewal(player, club, &mut state.transfer_system, &approach, today);
+            println!("✍️ You commit your future to {} until {}.", club.name, player.contract.contract_end_date.format("%d %b %Y"));
+            FreeAgencyEngine::new().schedule_contract_expiry(player, &mut self.time_engine);
+        } else if renewals.stall(player, &mut state.transfer_system) {
+            println!("🚪 The board have run out of patience and break off talks. Your contract will run down.");
+        } else {
+            println!("You keep them waiting. The board aren't impressed.");
+        }
+    }
+
+    /// Deals with whatever paused the game, then resumes it
+    fn resolve_pause(&mut self, state: &mut GameState) {
+        let player = &mut state.player;
+        let all_teams = &state.clubs;
+        let reason = self.time_engine.pause_reason.clone();
+        self.time_engine.resume_game();
+
+        let Some(PauseReason::HighPriorityEvent(event)) = reason else {
+            return;
+        };
+        let windows_engine = TransferWindowEngine::new();
+        let Some(mut bid) = windows_engine.bid_from_event(&event) else {
+            return;
+        };
+        let (Some(buying_club), Some(selling_club)) = (
+            all_teams.iter().find(|team| team.id == bid.club_id).cloned(),
+            all_teams.iter().find(|team| team.id == player.contract.club_id).cloned(),
+        ) else {
+            return;
+        };
+
+        println!("📞 LAST-MINUTE BID");
+        println!("{} bid £{:.0} for you, offering £{:.0}/week for {} years as a {:?}.",
+                 buying_club.name, bid.fee, bid.wage, bid.length_years, bid.squad_role);
+        let score = self.report_offer_score(&state.career_priorities, player, &buying_club, bid.wage, bid.squad_role);
+        print!("Do you want to go? (y/n): ");
+        io::stdout().flush().unwrap();
+
+        let mut input = String::new();
+        io::stdin().read_line(&mut input).expect("Failed to read line");
+        if !input.trim().eq_ignore_ascii_case("y") {
+            println!("You tell your agent you're staying put.");
+            return;
+        }
+
+        let today = self.time_engine.current_date.date_naive();
+        let agent = state.player_agent.and_then(|agent_id| state.agents.iter().find(|agent| agent.id == agent_id));
+        let mut terms = bid.terms();
+        let mut agent_fee = 0.0;
+        if let Some(agent) = agent {
+            // The agent goes back to the club for better personal terms before the deal is done
+            let transfer = TransferEngine::new();
+            let mut offer = transfer.generate_transfer_offer(player, &buying_club, &player.contract, today);
+            offer.offered_wage = bid.wage;
+            offer.contract_length_years = bid.length_years;
+            let squad: Vec<&Player> = state.players.iter().filter(|other| other.contract.club_id == buying_club.id).collect();
+            let positional_need = transfer.analyze_positional_need(player, &buying_club, &squad, today).need;
+            let talks = AgentEngine::new().negotiate_for_priorities(agent, player, &buying_club, &offer, &state.career_priorities, positional_need);
+            for overridden in &talks.overridden_priorities {
+                println!("   {} ignored your wishes: {}", agent.name, overridden);
+            }
+            transfer.record_negotiation(&mut state.transfer_system, &talks.negotiation);
+            let Some(agreed) = &talks.negotiation.agreed_terms else {
+                println!("❌ {} can't agree personal terms with {} and the deal collapses.", agent.name, buying_club.name);
+                return;
+            };
+            println!("🤝 {} gets you £{:.0}/week for {} years as a {:?}, £{:.0} signing-on fee, release clause £{:.0}.",
+                     agent.name, agreed.wage, agreed.length_years, agreed.squad_role, agreed.signing_on_fee, agreed.release_clause.unwrap_or(0.0));
+            terms = agreed.clone();
+            agent_fee = talks.agent_fee;
+        }
+
+        let windows = self.current_windows(&state.season, player, all_teams);
+        let mut response = windows_engine.accept_deadline_bid(player, &mut state.transfer_system, &mut state.clubs, &bid, &terms, windows, today);
+        if let BidResponse::Countered(asking) = response {
+            let transfer_requested = state.transfer_system.transfer_request.is_some();
+            if let Some(fee) = self.answer_counter(player, &buying_club, &selling_club, asking, transfer_requested, today) {
+                bid.fee = fee;
+                response = windows_engine.accept_deadline_bid(player, &mut state.transfer_system, &mut state.clubs, &bid, &terms, windows, today);
+            }
+        }
+        match response {
+            BidResponse::Accepted | BidResponse::ClauseTriggered => {
+                println!("✍️ Done deal! You sign for {} before the window shuts.", buying_club.name);
+                self.settle_in(player, &score);
+                if let Some(agent) = agent.filter(|_| agent_fee > 0.0) {
+                    FinanceEngine::new().charge_agent_fee(&mut state.player_finances, agent, agent_fee, today);
+                    println!("💼 {} takes their £{:.0} fee.", agent.name, agent_fee);
+                }
+                if let Some(record) = state.transfer_system.transfer_records.last() {
+                    println!("💷 The fee: {}", FeeEngine::new().describe_structure(&record.fee));
+                }
+                FreeAgencyEngine::new().schedule_contract_expiry(player, &mut self.time_engine);
+            }
+            BidResponse::Countered(_) => println!("❌ {} want more money and time runs out.", selling_club.name),
+            BidResponse::Rejected(reason) => println!("❌ {}.", reason),
+        }
+    }
+
+    /// Lets the player push the buying club to answer the selling club's counter, bid by bid
+    ///
+    /// Returns the fee the clubs agree on, if they get there.
+    fn answer_counter(&self, player: &Player, buying_club: &Team, selling_club: &Team, asking: f32, transfer_requested: bool, today: NaiveDate) -> Option<f32> {
+        println!("↩️ {} want £{:.0} for you.", selling_club.name, asking);
+        print!("Push {} to go higher? (y/n): ", buying_club.name);
+        io::stdout().flush().unwrap();
+
+        let mut input = String::new();
+        io::stdin().read_line(&mut input).expect("Failed to read line");
+        if !input.trim().eq_ignore_ascii_case("y") {
+            return None;
+        }
+
+        let transfer = TransferEngine::new();
+        let outcome = transfer.bid_for_player(player, buying_club, selling_club, asking, transfer.transfer_budget(buying_club), transfer_requested, today);
+        for bid in &outcome.bids {
+            let answer = match &bid.response {
+                BidResponse::Accepted | BidResponse::ClauseTriggered => "accepted".to_string(),
+                BidResponse::Countered(fee) => format!("they want £{:.0}", fee),
+                BidResponse::Rejected(reason) => reason.to_lowercase(),
+            };
+            println!("   {} bid £{:.0}: {}", buying_club.name, bid.fee, answer);
+        }
+        outcome.agreed_fee
+    }
+
+    /// Acts on scheduled events that have just fired
+    fn handle_triggered_events(&mut self, state: &mut GameState, today: NaiveDate) {
+        for event in self.time_engine.take_triggered_events() {
+            match event.event_type {
+                ScheduledEventType::ContractExpires(player_id) if player_id == state.player.id => {
+                    self.expire_contract(state, today);
+                }
+                ScheduledEventType::MatchDay(_) => self.play_match_day(state, today),
+                // Breaks and finals days that passed before the career began are skipped
+                ScheduledEventType::InternationalBreak if event.scheduled_time.date_naive() == today => {
+                    self.play_international_break(state, today);
+                }
+                ScheduledEventType::InternationalTournament if event.scheduled_time.date_naive() == today => {
+                    self.play_tournament_day(state, today);
+                }
+                ScheduledEventType::SeasonEnd => self.end_season(state),
+                _ => {}
+            }
+        }
+    }
+
+    /// Plays the day's senior fixtures around the world and reports the player's club's result
+    fn play_match_day(&mut self, state: &mut GameState, today: NaiveDate) {
+        let played = MatchEngine::new().play_match_day(state, today);
+        let club_id = state.player.contract.club_id;
+        for game_match in played.iter().filter(|game_match| game_match.home_team == club_id || game_match.away_team == club_id) {
+            let Some((home_goals, away_goals)) = game_match.fulltime_score else {
+                continue;
+            };
+            println!("⚽ {} {} - {} {}",
+                     self.get_team_name(&state.clubs, game_match.home_team), home_goals,
+                     away_goals, self.get_team_name(&state.clubs, game_match.away_team));
+            match game_match.player_ratings.get(&state.player.id) {
+                Some(rating) => println!("   You started and were rated {:.1}", rating),
+                None => println!("   You didn't make the starting eleven."),
+            }
+        }
+    }
+
+    /// Moves the player on when their contract runs out
+    fn expire_contract(&mut self, state: &mut GameState, today: NaiveDate) {
+        let player = &mut state.player;
+        let all_teams = &state.clubs;
+        match FreeAgencyEngine::new().handle_contract_expiry(player, &mut state.transfer_system, all_teams, today) {
+            Some(ExpiryOutcome::JoinedClub(club_id)) => {
+                println!("✍️ Your contract has run out and your pre-contract kicks in. Welcome to {}!", self.get_team_name(all_teams, club_id));
+                FreeAgencyEngine::new().schedule_contract_expiry(player, &mut self.time_engine);
+            }
+            Some(ExpiryOutcome::FreeAgent(club_id)) => {
+                println!("📭 Your contract at {} has expired. You are now a free agent.", self.get_team_name(all_teams, club_id));
+            }
+            None => {}
+        }
+    }
+
+    /// Closes the season, reports how it went and starts the next one
+    fn end_season(&mut self, state: &mut GameState) {
+        let archive = SeasonEngine::new().advance_season(state, &mut self.time_engine);
+        println!("🏁 The {} season is over.", archive.season);
+        for record in archive.competitions.iter().filter(|record| !record.youth) {
+            if let Some(winner) = record.winner {
+                println!("   {}: won by {}", record.name, self.get_team_name(&state.clubs, winner));
+            }
+        }
+        if let Some(season) = state.player.career_stats.season_stats.last() {
+            println!("   You: {} appearances, {} goals, {} assists", season.appearances, season.goals, season.assists);
+        }
+        if let Some(record) = state.player_finances.net_worth_history.last() {
+            println!("   Net worth: £{:.0}", record.net_worth);
+        }
+
+        // The awards were voted on as the season closed
+        let player_names = self.player_names(state);
+        if !archive.awards.is_empty() {
+            self.display_award_results(&archive.awards, &player_names, &state.clubs);
+        }
+        println!("Welcome to the {} season!", state.season.year);
+    }
+
+    /// Brings pre-contract offers in a contract's final months and runs the free-agent market
+    fn manage_free_agency(&mut self, state: &mut GameState, today: NaiveDate) {
+        let player = &mut state.player;
+        let all_teams = &state.clubs;
+        let free_agency = FreeAgencyEngine::new();
+
+        if let Some(outcome) = free_agency.process_trial(player, &mut state.transfer_system, all_teams, today) {
+            match outcome {
+                TrialOutcome::Signed(club_id) => {
+                    println!("✍️ Your trial went well. {} offer you a contract!", self.get_team_name(all_teams, club_id));
+                    free_agency.schedule_contract_expiry(player, &mut self.time_engine);
+                }
+                TrialOutcome::Released(club_id) => {
+                    println!("👋 {} have decided not to keep you after your trial.", self.get_team_name(all_teams, club_id));
+                }
+            }
+            return;
+        }
+        if today.weekday() != Weekday::Mon {
+            return;
+        }
+
+        if free_agency.is_free_agent(&state.transfer_system) {
+            let offers = free_agency.generate_free_agent_offers(player, &state.transfer_system, all_teams, today);
+            let Some(offer) = self.choose_free_transfer_offer("🆓 FREE AGENT OFFERS", player, &state.career_priorities, &offers, all_teams) else {
+                return;
+            };
+            let Some(club) = all_teams.iter().find(|team| team.id == offer.club_id) else {
+                return;
+            };
+            let score = PriorityEngine::new().score_offer(&state.career_priorities, player, club, offer.terms.wage, offer.terms.squad_role);
+            if free_agency.accept_free_agent_offer(player, &mut state.transfer_system, club, offer, today) {
+                println!("✍️ You sign for {}.", club.name);
+                self.settle_in(player, &score);
+                free_agency.schedule_contract_expiry(player, &mut self.time_engine);
+            } else {
+                println!("🏃 You start a trial at {}.", club.name);
+            }
+            return;
+        }
+
+        let Some(current_club) = all_teams.iter().find(|team| team.id == player.contract.club_id) else {
+            return;
+        };
+        let offers = free_agency.generate_pre_contract_offers(player, &state.transfer_system, current_club, all_teams, today);
+        let Some(offer) = self.choose_free_transfer_offer("🌍 PRE-CONTRACT OFFERS", player, &state.career_priorities, &offers, all_teams) else {
+            return;
+        };
+        if let Some(club) = all_teams.iter().find(|team| team.id == offer.club_id) {
+            if free_agency.sign_pre_contract(player, &mut state.transfer_system, current_club, club, offer, today) {
+                println!("✍️ You agree to join {} when your contract ends on {}.", club.name, player.contract.contract_end_date);
+            }
+        }
+    }
+
+    /// Lists free transfer offers, scored against the player's priorities, and lets the player pick one
+    fn choose_free_transfer_offer<'a>(&self, title: &str, player: &Player, priorities: &CareerPriorities, offers: &'a [FreeTransferOffer], all_teams: &[Team]) -> Option<&'a FreeTransferOffer> {
+        if offers.is_empty() {
+            return None;
+        }
+
+        println!("{}", title);
+        for (i, offer) in offers.iter().enumerate() {
+            let score = all_teams.iter()
+                .find(|team| team.id == offer.club_id)
+                .map(|club| PriorityEngine::new().score_offer(priorities, player, club, offer.terms.wage, offer.terms.squad_role).score)
+                .unwrap_or_default();
+            println!("{}. {} - {:?}, £{:.0}/week for {} years, £{:.0} signing-on fee{} (fits your priorities {:.0}/100)",
+                     i + 1,
+                     self.get_team_name(all_teams, offer.club_id),
+                     offer.terms.squad_role,
+                     offer.terms.wage,
+                     offer.terms.length_years,
+                     offer.terms.signing_on_fee,
+                     offer.trial_days.map(|days| format!(" after a {}-day trial", days)).unwrap_or_default(),
+                     score);
+        }
+        print!("Enter a number to accept, or press Enter to wait: ");
+        io::stdout().flush().unwrap();
+
+        let mut input = String::new();
+        io::stdin().read_line(&mut input).expect("Failed to read line");
+        input.trim().parse::<usize>().ok().and_then(|choice| offers.get(choice.wrapping_sub(1)))
+    }
+
+    /// Ends or recalls loans, and offers loan moves to prospects at the start of each window
+    fn manage_loans(&self, state: &mut GameState, today: NaiveDate) {
+        let player = &mut state.player;
+        let all_teams = &state.clubs;
+        let competitions = &state.competitions;
+        let loans = LoanEngine::new();
+
+        if let Some(review) = loans.process_day(player, competitions, today) {
+            self.report_loan_review(&review, all_teams);
+            self.consider_loan_purchase(state, &review, today);
+            return;
+        }
+        if today.weekday() == Weekday::Mon && loans.should_recall(player, competitions, today) {
+            if let Some(review) = loans.recall(player, competitions, today) {
+                self.report_loan_review(&review, all_teams);
+            }
+            return;
+        }
+
+        // Loan offers arrive in the first week of the summer and winter windows
+        let windows = self.current_windows(&state.season, player, all_teams);
+        let window_opens = windows.periods().iter().any(|(start, _)| today >= *start && today < *start + chrono::Duration::days(7));
+        if !window_opens || today.weekday() != Weekday::Mon {
+            return;
+        }
+        let Some(parent) = all_teams.iter().find(|team| team.id == player.contract.club_id) else {
+            return;
+        };
+        let offers = loans.generate_loan_offers(player, parent, all_teams, today);
+        if offers.is_empty() {
+            return;
+        }
+
+        println!("🔁 LOAN OFFERS");
+        let priorities = PriorityEngine::new();
+        let scores: Vec<Option<OfferScore>> = offers.iter()
+            .map(|offer| all_teams.iter()
+                .find(|team| team.id == offer.loan_club_id)
+                .map(|club| priorities.score_offer(&state.career_priorities, player, club, player.contract.wage, offer.promised_role)))
+            .collect();
+        for (i, offer) in offers.iter().enumerate() {
+            println!("{}. {} - {:?}, {:.0}% of matches promised, pays {:.0}% of wages{} (fits your priorities {:.0}/100)",
+                     i + 1,
+                     self.get_team_name(all_teams, offer.loan_club_id),
+                     offer.promised_role,
+                     offer.promised_minutes_share * 100.0,
+                     offer.wage_split * 100.0,
+                     offer.purchase_option.map(|fee| format!(", option to buy for £{:.0}", fee)).unwrap_or_default(),
+                     scores[i].as_ref().map(|score| score.score).unwrap_or_default());
+        }
+        print!("Enter a number to accept, or press Enter to stay: ");
+        io::stdout().flush().unwrap();
+
+        let mut input = String::new();
+        io::stdin().read_line(&mut input).expect("Failed to read line");
+        if let Some(choice) = input.trim().parse::<usize>().ok().filter(|choice| (1..=offers.len()).contains(choice)) {
+            let offer = &offers[choice - 1];
+            loans.accept_loan(player, offer, today);
+            println!("✈️ You join {} on loan until {}.", self.get_team_name(all_teams, offer.loan_club_id), offer.end_date);
+            if let Some(score) = &scores[choice - 1] {
+                self.settle_in(player, score);
+            }
+        }
+    }
+
+    /// Asks the player whether to join the loan club for good when it takes up its purchase option
+    fn consider_loan_purchase(&self, state: &mut GameState, review: &LoanReview, today: NaiveDate) {
+        let Some(fee) = review.purchase_fee else {
+            return;
+        };
+        let Some(loan_club) = state.clubs.iter().find(|team| team.id == review.loan_club_id) else {
+            return;
+        };
+
+        println!("✍️ {} want to sign you permanently for £{:.0}, as a {:?}.", loan_club.name, fee, review.promised_role);
+        let score = self.report_offer_score(&state.career_priorities, &state.player, loan_club, state.player.contract.wage, review.promised_role);
+        print!("Do you want to stay? (y/n): ");
+        io::stdout().flush().unwrap();
+
+        let mut input = String::new();
+        io::stdin().read_line(&mut input).expect("Failed to read line");
+        if !input.trim().eq_ignore_ascii_case("y") {
+            println!("You turn them down and head back to {}.", self.get_team_name(&state.clubs, review.parent_club_id));
+            return;
+        }
+
+        let records = &mut state.transfer_system.transfer_records;
+        if LoanEngine::new().accept_purchase(&mut state.player, review, records, &mut state.clubs, today) {
+            println!("✍️ The move is permanent. {} pay £{:.0} to {}.",
+                     self.get_team_name(&state.clubs, review.loan_club_id),
+                     fee,
+                     self.get_team_name(&state.clubs, review.parent_club_id));
+            self.settle_in(&mut state.player, &score);
+        }
+    }
+
+    /// The player asks their club to let them leave
+    fn request_transfer(&mut self, state: &mut GameState) {
+        if state.transfer_system.transfer_request.is_some() {
+            println!("You have already asked to leave. The club knows where you stand.");
+            return;
+        }
+        let Some(club) = state.clubs.iter().find(|club| club.id == state.player.contract.club_id) else {
+            println!("You have no club to ask.");
+            return;
+        };
+        let today = self.time_engine.current_date.date_naive();
+        TransferEngine::new().push_for_move(&mut state.player, club, &mut state.transfer_system, today);
+        println!("📝 You hand in a transfer request. The club will listen to lower offers, but your manager has taken it badly.");
+    }
+
+    /// Shows the player's agent and lets them fire them, or hire one if they have none
+    fn manage_agent(&mut self, state: &mut GameState) {
+        let agents = AgentEngine::new();
+        if let Some(agent) = agents.current_agent(state) {
+            println!("🤝 Your agent: {} (skill {}, network {}, greed {}, fee {:.1}%)",
+                     agent.name, agent.negotiation_skill, agent.network_reach, agent.greed, agent.fee_percentage);
+            print!("Type 'fire' to let them go, or press Enter to go back: ");
+            io::stdout().flush().unwrap();
+
+            let mut input = String::new();
+            io::stdin().read_line(&mut input).expect("Failed to read line");
+            if input.trim().eq_ignore_ascii_case("fire") {
+                let name = agent.name.clone();
+                agents.fire_agent(state);
+                println!("✂️ You part ways with {}. They won't forget it.", name);
+            }
+            return;
+        }
+
+        if state.agents.is_empty() {
+            println!("No agents are looking for clients.");
+            return;
+        }
+        println!("🤝 AGENTS");
+        for (i, agent) in state.agents.iter().enumerate() {
+            println!("{}. {} - skill {}, network {}, greed {}, fee {:.1}%",
+                     i + 1, agent.name, agent.negotiation_skill, agent.network_reach, agent.greed, agent.fee_percentage);
+        }
+        print!("Enter a number to hire, or press Enter to go back: ");
+        io::stdout().flush().unwrap();
+
+        let mut input = String::new();
+        io::stdin().read_line(&mut input).expect("Failed to read line");
+        let Some(agent_id) = input.trim().parse::<usize>().ok()
+            .and_then(|choice| state.agents.get(choice.wrapping_sub(1)))
+            .map(|agent| agent.id)
+        else {
+            return;
+        };
+        match agents.hire_agent(state, agent_id) {
+            AgentHireOutcome::Hired => println!("✍️ You have a new agent. They'll handle your talks from now on."),
+            AgentHireOutcome::Declined(reason) => println!("❌ {}.", reason),
+            AgentHireOutcome::AlreadyRepresented | AgentHireOutcome::NotFound => {}
+        }
+    }
+
+    /// Shows the player's money and lets them spend it
+    fn manage_finances(&mut self, state: &mut GameState) {
+        let finance = FinanceEngine::new();
+        println!("🏦 PERSONAL FINANCES");
+        println!("Balance: £{:.0}   Net worth: £{:.0}", state.player_finances.balance, finance.net_worth(&state.player_finances));
+        for entry in state.player_finances.ledger.iter().rev().take(5) {
+            println!("  {} {:<28} £{:>10.0}", entry.date, entry.description, entry.amount);
+        }
+        for record in &state.player_finances.net_worth_history {
+            println!("  {} net worth: £{:.0}", record.season, record.net_worth);
+        }
+
+        let items = [LifestyleItem::House, LifestyleItem::Car, LifestyleItem::PersonalTrainer];
+        for (i, item) in items.iter().enumerate() {
+            println!("{}. Get a {} (£{:.0}, then £{:.0}/week)", i + 1, item.name(), finance.purchase_price(*item), finance.weekly_upkeep(*item));
+        }
+        print!("Enter a number to buy, or press Enter to go back: ");
+        io::stdout().flush().unwrap();
+
+        let mut input = String::new();
+        io::stdin().read_line(&mut input).expect("Failed to read line");
+        let Some(item) = input.trim().parse::<usize>().ok().and_then(|choice| items.get(choice.wrapping_sub(1))) else {
+            return;
+        };
+        let today = self.time_engine.current_date.date_naive();
+        if finance.buy_lifestyle(&mut state.player, &mut state.player_finances, *item, today) {
+            println!("✅ You now have a {}.", item.name());
+        } else {
+            println!("❌ You can't get a {} right now.", item.name());
+        }
+    }
+
+    /// Charts the player's market value over the weeks recorded so far
+    fn display_value_chart(&self, state: &GameState) {
+        const ROWS: usize = 20;
+        const BAR_WIDTH: f32 = 40.0;
+
+        let valuation = ValuationEngine::new();
+        let today = self.time_engine.current_date.date_naive();
+        println!("📈 MARKET VALUE: £{:.0}", valuation.market_value(&state.player, today));
+        let history = state.market_values.get(&state.player.id).map(Vec::as_slice).unwrap_or_default();
+        if history.is_empty() {
+            println!("No weekly values recorded yet.");
+            return;
+        }
+
+        // Spread the rows evenly over the whole history, always ending on the latest week
+        let step = history.len().div_ceil(ROWS);
+        let mut points: Vec<&MarketValuePoint> = history.iter().rev().step_by(step).collect();
+        points.reverse();
+        let max = points.iter().map(|point| point.value).fold(0.0, f32::max).max(1.0);
+        for point in points {
+            let bar = "█".repeat((point.value / max * BAR_WIDTH).round() as usize);
+            println!("{} {:<40} £{:.0}", point.date.format("%b %Y"), bar, point.value);
+        }
+        if let Some(trend) = valuation.value_trend(history) {
+            println!("Change since {}: {:+.1}%", history[0].date, trend * 100.0);
+        }
+    }
+
+    /// Reports how a loan ended
+    fn report_loan_review(&self, review: &LoanReview, all_teams: &[Team]) {
+        let club = self.get_team_name(all_teams, review.loan_club_id);
+        match review.outcome {
+            LoanOutcome::Completed => println!("🔁 Your loan at {} is over. Back to {}.", club, self.get_team_name(all_teams, review.parent_club_id)),
+            LoanOutcome::Recalled => println!("🔁 {} have recalled you from {}.", self.get_team_name(all_teams, review.parent_club_id), club),
+            LoanOutcome::PurchaseOffered => println!("🔁 Your loan at {} is over, and they want to keep you.", club),
+        }
+        println!("   {} appearances in {} matches ({:.0}% against {:.0}% promised)",
+                 review.appearances,
+                 review.matches,
+                 review.minutes_share * 100.0,
+                 review.promised_minutes_share * 100.0);
+    }
+
+    /// Moves club interest on by a week and shows the rumours the player hears about
+    fn follow_transfer_rumours(&mut self, state: &mut GameState, today: NaiveDate) {
+        let player = &mut state.player;
+        let all_teams = &state.clubs;
+        let competitions = &state.competitions;
+        // Scouts can only watch the matches played last week
+        let matches = CalendarEngine::new().matches_in_week(competitions, player.contract.club_id, today - chrono::Duration::days(7));
+        let rumours = RumourEngine::new();
+
+        // The agent talks the player up around their network before the week's round
+        if let Some(agent) = state.player_agent.and_then(|agent_id| state.agents.iter().find(|agent| agent.id == agent_id)) {
+            let introductions = AgentEngine::new().generate_interest(agent, player, all_teams, &state.players, player.contract.club_id, today);
+            for interest in &introductions {
+                println!("💼 {} has been talking you up to {}.", agent.name, self.get_team_name(all_teams, interest.club_id));
+            }
+            let club_ids: Vec<Uuid> = introductions.iter().map(|interest| interest.club_id).collect();
+            rumours.agent_introductions(&mut state.transfer_system, &club_ids);
+        }
+        let report = rumours.advance_week(&mut state.transfer_system, player, all_teams, &state.players, matches as u32, today);
+
+        // Clubs that reach the official offer stage put their offer in, held if the window is shut
+        let windows = self.current_windows(&state.season, player, all_teams);
+        for change in report.stage_changes.iter().filter(|change| change.to == Some(InterestLevel::OfficialOffer)) {
+            let Some(club) = all_teams.iter().find(|team| team.id == change.club_id) else {
+                continue;
+            };
+            let offer = TransferEngine::new().generate_transfer_offer(player, club, &player.contract, today);
+            match TransferWindowEngine::new().submit_offer(&mut state.transfer_system, &offer, windows, today) {
+                OfferStatus::Active => println!("💼 {} have made an official offer for you.", club.name),
+                OfferStatus::PreAgreed(opens) => println!("💼 {} have agreed an offer for you, to go through when the window opens on {}.", club.name, opens),
+            }
+        }
+
+        for rumour in &report.rumours {
+            let source = match rumour.reliability {
+                RumourReliability::Tabloid => "Tabloid gossip",
+                RumourReliability::Journalist => "Reporters say",
+                RumourReliability::Insider => "Sources close to the club",
+            };
+            println!("📰 {}: {}", source, rumour.headline);
+        }
+        if !report.rumours.is_empty() && (report.morale_change.abs() >= 0.5 || report.focus_change.abs() >= 0.5) {
+            println!("   Morale {:+.1}, focus {:+.1}", report.morale_change, report.focus_change);
+        }
+        self.consider_release_clauses(state, today);
+    }
+
+    /// Lets a club ready to bid pay the player's release clause and go straight to personal terms
+    fn consider_release_clauses(&mut self, state: &mut GameState, today: NaiveDate) {
+        let player = &mut state.player;
+        let all_teams = &state.clubs;
+        let transfer = TransferEngine::new();
+        let windows = self.current_windows(&state.season, player, all_teams);
+        let preferences = PriorityEngine::new().negotiation_preferences(&state.career_priorities);
+        let activation = state.transfer_system.interested_clubs.values()
+            .filter(|interest| matches!(interest.interest_level, InterestLevel::PreparingOffer | InterestLevel::OfficialOffer))
+            .filter_map(|interest| all_teams.iter().find(|team| team.id == interest.club_id && team.id != player.contract.club_id))
+            .find_map(|club| {
+                let squad = transfer.squad_members(club, &state.players, player.id);
+                let need = transfer.analyze_positional_need(player, club, &squad, today).need;
+                transfer.trigger_release_clause(player, club, &preferences, need, windows, today)
+            });
+        let Some(activation) = activation else {
+            return;
+        };
+        let Some(club) = all_teams.iter().find(|team| team.id == activation.buying_club_id).cloned() else {
+            return;
+        };
+        transfer.record_negotiation(&mut state.transfer_system, &activation.negotiation);
+
+        println!("💥 RELEASE CLAUSE");
+        println!("{} have paid your £{:.0} release clause. {} can't stand in the way.",
+                 club.name, activation.fee, self.get_team_name(all_teams, player.contract.club_id));
+        let Some(terms) = &activation.negotiation.agreed_terms else {
+            println!("Talks over personal terms break down and {} walk away.", club.name);
+            state.transfer_system.interested_clubs.remove(&club.id);
+            return;
+        };
+        println!("They offer £{:.0}/week for {} years as a {:?}, £{:.0} signing-on fee.",
+                 terms.wage, terms.length_years, terms.squad_role, terms.signing_on_fee);
+        let score = self.report_offer_score(&state.career_priorities, player, &club, terms.wage, terms.squad_role);
+        print!("Do you want to go? (y/n): ");
+        io::stdout().flush().unwrap();
+
+        let mut input = String::new();
+        io::stdin().read_line(&mut input).expect("Failed to read line");
+        if !input.trim().eq_ignore_ascii_case("y") {
+            println!("You turn {} down and they look elsewhere.", club.name);
+            state.transfer_system.interested_clubs.remove(&club.id);
+            return;
+        }
+        if transfer.complete_clause_move(player, &mut state.transfer_system, &mut state.clubs, &activation, windows, today) {
+            println!("✍️ You sign for {}.", club.name);
+            self.settle_in(player, &score);
+            FreeAgencyEngine::new().schedule_contract_expiry(player, &mut self.time_engine);
+        }
+    }
+
+    /// Plays the day's youth fixtures and has the manager review a prospect's progress each week
+    fn play_youth_football(&self, player: &mut Player, competitions: &mut [Competition], all_teams: &[Team], today: NaiveDate) {
+        let youth = YouthEngine::new();
+        let reports = youth.play_youth_day(competitions, all_teams, player, today);
+        for report in reports.iter() {
+            let Some(performance) = &report.performance else {
+                continue;
+            };
+            let competition_name = competitions.iter()
+                .find(|competition| competition.id == report.competition_id)
+                .map(|competition| competition.name.clone())
+                .unwrap_or_default();
+            println!("🌱 {}: {} {} - {} {}",
+                     competition_name,
+                     self.get_team_name(all_teams, report.home_team),
+                     report.home_goals,
+                     report.away_goals,
+                     self.get_team_name(all_teams, report.away_team));
+            println!("   Rating {:.1}, {} goal(s), {} assist(s)", performance.rating, performance.goals, performance.assists);
+        }
+
+        if today.weekday() != Weekday::Mon || youth.youth_competition_for(player, competitions).is_none() {
+            return;
+        }
+
+        // The club's youth focus stands in for how much the manager trusts young players
+        let youth_trust = all_teams.iter()
+            .find(|team| team.id == player.contract.club_id)
+            .map(|team| team.youth_focus)
+            .unwrap_or(50.0);
+        let manager = ManagerProfile {
+            favoritism: 50.0,
+            youth_trust,
+            discipline: 50.0,
+            communication_style: CommunicationStyle::Direct,
+        };
+        let decision = youth.evaluate_promotion(player, &manager);
+        if decision.promote {
+            youth.promote(player);
+            println!("⬆️ The manager has promoted you to the first-team squad!");
+            for reason in &decision.reasons {
+                println!("   • {}", reason);
+            }
+        }
+    }
+
+    /// Runs an international window and reports the player's part in it
+    fn play_international_break(&mut self, state: &mut GameState, today: NaiveDate) {
+        let caps_before = state.player.career_stats.international_caps;
+        let goals_before = state.player.career_stats.international_goals;
+
+        let report = InternationalEngine::new().run_international_break_for_world(state, today);
+        let player = &state.player;
+
+        let Some(call_up) = report.call_ups.iter().find(|call_up| call_up.player_id == player.id) else {
+            println!("🌍 International break. You weren't called up this time.");
+            return;
+        };
+        let team_name = self.get_national_team_name(&state.national_teams, call_up.national_team);
+
+        if call_up.first_call_up {
+            println!("🎉🎉 FIRST CALL-UP! You've been named in the {} squad! 🎉🎉", team_name);
+        } else {
+            println!("🌍 You've been called up to the {} squad.", team_name);
+        }
+
+        let caps = player.career_stats.international_caps - caps_before;
+        let goals = player.career_stats.international_goals - goals_before;
+        if caps > 0 {
+            println!("   {} cap(s), {} goal(s) this break. Career: {} caps, {} goals",
+                     caps, goals, player.career_stats.international_caps, player.career_stats.international_goals);
+        } else {
+            println!("   You didn't get off the bench this time.");
+        }
+        self.report_international_results(&report.fixtures, &state.national_teams, call_up.national_team);
+    }
+
+    /// Plays a day of a tournament finals and reports how the player's nation got on
+    fn play_tournament_day(&mut self, state: &mut GameState, today: NaiveDate) {
+        let tournament_engine = TournamentEngine::new();
+        let Some(report) = tournament_engine.play_finals_day_for_world(state, today) else {
+            return;
+        };
+        let player = &state.player;
+
+        if let Some(call_up) = report.call_ups.iter().find(|call_up| call_up.player_id == player.id) {
+            println!("🏆 You're going to the {}! Named in the {} squad.",
+                     report.tournament, self.get_national_team_name(&state.national_teams, call_up.national_team));
+        }
+
+        let Some(nation) = state.national_teams.iter().find(|team| team.nationality == player.nationality).map(|team| team.id) else {
+            return;
+        };
+        self.report_international_results(&report.fixtures, &state.national_teams, nation);
+
+        if let Some(champion) = report.champion {
+            println!("🏆 {} win the {}!", self.get_national_team_name(&state.national_teams, champion), report.tournament);
+            if report.golden_boot == Some(player.id) {
+                println!("👟 You've won the Golden Boot!");
+            }
+            let last_match = state.international_tournaments.iter()
+                .find(|tournament| tournament.name == report.tournament)
+                .and_then(|tournament| tournament_engine.last_match_date(tournament, nation));
+            if let Some(last_match) = last_match {
+                let days_lost = tournament_engine.pre_season_days_lost(last_match);
+                if days_lost > 0 {
+                    println!("🥱 The tournament has cost you {} days of pre-season.", days_lost);
+                }
+            }
+        }
+    }
+
+    /// Prints the results of a nation's international matches
+    fn report_international_results(&self, fixtures: &[InternationalFixture], national_teams: &[NationalTeam], nation: Uuid) {
+        for fixture in fixtures.iter().filter(|fixture| fixture.home_team == nation || fixture.away_team == nation) {
+            if let Some(result) = &fixture.result {
+                println!("   {:?}: {} {} - {} {}",
+                         fixture.match_type,
+                         self.get_national_team_name(national_teams, fixture.home_team),
+                         result.home_score,
+                         result.away_score,
+                         self.get_national_team_name(national_teams, fixture.away_team));
+            }
+        }
+    }
+
+    /// Looks up a national team's name by ID
+    fn get_national_team_name(&self, national_teams: &[NationalTeam], team_id: Uuid) -> String {
+        national_teams.iter()
+            .find(|team| team.id == team_id)
+            .map(|team| team.name.clone())
+            .unwrap_or_else(|| "Unknown".to_string())
+    }
+
+    /// Tells the player about postponed or cancelled fixtures involving their club
+    fn report_postponements(&self, postponements: &[Postponement], club_id: Uuid, competitions: &[Competition], all_teams: &[Team]) {
+        for postponement in postponements {
+            let Some(fixture) = competitions.iter()
+                .flat_map(|competition| competition.fixtures.iter())
+                .find(|fixture| fixture.id == postponement.fixture_id)
+            else {
+                continue;
+            };
+            if fixture.home_team != club_id && fixture.away_team != club_id {
+                continue;
+            }
+
+            let reason = match postponement.reason {
+                PostponementReason::CupTie => "a cup tie",
+                PostponementReason::Weather => "bad weather",
+            };
+            let teams = format!("{} vs {}",
+                                self.get_team_name(all_teams, fixture.home_team),
+                                self.get_team_name(all_teams, fixture.away_team));
+            match postponement.new_date {
+                Some(new_date) => println!("⛔ {} on {} postponed due to {}. Rescheduled for {}.",
+                                           teams, postponement.original_date, reason, new_date.format("%a %d %b")),
+                None => println!("⛔ {} on {} called off due to {}. No free date remains this season.",
+                                 teams, postponement.original_date, reason),
+            }
+        }
+    }
+
     /// Displays the weekly status screen
-    fn display_weekly_status(&self, player: &mut Player, all_teams: &[Team]) {
+    fn display_weekly_status(&self, state: &mut GameState) {
+        let player = &mut state.player;
+        let all_teams = &state.clubs;
+        let competitions = &state.competitions;
         println!("┌─────────────────────────────────────────────────────────┐");
         println!("│                    WEEKLY STATUS                        │");
         println!("├─────────────────────────────────────────────────────────┤");
@@ -110,25 +1252,70 @@ impl ConsoleUI {
         println!("│ Club: {:<48} │", current_team);
         println!("│ Squad Role: {:<10} Wage: £{:<18.0} │", 
                  format!("{:?}", player.contract.squad_role), player.contract.wage);
+        let bonuses = BonusEngine::new();
+        for bonus in &player.contract.performance_bonuses {
+            let progress = bonuses.bonus_progress(player, bonus, competitions);
+            let status = if bonus.achieved {
+                "paid".to_string()
+            } else if matches!(bonus.condition, BonusCondition::LeaguePosition(_)) {
+                format!("now {}", progress.current)
+            } else {
+                format!("{}/{}", progress.current, progress.target)
+            };
+            println!("│ Bonus: {:<47} │", format!("{} £{:.0} ({})", bonuses.describe_condition(&bonus.condition), bonus.amount, status));
+        }
         println!("├─────────────────────────────────────────────────────────┤");
         
         // Upcoming matches
         println!("│ Upcoming Matches:                                       │");
-        // In a real implementation, this would show actual upcoming matches
-        println!("│ - No matches scheduled this week                        │");
+        let calendar = CalendarEngine::new();
+        let club_id = player.contract.club_id;
+        let today = self.time_engine.current_date.date_naive();
+        let mut this_week: Vec<_> = competitions.iter()
+            .flat_map(|competition| competition.fixtures.iter())
+            .filter(|fixture| fixture.status == MatchStatus::Scheduled)
+            .filter(|fixture| fixture.home_team == club_id || fixture.away_team == club_id)
+            .filter(|fixture| (0..7).contains(&(fixture.scheduled_date - today).num_days()))
+            .collect();
+        this_week.sort_by_key(|fixture| fixture.scheduled_date);
+
+        if this_week.is_empty() {
+            println!("│ - No matches scheduled this week                        │");
+        }
+        for fixture in &this_week {
+            let opponent = if fixture.home_team == club_id { fixture.away_team } else { fixture.home_team };
+            let venue = if fixture.home_team == club_id { "H" } else { "A" };
+            let moved = if fixture.postponed_from.is_some() { " (rescheduled)" } else { "" };
+            println!("│ - {:<52} │", format!("{} vs {} ({}){}",
+                                            fixture.scheduled_date.format("%a %d %b"),
+                                            self.get_team_name(all_teams, opponent),
+                                            venue,
+                                            moved));
+        }
+
+        let matches = calendar.matches_in_week(competitions, club_id, today);
+        if calendar.congestion_level(matches) != CongestionLevel::Normal {
+            println!("│ ⚠ Fixture congestion: {:<32} │",
+                     format!("{:?}, rotation risk {:.0}%", calendar.congestion_level(matches),
+                             calendar.rotation_pressure(player, matches) * 100.0));
+        }
         println!("└─────────────────────────────────────────────────────────┘");
         println!();
     }
 
     /// Checks for any events requiring user decisions
-    fn check_for_user_decisions(&mut self) -> Option<UserDecisionRequest> {
-        // In a real implementation, this would check the event engine for user input events
-        // For now, we'll return None
-        None
+    fn check_for_user_decisions(&self, state: &mut GameState) -> Option<UserDecisionRequest> {
+        // In a real implementation, this would also check the event engine for user input events
+        if state.pending_decisions.is_empty() {
+            None
+        } else {
+            Some(state.pending_decisions.remove(0))
+        }
     }
 
     /// Handles a user decision
-    fn handle_user_decision(&mut self, player: &mut Player, decision: UserDecisionRequest) {
+    fn handle_user_decision(&mut self, state: &mut GameState, decision: UserDecisionRequest) {
+        let player = &mut state.player;
         match decision.decision_type {
             crate::core::event_engine::DecisionType::TrainingFocusSelection => {
                 self.handle_training_focus_selection(player, &decision);
@@ -151,6 +1338,41 @@ impl ConsoleUI {
             crate::core::event_engine::DecisionType::PersonalLifeChoice => {
                 self.handle_personal_life_choice(player, &decision);
             },
+            crate::core::event_engine::DecisionType::CareerOpportunity => {
+                self.handle_career_opportunity(state, &decision);
+            },
+        }
+    }
+
+    /// Handles a career opportunity: the first option accepts it, the second declines
+    fn handle_career_opportunity(&mut self, state: &mut GameState, decision: &UserDecisionRequest) {
+        let opportunities = OpportunityEngine::new();
+        let Some(opportunity) = opportunities.opportunity_from_request(decision) else {
+            return;
+        };
+        println!("🌟 CAREER OPPORTUNITY");
+        println!("{}", opportunities.describe(&opportunity));
+
+        for (i, option) in decision.options.iter().enumerate() {
+            println!("{}. {}", i + 1, option.text);
+        }
+
+        print!("Enter your choice (1-{}): ", decision.options.len());
+        io::stdout().flush().unwrap();
+
+        let mut input = String::new();
+        io::stdin().read_line(&mut input).expect("Failed to read line");
+
+        let accepted = input.trim().parse::<usize>() == Ok(1);
+        if !accepted && input.trim().parse::<usize>() != Ok(2) {
+            println!("Invalid choice. You let the chance pass.");
+        }
+        let player = &mut state.player;
+        opportunities.resolve(player, &opportunity, accepted, &mut state.career_opportunities);
+        if accepted {
+            println!("You accept. Role: {:?}, position: {:?}.", player.contract.squad_role, player.primary_position);
+        } else {
+            println!("You decline.");
         }
     }
 
@@ -558,6 +1780,93 @@ impl ConsoleUI {
         println!("└────┴────────────────────────────┴──────┴────┴────┴────┘");
     }
 
+    /// Displays the competition's statistical leaderboards
+    pub fn display_leaderboards(&self, competition: &Competition, player_names: &HashMap<Uuid, String>, all_teams: &[Team]) {
+        let leaderboards = LeaderboardEngine::new();
+        let today = self.time_engine.current_date.date_naive();
+
+        println!("┌─────────────────────────────────────────────────────────┐");
+        println!("│ {:^55} │", format!("{} LEADERBOARDS", competition.name.to_uppercase()));
+
+        let boards = [
+            ("Top Scorers", leaderboards.top_scorers(competition, 5), 0),
+            ("Top Assists", leaderboards.top_assists(competition, 5), 0),
+            ("Average Rating (min 5 apps)", leaderboards.top_rated(competition, 5, 5), 2),
+            ("Clean Sheets", leaderboards.clean_sheets(competition, 5), 0),
+            ("Disciplinary Points", leaderboards.most_cards(competition, 5), 0),
+        ];
+
+        for (title, entries, precision) in boards {
+            println!("├─────────────────────────────────────────────────────────┤");
+            println!("│ {:<55} │", title);
+            if entries.is_empty() {
+                println!("│   {:<53} │", "No entries yet");
+            }
+            for (rank, entry) in entries.iter().enumerate() {
+                println!("│ {:>2}. {:<22} {:<16} {:>3} apps {:>4.*} │",
+                         rank + 1,
+                         self.get_player_name(player_names, entry.player_id),
+                         self.get_team_name(all_teams, entry.team_id),
+                         entry.appearances,
+                         precision,
+                         entry.value);
+            }
+        }
+
+        println!("├─────────────────────────────────────────────────────────┤");
+        match leaderboards.player_of_the_month(competition, today.year(), today.month(), 2) {
+            Some(entry) => println!("│ Player of the Month: {:<23} ({:.2} avg) │",
+                                    self.get_player_name(player_names, entry.player_id),
+                                    entry.value),
+            None => println!("│ Player of the Month: {:<34} │", "Not enough matches yet"),
+        }
+        println!("└─────────────────────────────────────────────────────────┘");
+    }
+
+    /// Displays the published voting from the end-of-season awards ceremony
+    pub fn display_award_results(&self, results: &[AwardResult], player_names: &HashMap<Uuid, String>, all_teams: &[Team]) {
+        println!("┌─────────────────────────────────────────────────────────┐");
+        println!("│                   AWARDS CEREMONY                       │");
+
+        for result in results {
+            println!("├─────────────────────────────────────────────────────────┤");
+            println!("│ {:<55} │", format!("{} ({})", result.name, result.competition));
+            for votes in &result.voting {
+                let marker = if result.winners.contains(&votes.player_id) { "🏆" } else { "  " };
+                println!("│ {} {:<22} {:<16} {:>5.1}% of vote │",
+                         marker,
+                         self.get_player_name(player_names, votes.player_id),
+                         self.get_team_name(all_teams, votes.team_id),
+                         votes.vote_share);
+            }
+        }
+
+        println!("└─────────────────────────────────────────────────────────┘");
+    }
+
+    /// Helper to get a player's name, falling back to a short ID for players not yet scouted
+    fn get_player_name(&self, player_names: &HashMap<Uuid, String>, player_id: Uuid) -> String {
+        player_names.get(&player_id)
+            .cloned()
+            .unwrap_or_else(|| format!("Player {}", player_id.as_u128() % 1000))
+    }
+
+    /// Names of the player and everyone else in the world, for leaderboards and awards
+    fn player_names(&self, state: &GameState) -> HashMap<Uuid, String> {
+        std::iter::once(&state.player)
+            .chain(state.players.iter())
+            .map(|player| (player.id, player.name.clone()))
+            .collect()
+    }
+
+    /// Helper to get a club's name from the loaded teams
+    fn get_team_name(&self, all_teams: &[Team], team_id: Uuid) -> String {
+        all_teams.iter()
+            .find(|team| team.id == team_id)
+            .map(|team| team.name.clone())
+            .unwrap_or_else(|| format!("Team {}", team_id.as_u128() % 1000))
+    }
+
     /// Helper to get team name by ID
     fn get_team_name_by_id(&self, _competition: &Competition, team_id: Uuid) -> String {
         // In a real implementation, this would look up the team name
@@ -637,7 +1946,6 @@ pub enum MainMenuOption {
 mod tests {
     use super::*;
     use crate::entities::{Position, Foot, CareerStats, SquadRole, HiddenAttributes};
-    use crate::systems::social_system::ManagerProfile;
     use chrono::NaiveDate;
 
     #[test]
@@ -736,6 +2044,14 @@ mod tests {
                 season_stats: vec![],
                 awards: vec![],
                 trophies: vec![],
+                current_season: crate::entities::SeasonStats::default(),
+                international_caps: 0,
+                international_goals: 0,
+                first_call_up: None,
+                youth_season: crate::entities::SeasonStats::default(),
+                youth_history: vec![],
+                youth_form: vec![],
+                club_spells: vec![],
             },
             relationships: HashMap::new(),
             injury_status: None,
@@ -754,6 +2070,7 @@ mod tests {
             performance_bonuses: vec![],
             contract_end_date: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
             league_strength: 75.0,
+            loan: None,
         }
     }
 
@@ -11,6 +11,12 @@ pub struct TutorialManager {
     guides: HashMap<String, TutorialGuide>,
 }
 
+impl Default for TutorialManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl TutorialManager {
     /// Creates a new TutorialManager and registers default guides
     pub fn new() -> Self {
@@ -48,6 +54,12 @@ impl TutorialManager {
              - View Profile: Check your attributes and status.\n\
              - Team Info: See details about your current club.\n\
              - Continue: Advance time to the next important event.\n\
+             - Stats: Type 's' to see the competition leaderboards.\n\
+             - Transfer: Type 't' to hand in a transfer request and push for a move.\n\
+             - Money: Type 'm' to check your finances and spend on a house, car or trainer.\n\
+             - Value: Type 'v' to chart your market value over time.\n\
+             - Agent: Type 'a' to hire or fire an agent, who negotiates your moves for a fee.\n\
+             - Save: Type 'save' to save your career.\n\
              \n\
              Tip: You can type 'help' or 'h' at any menu to see this guide again."
         );
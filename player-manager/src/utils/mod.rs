@@ -6,6 +6,7 @@ pub mod constants {
     pub const MAX_PLAYERS_PER_SQUAD: usize = 25;
     pub const SEASON_LENGTH_WEEKS: u32 = 38;
     pub const MATCH_DURATION_MINUTES: u8 = 90;
+    pub const MIN_REST_DAYS: i64 = 1; // Days a team needs between matches
 }
 
 pub mod helpers {
@@ -21,4 +22,23 @@ pub mod helpers {
         let duration = today.signed_duration_since(birth_date);
         (duration.num_days() / 365) as u8
     }
+
+    /// Age in whole years on a given in-game date
+    pub fn age_on(birth_date: chrono::NaiveDate, date: chrono::NaiveDate) -> u8 {
+        use chrono::Datelike;
+
+        let had_birthday = (date.month(), date.day()) >= (birth_date.month(), birth_date.day());
+        let years = date.year() - birth_date.year() - if had_birthday { 0 } else { 1 };
+        years.max(0) as u8
+    }
+
+    /// Matches kick off at 3pm on their scheduled day
+    pub fn kick_off(date: chrono::NaiveDate) -> chrono::DateTime<chrono::Utc> {
+        date.and_hms_opt(15, 0, 0).unwrap().and_utc()
+    }
+
+    /// Season label for a season starting in the given year, e.g. 2026 -> "2026-27"
+    pub fn season_label(start_year: i32) -> String {
+        format!("{}-{:02}", start_year, (start_year + 1).rem_euclid(100))
+    }
 }
\ No newline at end of file
@@ -1,9 +1,8 @@
 // tests/integration_tests.rs
 use player_manager::entities::{
     Player, Team, Match, Competition, Position, Foot, CareerStats, Contract, 
-    SquadRole, HiddenAttributes, MatchStatus, MatchResult, MatchLineup, 
-    Formation, Tactics, TacticalStyle, Finances, Facilities, CurrentSeason,
-    Fixture, Standing
+    SquadRole, HiddenAttributes, MatchStatus, MatchLineup, 
+    Formation, Tactics, TacticalStyle, Finances, Facilities, CurrentSeason
 };
 use player_manager::core::{TimeEngine, EventEngine, game_state::GameState};
 use player_manager::systems::{
@@ -21,16 +20,16 @@ fn test_full_game_flow_integration() {
     let mut player = create_test_player();
     
     // Initialize all systems
-    let mut time_engine = TimeEngine::new(chrono::Utc::now());
-    let mut event_engine = EventEngine::new();
+    let _time_engine = TimeEngine::new(chrono::Utc::now());
+    let _event_engine = EventEngine::new();
     let development_engine = PlayerDevelopmentEngine::new();
     let morale_engine = MoraleEngine::new();
-    let mut match_engine = MatchEngine::new();
+    let _match_engine = MatchEngine::new();
     let reputation_engine = ReputationEngine::new();
-    let social_engine = SocialEngine::new();
+    let _social_engine = SocialEngine::new();
     let training_engine = TrainingSystem::new();
-    let competition_engine = CompetitionEngine::new();
-    let transfer_engine = TransferEngine::new();
+    let _competition_engine = CompetitionEngine::new();
+    let _transfer_engine = TransferEngine::new();
     let save_manager = SaveManager::new();
     
     // Test initial state
@@ -108,7 +107,7 @@ fn test_full_game_flow_integration() {
 
 #[test]
 fn test_match_simulation_integration() {
-    let mut player = create_test_player();
+    let player = create_test_player();
     let mut match_engine = MatchEngine::new();
     
     // Create a mock match
@@ -162,16 +161,17 @@ fn test_transfer_system_integration() {
     let current_club_id = teams[0].id; // Player belongs to team 0
     
     let transfer_engine = TransferEngine::new();
+    let today = chrono::NaiveDate::from_ymd_opt(2025, 8, 1).unwrap();
     
     // Evaluate transfer interest
-    let interests = transfer_engine.evaluate_transfer_interest(&player, &teams, current_club_id);
+    let interests = transfer_engine.evaluate_transfer_interest(&player, &teams, &[], current_club_id, today);
     
     // Should have at least some interest given the player's good stats
     assert!(!interests.is_empty());
     
     // Generate an offer for the first interested club
-    if let Some(interest) = interests.first() {
-        let offer = transfer_engine.generate_transfer_offer(&player, &teams[0], &player.contract);
+    if let Some(_interest) = interests.first() {
+        let offer = transfer_engine.generate_transfer_offer(&player, &teams[0], &player.contract, today);
         
         // Verify offer was generated with reasonable values
         assert!(offer.offered_wage > 0.0);
@@ -202,7 +202,7 @@ fn test_competition_system_integration() {
 
 #[test]
 fn test_social_relationship_integration() {
-    let mut player = create_test_player();
+    let _player = create_test_player();
     let mut relationships = HashMap::new();
     let other_entity_id = Uuid::new_v4();
     
@@ -232,7 +232,7 @@ fn test_social_relationship_integration() {
 #[test]
 fn test_time_event_integration() {
     let mut time_engine = TimeEngine::new(chrono::Utc::now());
-    let mut event_engine = EventEngine::new();
+    let _event_engine = EventEngine::new();
     
     // Schedule an event
     let event = player_manager::core::time_engine::ScheduledEvent::new(
@@ -282,8 +282,8 @@ fn test_player_development_with_age_progression() {
     );
     
     // Growth should be slower at age 28
-    let dribbling_increase_young = player.technical.dribbling as i32 - initial_dribbling as i32;
-    let dribbling_increase_older = player.technical.dribbling as i32 - initial_dribbling_at_28 as i32;
+    let _dribbling_increase_young = player.technical.dribbling as i32 - initial_dribbling as i32;
+    let _dribbling_increase_older = player.technical.dribbling as i32 - initial_dribbling_at_28 as i32;
     
     // The increase should be greater when younger (though both could be positive)
     // This test verifies the age-based development factors are working
@@ -352,6 +352,7 @@ fn create_test_player() -> Player {
             performance_bonuses: vec![],
             contract_end_date: NaiveDate::from_ymd_opt(2027, 1, 1).unwrap(),
             league_strength: 60.0,
+            loan: None,
         },
         career_stats: CareerStats {
             seasons_played: 0,
@@ -365,6 +366,14 @@ fn create_test_player() -> Player {
             season_stats: vec![],
             awards: vec![],
             trophies: vec![],
+            current_season: player_manager::entities::SeasonStats::default(),
+            international_caps: 0,
+            international_goals: 0,
+            first_call_up: None,
+            youth_season: player_manager::entities::SeasonStats::default(),
+            youth_history: vec![],
+            youth_form: vec![],
+            club_spells: vec![],
         },
         relationships: HashMap::new(),
         injury_status: None,
@@ -419,6 +428,7 @@ fn create_test_competition() -> Competition {
         level: 1,
         teams: vec![create_test_team().id, Uuid::new_v4()], // Need at least 2 teams for fixtures
         current_season: CurrentSeason {
+            year: String::new(),
             start_date: chrono::Utc::now().date_naive(),
             end_date: (chrono::Utc::now() + chrono::Duration::days(365)).date_naive(),
             current_matchday: 1,
@@ -428,6 +438,8 @@ fn create_test_competition() -> Competition {
         standings: vec![],
         season_start: chrono::Utc::now().date_naive(),
         season_end: (chrono::Utc::now() + chrono::Duration::days(365)).date_naive(),
+        tiebreak_rules: player_manager::entities::TiebreakRule::goal_difference_first(),
+        player_stats: vec![],
     }
 }
 